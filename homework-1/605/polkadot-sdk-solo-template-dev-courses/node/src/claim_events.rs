@@ -0,0 +1,102 @@
+//! 从区块中解码 PoE 存证事件，供节点的指标和订阅 RPC 共用
+
+use codec::Decode;
+use sc_client_api::StorageProvider;
+use serde::Serialize;
+use solochain_template_runtime::{opaque::Block, AccountId, BlockNumber, Hash, RuntimeEvent};
+use sp_core::{hashing::twox_128, storage::StorageKey};
+
+use crate::service::FullBackend;
+
+const LOG_TARGET: &str = "poe-events";
+
+type EventRecords = Vec<frame_system::EventRecord<RuntimeEvent, Hash>>;
+
+/// 存证事件的类型
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ClaimEventKind {
+    /// 创建了存证
+    Created,
+    /// 撤销了存证
+    Revoked,
+    /// 存证被转移
+    Transferred,
+}
+
+/// 区块中的一条存证事件
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimEvent {
+    /// 事件类型
+    pub kind: ClaimEventKind,
+    /// 存证哈希
+    pub claim_hash: Hash,
+    /// 事件发生前的所有者（创建时为新的所有者）
+    pub who: AccountId,
+    /// 转移的接收方，其他事件为 `None`
+    pub target: Option<AccountId>,
+    /// 事件所在的区块号
+    pub block_number: BlockNumber,
+    /// 事件所在的区块哈希
+    pub block_hash: Hash,
+}
+
+impl ClaimEvent {
+    /// 事件是否涉及账户 `owner`：作为原所有者或转移的接收方
+    pub fn involves(&self, owner: &AccountId) -> bool {
+        &self.who == owner || self.target.as_ref() == Some(owner)
+    }
+}
+
+/// 解码区块 `hash` 中的存证创建、撤销和转移事件
+///
+/// 区块的事件无法读取或解码（例如运行时升级改变了事件布局）时返回空列表。
+pub fn claim_events<C>(client: &C, hash: Hash) -> Vec<ClaimEvent>
+where
+    C: StorageProvider<Block, FullBackend>,
+{
+    let Some(records) = block_events(client, hash) else {
+        return Vec::new();
+    };
+    records
+        .into_iter()
+        .filter_map(|record| {
+            let RuntimeEvent::PoeModule(event) = record.event else {
+                return None;
+            };
+            let (kind, who, target, claim_hash, block) = match event {
+                pallet_poe::Event::ClaimCreated { who, claim_hash, block } => {
+                    (ClaimEventKind::Created, who, None, claim_hash, block)
+                }
+                pallet_poe::Event::ClaimRevoked { who, claim_hash, block } => {
+                    (ClaimEventKind::Revoked, who, None, claim_hash, block)
+                }
+                pallet_poe::Event::ClaimTransfered { who, target, claim_hash, block } => {
+                    (ClaimEventKind::Transferred, who, Some(target), claim_hash, block)
+                }
+                _ => return None,
+            };
+            Some(ClaimEvent { kind, claim_hash, who, target, block_number: block, block_hash: hash })
+        })
+        .collect()
+}
+
+fn block_events<C>(client: &C, hash: Hash) -> Option<EventRecords>
+where
+    C: StorageProvider<Block, FullBackend>,
+{
+    let key = StorageKey([twox_128(b"System"), twox_128(b"Events")].concat());
+    let data = match client.storage(hash, &key) {
+        Ok(data) => data?,
+        Err(err) => {
+            log::debug!(target: LOG_TARGET, "Failed to read events of block {hash:?}: {err}");
+            return None;
+        }
+    };
+    EventRecords::decode(&mut &data.0[..])
+        .map_err(|err| {
+            log::debug!(target: LOG_TARGET, "Failed to decode events of block {hash:?}: {err}")
+        })
+        .ok()
+}
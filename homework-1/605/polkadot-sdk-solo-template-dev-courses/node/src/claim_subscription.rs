@@ -0,0 +1,75 @@
+//! `poe_subscribeClaims` 订阅：推送最终确定区块中的存证创建、撤销和转移事件，前端无需轮询存储
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use jsonrpsee::{
+    core::{async_trait, SubscriptionResult},
+    proc_macros::rpc,
+    PendingSubscriptionSink, SubscriptionMessage,
+};
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use solochain_template_runtime::{opaque::Block, AccountId};
+
+use crate::{
+    claim_events::{claim_events, ClaimEvent},
+    service::FullBackend,
+};
+
+/// 存证事件的订阅接口
+#[rpc(server)]
+pub trait PoeSubscriptionApi {
+    /// 订阅最终确定区块中的存证事件，指定 `owner_filter` 时只推送原所有者或转移接收方为该账户的事件
+    #[subscription(
+        name = "poe_subscribeClaims" => "poe_claim",
+        unsubscribe = "poe_unsubscribeClaims",
+        item = ClaimEvent
+    )]
+    async fn subscribe_claims(&self, owner_filter: Option<AccountId>) -> SubscriptionResult;
+}
+
+/// [`PoeSubscriptionApiServer`] 的实现，跟随客户端的最终确定通知
+pub struct PoeSubscription<C> {
+    client: Arc<C>,
+}
+
+impl<C> PoeSubscription<C> {
+    /// 使用给定的客户端创建订阅处理器
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl<C> PoeSubscriptionApiServer for PoeSubscription<C>
+where
+    C: BlockchainEvents<Block> + StorageProvider<Block, FullBackend> + Send + Sync + 'static,
+{
+    async fn subscribe_claims(
+        &self,
+        pending: PendingSubscriptionSink,
+        owner_filter: Option<AccountId>,
+    ) -> SubscriptionResult {
+        let sink = pending.accept().await?;
+        let mut finality_notifications = self.client.finality_notification_stream();
+        while let Some(notification) = finality_notifications.next().await {
+            if sink.is_closed() {
+                break;
+            }
+            // `tree_route` 是上一个最终确定区块与本区块之间一并被确定的区块，不含本区块
+            let hashes = notification.tree_route.iter().chain(std::iter::once(&notification.hash));
+            for hash in hashes {
+                let events = claim_events(&*self.client, *hash).into_iter().filter(|event| {
+                    owner_filter.as_ref().map_or(true, |owner| event.involves(owner))
+                });
+                for event in events {
+                    // 客户端已取消订阅或断开连接
+                    if sink.send(SubscriptionMessage::from_json(&event)?).await.is_err() {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -48,4 +48,7 @@ pub enum Subcommand {
 
     /// Db meta columns information.
     ChainInfo(sc_cli::ChainInfoCmd),
+
+    /// Export all PoE claims stored at a given block.
+    ExportClaims(crate::export_claims::ExportClaimsCmd),
 }
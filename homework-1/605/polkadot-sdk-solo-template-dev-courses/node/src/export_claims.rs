@@ -0,0 +1,151 @@
+//! `export-claims` 子命令：导出某个区块的全部存证，用于审计或作为新链创世配置的数据来源
+
+use std::{fs, io::Write, path::PathBuf, sync::Arc};
+
+use codec::{Decode, Encode};
+use sc_cli::{BlockNumberOrHash, CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::StorageProvider;
+use serde::Serialize;
+use solochain_template_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, Runtime};
+use sp_blockchain::HeaderBackend;
+use sp_core::{hashing::twox_128, hexdisplay::HexDisplay, storage::StorageKey};
+use sp_runtime::generic::BlockId;
+
+use crate::service::FullClient;
+
+type ClaimDetails = pallet_poe::ClaimDetails<Runtime>;
+
+/// 导出文件的格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    /// 便于阅读的 JSON 数组
+    Json,
+    /// `Vec<(Hash, ClaimDetails)>` 的 SCALE 编码
+    Scale,
+}
+
+/// The `export-claims` command.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportClaimsCmd {
+    /// Block hash or number to export the claims at. Defaults to the best block.
+    #[arg(long, value_name = "HASH or NUMBER")]
+    pub at: Option<BlockNumberOrHash>,
+
+    /// Output file name. Defaults to stdout.
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+
+    /// Snapshot format.
+    #[arg(long, value_enum, default_value_t = ExportFormat::Json)]
+    pub format: ExportFormat,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub pruning_params: PruningParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub database_params: DatabaseParams,
+}
+
+/// JSON 快照中的一条存证，字段与 `pallet_poe::ClaimDetails` 一一对应
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ClaimEntry {
+    claim_hash: Hash,
+    owner: AccountId,
+    created_at: BlockNumber,
+    title: Option<String>,
+    uri: Option<String>,
+    status: String,
+    expires_at: BlockNumber,
+    deposit: Balance,
+    depositor: AccountId,
+    last_transfer: Option<BlockNumber>,
+}
+
+impl From<(Hash, ClaimDetails)> for ClaimEntry {
+    fn from((claim_hash, details): (Hash, ClaimDetails)) -> Self {
+        let hex = |bytes: &[u8]| format!("0x{}", HexDisplay::from(&bytes));
+        Self {
+            claim_hash,
+            owner: details.owner,
+            created_at: details.created_at,
+            title: details.title.as_ref().map(|title| hex(title)),
+            uri: details.uri.as_ref().map(|uri| hex(uri)),
+            status: format!("{:?}", details.status),
+            expires_at: details.expires_at,
+            deposit: details.deposit,
+            depositor: details.depositor,
+            last_transfer: details.last_transfer,
+        }
+    }
+}
+
+impl ExportClaimsCmd {
+    /// 遍历区块状态中的 `Proofs` 并写出快照
+    pub fn run(&self, client: Arc<FullClient>) -> sc_cli::Result<()> {
+        let hash = match &self.at {
+            None => client.info().best_hash,
+            Some(input) => match input.parse::<Block>()? {
+                BlockId::Hash(hash) => hash,
+                BlockId::Number(number) => client
+                    .hash(number)?
+                    .ok_or_else(|| format!("Block #{number} not found"))?,
+            },
+        };
+
+        let claims = read_claims(&client, hash)?;
+        log::info!("Exporting {} claims at block {hash:?}", claims.len());
+
+        let data = match self.format {
+            ExportFormat::Json => {
+                let entries: Vec<ClaimEntry> = claims.into_iter().map(Into::into).collect();
+                serde_json::to_vec_pretty(&entries).map_err(|e| format!("{e}"))?
+            }
+            ExportFormat::Scale => claims.encode(),
+        };
+        match &self.output {
+            Some(path) => fs::write(path, data)?,
+            None => std::io::stdout().write_all(&data)?,
+        }
+        Ok(())
+    }
+}
+
+/// 按存储键的顺序读取区块 `hash` 中的全部存证
+fn read_claims(client: &FullClient, hash: Hash) -> sc_cli::Result<Vec<(Hash, ClaimDetails)>> {
+    let prefix = StorageKey([twox_128(b"PoeModule"), twox_128(b"Proofs")].concat());
+    client
+        .storage_pairs(hash, Some(&prefix), None)?
+        .map(|(key, value)| -> sc_cli::Result<_> {
+            // 键的后缀是 `Blake2_128Concat(claim_hash)`：16 字节哈希之后是存证哈希本身
+            let claim_hash = key
+                .0
+                .get(prefix.0.len() + 16..)
+                .and_then(|mut raw| Hash::decode(&mut raw).ok())
+                .ok_or_else(|| format!("Invalid Proofs key 0x{}", HexDisplay::from(&key.0)))?;
+            let details = ClaimDetails::decode(&mut &value.0[..])
+                .map_err(|e| format!("Unable to decode claim {claim_hash:?}: {e}"))?;
+            Ok((claim_hash, details))
+        })
+        .collect()
+}
+
+impl CliConfiguration for ExportClaimsCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&PruningParams> {
+        Some(&self.pruning_params)
+    }
+
+    fn database_params(&self) -> Option<&DatabaseParams> {
+        Some(&self.database_params)
+    }
+}
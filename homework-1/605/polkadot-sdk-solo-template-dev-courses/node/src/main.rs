@@ -3,8 +3,12 @@
 
 mod benchmarking;
 mod chain_spec;
+mod claim_events;
+mod claim_subscription;
 mod cli;
 mod command;
+mod export_claims;
+mod metrics;
 mod rpc;
 mod service;
 
@@ -0,0 +1,98 @@
+//! PoE 存证注册表的 Prometheus 指标
+//!
+//! 节点在区块最终确定后解码其中的存证事件并更新指标，这些指标与节点自带的指标注册在同一个
+//! registry 中，名称统一带有 `substrate_poe_` 前缀。只统计最终确定的区块，分叉切换不会重复计数。
+
+use futures::StreamExt;
+use pallet_poe_runtime_api::PoeApi;
+use sc_client_api::BlockchainEvents;
+use solochain_template_runtime::Hash;
+use sp_api::ProvideRuntimeApi;
+use std::sync::Arc;
+use substrate_prometheus_endpoint::{register, Counter, Gauge, PrometheusError, Registry, U64};
+
+use crate::{
+    claim_events::{claim_events, ClaimEventKind},
+    service::FullClient,
+};
+
+const LOG_TARGET: &str = "poe-metrics";
+
+/// 存证相关的指标
+#[derive(Clone)]
+pub struct PoeMetrics {
+    total_claims: Gauge<U64>,
+    claims_per_block: Gauge<U64>,
+    claims_created: Counter<U64>,
+    claims_revoked: Counter<U64>,
+    claims_transferred: Counter<U64>,
+}
+
+impl PoeMetrics {
+    /// 在节点的 registry 中注册全部指标
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        Ok(Self {
+            total_claims: register(
+                Gauge::new(
+                    "poe_total_claims",
+                    "Number of claims stored at the last finalized block",
+                )?,
+                registry,
+            )?,
+            claims_per_block: register(
+                Gauge::new(
+                    "poe_claims_per_block",
+                    "Number of claims created in the last finalized block",
+                )?,
+                registry,
+            )?,
+            claims_created: register(
+                Counter::new("poe_claims_created_total", "Total number of claims created")?,
+                registry,
+            )?,
+            claims_revoked: register(
+                Counter::new("poe_claims_revoked_total", "Total number of claims revoked")?,
+                registry,
+            )?,
+            claims_transferred: register(
+                Counter::new(
+                    "poe_claims_transferred_total",
+                    "Total number of claims transferred",
+                )?,
+                registry,
+            )?,
+        })
+    }
+
+    fn note_block(&self, client: &FullClient, hash: Hash) {
+        let mut created = 0;
+        for event in claim_events(client, hash) {
+            match event.kind {
+                ClaimEventKind::Created => created += 1,
+                ClaimEventKind::Revoked => self.claims_revoked.inc(),
+                ClaimEventKind::Transferred => self.claims_transferred.inc(),
+            }
+        }
+        self.claims_created.inc_by(created);
+        self.claims_per_block.set(created);
+    }
+}
+
+/// 跟随最终确定的区块更新指标，随节点一起运行，直到通知流关闭
+pub async fn run(client: Arc<FullClient>, metrics: PoeMetrics) {
+    let mut finality_notifications = client.finality_notification_stream();
+    while let Some(notification) = finality_notifications.next().await {
+        // `tree_route` 是上一个最终确定区块与本区块之间一并被确定的区块，不含本区块
+        for hash in notification
+            .tree_route
+            .iter()
+            .chain(std::iter::once(&notification.hash))
+        {
+            metrics.note_block(&client, *hash);
+        }
+        match client.runtime_api().total_claims(notification.hash) {
+            Ok(total) => metrics.total_claims.set(total.into()),
+            Err(err) => log::debug!(target: LOG_TARGET, "Failed to query total claims: {err}"),
+        }
+    }
+}
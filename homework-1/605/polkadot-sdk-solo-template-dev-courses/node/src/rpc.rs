@@ -8,37 +8,46 @@
 use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
+use sc_client_api::{BlockchainEvents, StorageProvider};
 use sc_transaction_pool_api::TransactionPool;
-use solochain_template_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use solochain_template_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, Nonce};
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_core::offchain::OffchainStorage;
 
 pub use sc_rpc_api::DenyUnsafe;
 
 /// Full client dependencies.
-pub struct FullDeps<C, P> {
+pub struct FullDeps<C, P, S> {
     /// The client instance to use.
     pub client: Arc<C>,
     /// Transaction pool instance.
     pub pool: Arc<P>,
     /// Whether to deny unsafe calls
     pub deny_unsafe: DenyUnsafe,
+    /// Offchain database, used to serve the PoE activity index.
+    pub offchain_storage: Option<S>,
 }
 
 /// Instantiate all full RPC extensions.
-pub fn create_full<C, P>(
-    deps: FullDeps<C, P>,
+pub fn create_full<C, P, S>(
+    deps: FullDeps<C, P, S>,
 ) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>>
 where
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+    C: BlockchainEvents<Block> + StorageProvider<Block, crate::service::FullBackend>,
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: pallet_poe_rpc::PoeRuntimeApi<Block, AccountId, BlockNumber, Hash>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
+    S: OffchainStorage + 'static,
 {
+    use crate::claim_subscription::{PoeSubscription, PoeSubscriptionApiServer};
+    use pallet_poe_rpc::{Poe, PoeApiServer, PoeIndex, PoeIndexApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -47,10 +56,16 @@ where
         client,
         pool,
         deny_unsafe,
+        offchain_storage,
     } = deps;
 
     module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(Poe::new(client.clone()).into_rpc())?;
+    module.merge(PoeSubscription::new(client).into_rpc())?;
+    if let Some(storage) = offchain_storage {
+        module.merge(PoeIndexApiServer::<AccountId, BlockNumber, Hash>::into_rpc(PoeIndex::new(storage)))?;
+    }
 
     // Extend this RPC with a custom API by using the following syntax.
     // `YourRpcStruct` should have a reference to a client, which is needed
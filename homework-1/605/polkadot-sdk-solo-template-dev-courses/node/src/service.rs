@@ -16,7 +16,7 @@ pub(crate) type FullClient = sc_service::TFullClient<
     RuntimeApi,
     sc_executor::WasmExecutor<sp_io::SubstrateHostFunctions>,
 >;
-type FullBackend = sc_service::TFullBackend<Block>;
+pub(crate) type FullBackend = sc_service::TFullBackend<Block>;
 type FullSelectChain = sc_consensus::LongestChain<FullBackend, Block>;
 
 /// The minimum period of blocks on which justifications will be
@@ -202,12 +202,14 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
     let rpc_extensions_builder = {
         let client = client.clone();
         let pool = transaction_pool.clone();
+        let offchain_storage = backend.offchain_storage();
 
         Box::new(move |deny_unsafe, _| {
             let deps = crate::rpc::FullDeps {
                 client: client.clone(),
                 pool: pool.clone(),
                 deny_unsafe,
+                offchain_storage: offchain_storage.clone(),
             };
             crate::rpc::create_full(deps).map_err(Into::into)
         })
@@ -228,6 +230,15 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
         telemetry: telemetry.as_mut(),
     })?;
 
+    if let Some(registry) = prometheus_registry.as_ref() {
+        let metrics = crate::metrics::PoeMetrics::register(registry)?;
+        task_manager.spawn_handle().spawn(
+            "poe-metrics",
+            None,
+            crate::metrics::run(client.clone(), metrics),
+        );
+    }
+
     if role.is_authority() {
         let proposer_factory = sc_basic_authorship::ProposerFactory::new(
             task_manager.spawn_handle(),
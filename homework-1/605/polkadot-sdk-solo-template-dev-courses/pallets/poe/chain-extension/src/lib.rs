@@ -0,0 +1,68 @@
+//! PoE pallet 的 `pallet_contracts` 链扩展，智能合约可以据存证是否存在决定自己的业务逻辑
+//!
+//! 运行时把 [`PoeExtension`] 配置为 `pallet_contracts::Config::ChainExtension`，
+//! 或与其他扩展组成元组并以 [`PoeExtension::ID`] 区分。扩展提供两个只读函数：
+//!
+//! | 函数编号 | 函数 | 输入 | 输出 |
+//! |----------|------|------|------|
+//! | 0 | `owner_of` | 存证哈希 | `Option<AccountId>` |
+//! | 1 | `exists` | 存证哈希 | `bool` |
+//!
+//! 已过期的存证视为不存在。ink! 合约一侧的对应定义：
+//!
+//! ```ignore
+//! #[ink::chain_extension(extension = 0x504f)]
+//! pub trait Poe {
+//!     type ErrorCode = PoeError;
+//!
+//!     #[ink(function = 0, handle_status = false)]
+//!     fn owner_of(claim_hash: Hash) -> Option<AccountId>;
+//!
+//!     #[ink(function = 1, handle_status = false)]
+//!     fn exists(claim_hash: Hash) -> bool;
+//! }
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Encode;
+use frame_support::traits::Get;
+use pallet_contracts::chain_extension::{
+    ChainExtension, Environment, Ext, InitState, RegisteredChainExtension, Result, RetVal,
+};
+use pallet_poe::ClaimHashOf;
+use sp_runtime::DispatchError;
+
+const OWNER_OF: u16 = 0;
+const EXISTS: u16 = 1;
+
+#[derive(Default)]
+pub struct PoeExtension;
+
+impl<T> ChainExtension<T> for PoeExtension
+where
+    T: pallet_contracts::Config + pallet_poe::Config,
+{
+    fn call<E: Ext<T = T>>(&mut self, env: Environment<E, InitState>) -> Result<RetVal> {
+        let func_id = env.func_id();
+        if func_id != OWNER_OF && func_id != EXISTS {
+            return Err(DispatchError::Other("PoeExtension: unknown function id"));
+        }
+        let mut env = env.buf_in_buf_out();
+        let claim_hash: ClaimHashOf<T> = env.read_as()?;
+        // Proofs: 读取一个条目
+        env.charge_weight(T::DbWeight::get().reads(1))?;
+        let owner = pallet_poe::Pallet::<T>::live_claim(&claim_hash).ok().map(|details| details.owner);
+        let output = if func_id == OWNER_OF { owner.encode() } else { owner.is_some().encode() };
+        env.write(&output, false, None)?;
+        Ok(RetVal::Converging(0))
+    }
+}
+
+impl<T> RegisteredChainExtension<T> for PoeExtension
+where
+    T: pallet_contracts::Config + pallet_poe::Config,
+{
+    /// ASCII `PO`
+    const ID: u16 = 0x504f;
+}
@@ -0,0 +1,76 @@
+//! PoE pallet 的 EVM 预编译合约，供集成了 Frontier 的运行时注册到 `PrecompileSet` 中
+//!
+//! Solidity 合约可以直接调用以下接口：
+//!
+//! ```solidity
+//! interface Poe {
+//!     function createClaim(bytes32 claimHash) external;
+//!     function ownerOf(bytes32 claimHash) external view returns (bytes32);
+//!     function revokeClaim(bytes32 claimHash) external;
+//! }
+//! ```
+//!
+//! 调用者的以太坊地址经由运行时的 `AddressMapping` 映射为 Substrate 账户，写操作以该账户
+//! 的签名来源分发到 pallet，押金、手续费和各项检查与直接调用外部函数完全一致。
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Encode;
+use core::marker::PhantomData;
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_runtime::traits::Dispatchable;
+
+/// 新建一条存证写入的存储上限：`Proofs` 条目加上所有者索引条目
+const CLAIM_STORAGE_GROWTH: u64 = 16 + 32 + 335 + 16 + 48 + 32;
+
+pub struct PoePrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> PoePrecompile<Runtime>
+where
+    Runtime: pallet_poe::Config + pallet_evm::Config,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+    Runtime::RuntimeCall: From<pallet_poe::Call<Runtime>>,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::Hash: From<H256>,
+{
+    /// 以调用者的身份按哈希创建存证
+    #[precompile::public("createClaim(bytes32)")]
+    fn create_claim(handle: &mut impl PrecompileHandle, claim_hash: H256) -> EvmResult {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_poe::Call::<Runtime>::create_claim_by_hash { claim_hash: claim_hash.into() };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, CLAIM_STORAGE_GROWTH)?;
+        Ok(())
+    }
+
+    /// 存证所有者账户的 SCALE 编码，右对齐到 32 字节：`AccountId32` 即为公钥本身，
+    /// 20 字节的以太坊风格账户左侧补零。存证不存在或已过期时返回全零
+    #[precompile::public("ownerOf(bytes32)")]
+    #[precompile::view]
+    fn owner_of(handle: &mut impl PrecompileHandle, claim_hash: H256) -> EvmResult<H256> {
+        // Proofs: 读取一个条目
+        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        let owner = pallet_poe::Pallet::<Runtime>::live_claim(&claim_hash.into())
+            .ok()
+            .map(|details| details.owner.encode());
+        Ok(owner.map_or_else(H256::zero, |encoded| {
+            let mut word = H256::zero();
+            let len = encoded.len().min(32);
+            word.as_bytes_mut()[32 - len..].copy_from_slice(&encoded[..len]);
+            word
+        }))
+    }
+
+    /// 以调用者的身份撤销存证，退还的押金回到调用者映射的账户
+    #[precompile::public("revokeClaim(bytes32)")]
+    fn revoke_claim(handle: &mut impl PrecompileHandle, claim_hash: H256) -> EvmResult {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_poe::Call::<Runtime>::revoke_claim { claim_hash: claim_hash.into() };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+//! PoE pallet 的 JSON-RPC 接口，基于 [`pallet_poe_runtime_api::PoeApi`]
+//!
+//! 前端只需提供存证哈希或账户即可查询，无需自行构造 `Blake2_128Concat` 存储键。
+//! [`PoeIndexApi`] 读取 pallet 写入链下数据库的活动索引，不需要归档节点。
+
+use std::sync::Arc;
+
+use codec::{Codec, Decode, Encode};
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::offchain::OffchainStorage;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_poe_runtime_api::PoeApi as PoeRuntimeApi;
+
+/// `poe_getClaimsByOwner` 未指定 `limit` 时每页返回的最大数量
+pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+#[rpc(client, server)]
+pub trait PoeApi<BlockHash, AccountId, BlockNumber, Hash> {
+    /// 查询存证的所有者和创建区块号，存证不存在或已过期时返回 `null`
+    #[method(name = "poe_getClaim")]
+    fn get_claim(&self, claim_hash: Hash, at: Option<BlockHash>) -> RpcResult<Option<(AccountId, BlockNumber)>>;
+
+    /// 分页查询某个账户拥有的存证哈希，`offset` 默认为 0，`limit` 默认为 [`DEFAULT_PAGE_SIZE`]
+    #[method(name = "poe_getClaimsByOwner")]
+    fn get_claims_by_owner(
+        &self,
+        owner: AccountId,
+        offset: Option<u32>,
+        limit: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Hash>>;
+}
+
+/// 链下索引中存证活动的键前缀，与 `pallet_poe::ACTIVITY_INDEX_PREFIX` 相同
+const ACTIVITY_INDEX_PREFIX: &[u8] = b"poe::activity";
+
+/// 存证活动的类型，编码与 `pallet_poe::ActivityKind` 相同
+#[derive(Clone, Copy, Debug, Decode, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActivityKind {
+    /// 创建了存证
+    Created,
+    /// 撤销了存证
+    Revoked,
+}
+
+/// 账户的一条存证活动，编码与 `pallet_poe::ActivityRecord` 相同
+#[derive(Clone, Debug, Decode, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Activity<Hash, BlockNumber> {
+    /// 存证哈希
+    pub claim_hash: Hash,
+    /// 活动发生的区块号
+    pub block_number: BlockNumber,
+    /// 活动类型
+    pub kind: ActivityKind,
+}
+
+#[rpc(client, server)]
+pub trait PoeIndexApi<AccountId, BlockNumber, Hash> {
+    /// 按时间顺序分页查询某个账户的存证活动，`offset` 默认为 0，`limit` 默认为 [`DEFAULT_PAGE_SIZE`]
+    ///
+    /// 节点需要以 `--enable-offchain-indexing true` 启动，否则始终返回空列表。
+    #[method(name = "poe_getActivity")]
+    fn get_activity(
+        &self,
+        owner: AccountId,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> RpcResult<Vec<Activity<Hash, BlockNumber>>>;
+}
+
+/// RPC 错误码
+pub enum Error {
+    /// 调用 Runtime API 失败
+    RuntimeError,
+    /// 链下索引中的记录无法解码
+    DecodeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::DecodeError => 2,
+        }
+    }
+}
+
+/// [`PoeApiServer`] 的实现，通过客户端调用 Runtime API
+pub struct Poe<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Poe<C, Block> {
+    /// 使用给定的客户端创建 RPC 处理器
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+fn runtime_error(message: &'static str, e: impl std::fmt::Display) -> ErrorObjectOwned {
+    ErrorObject::owned(Error::RuntimeError.into(), message, Some(e.to_string()))
+}
+
+impl<C, Block, AccountId, BlockNumber, Hash> PoeApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber, Hash>
+    for Poe<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: PoeRuntimeApi<Block, AccountId, BlockNumber, Hash>,
+    AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + Send + Sync + 'static,
+    Hash: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn get_claim(
+        &self,
+        claim_hash: Hash,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<(AccountId, BlockNumber)>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .claim_owner(at_hash, claim_hash)
+            .map_err(|e| runtime_error("Unable to query claim.", e))
+    }
+
+    fn get_claims_by_owner(
+        &self,
+        owner: AccountId,
+        offset: Option<u32>,
+        limit: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Hash>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let claims = self
+            .client
+            .runtime_api()
+            .claims_of(at_hash, owner)
+            .map_err(|e| runtime_error("Unable to query claims of owner.", e))?;
+
+        Ok(claims
+            .into_iter()
+            .skip(offset.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(DEFAULT_PAGE_SIZE) as usize)
+            .collect())
+    }
+}
+
+/// [`PoeIndexApiServer`] 的实现，直接读取节点的链下数据库
+pub struct PoeIndex<S> {
+    storage: S,
+}
+
+impl<S> PoeIndex<S> {
+    /// 使用节点的链下数据库创建 RPC 处理器
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+}
+
+impl<S, AccountId, BlockNumber, Hash> PoeIndexApiServer<AccountId, BlockNumber, Hash> for PoeIndex<S>
+where
+    S: OffchainStorage + 'static,
+    AccountId: Encode + DeserializeOwned + Send + Sync + 'static,
+    BlockNumber: Decode + Serialize + Send + Sync + 'static,
+    Hash: Decode + Serialize + Send + Sync + 'static,
+{
+    fn get_activity(
+        &self,
+        owner: AccountId,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> RpcResult<Vec<Activity<Hash, BlockNumber>>> {
+        // 每个账户的活动从 0 连续编号，遇到第一个不存在的编号即结束
+        (offset.unwrap_or(0)..=u32::MAX)
+            .take(limit.unwrap_or(DEFAULT_PAGE_SIZE) as usize)
+            .map_while(|index| {
+                let key = (ACTIVITY_INDEX_PREFIX, &owner, index).encode();
+                self.storage.get(sp_offchain::STORAGE_PREFIX, &key)
+            })
+            .map(|raw| {
+                Activity::decode(&mut &raw[..]).map_err(|e| {
+                    ErrorObject::owned(Error::DecodeError.into(), "Unable to decode activity.", Some(e.to_string()))
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,42 @@
+//! PoE pallet 的 Runtime API，供 RPC 和轻客户端查询存证，无需自行拼接存储键
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait PoeApi<AccountId, BlockNumber, Hash>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+    {
+        /// 存证的所有者及创建区块号，存证不存在或已过期时返回 `None`
+        fn claim_owner(claim_hash: Hash) -> Option<(AccountId, BlockNumber)>;
+
+        /// 某个账户当前拥有的全部存证哈希
+        fn claims_of(owner: AccountId) -> Vec<Hash>;
+
+        /// 当前存储中的存证总数
+        fn total_claims() -> u32;
+
+        /// 存证的历史所有者及其转出时的区块号，按时间顺序排列
+        fn ownership_history(claim_hash: Hash) -> Vec<(AccountId, BlockNumber)>;
+
+        /// 存证在区块 `block` 结束时的所有者，无法确定时返回 `None`
+        fn owner_at(claim_hash: Hash, block: BlockNumber) -> Option<AccountId>;
+
+        /// 查询所在区块的存证统计：(创建, 撤销, 转移) 的数量
+        fn block_stats() -> (u64, u64, u64);
+
+        /// 到查询所在区块为止的累计存证统计：(创建, 撤销, 转移) 的数量
+        fn cumulative_stats() -> (u64, u64, u64);
+
+        /// 账户作为存证所有者的声誉，可以为负
+        fn reputation(who: AccountId) -> i32;
+
+        /// 账户获得灵魂绑定徽章的区块号，没有徽章时返回 `None`
+        fn badge(who: AccountId) -> Option<BlockNumber>;
+    }
+}
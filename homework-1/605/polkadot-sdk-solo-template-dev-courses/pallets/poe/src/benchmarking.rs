@@ -0,0 +1,1545 @@
+//! Benchmarking setup for pallet-poe
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as PoeModule;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    migrations::SteppedMigration,
+    pallet_prelude::*,
+    traits::{Currency, StorePreimage},
+    weights::WeightMeter,
+};
+use frame_system::{offchain::AppCrypto, pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::{
+    traits::{Bounded, IdentifyAccount, Saturating, Zero},
+    FixedU128, RuntimeAppPublic,
+};
+use sp_std::{vec, vec::Vec};
+use xcm::latest::{validate_send, Junction, Location, Xcm};
+
+const SEED: u32 = 0;
+
+/// 由种子 `[7; 32]` 生成的 ed25519 公钥，以及它对存证内容 `[1]` 的签名
+///
+/// 运行时中无法从种子生成密钥，基准测试使用预先计算好的签名。
+const AUTHOR_PUBLIC: [u8; 32] = [
+    0xea, 0x4a, 0x6c, 0x63, 0xe2, 0x9c, 0x52, 0x0a, 0xbe, 0xf5, 0x50, 0x7b,
+    0x13, 0x2e, 0xc5, 0xf9, 0x95, 0x47, 0x76, 0xae, 0xbe, 0xbe, 0x7b, 0x92,
+    0x42, 0x1e, 0xea, 0x69, 0x14, 0x46, 0xd2, 0x2c,
+];
+const AUTHOR_SIGNATURE: [u8; 64] = [
+    0x6d, 0x4c, 0x7f, 0x93, 0x6b, 0xb1, 0x53, 0x6c, 0x2b, 0x2a, 0x21, 0x62,
+    0x70, 0x81, 0x98, 0x91, 0x0e, 0x2e, 0xaa, 0x6a, 0x6d, 0x6a, 0xbc, 0x61,
+    0x3f, 0x56, 0x16, 0x40, 0x87, 0xc5, 0x09, 0xac, 0x61, 0x0f, 0xc8, 0x0e,
+    0x42, 0x96, 0xd0, 0xfc, 0x38, 0x21, 0xda, 0xc4, 0x90, 0xcf, 0xb7, 0x95,
+    0x10, 0x3c, 0x32, 0x5c, 0x5a, 0x0c, 0x11, 0x96, 0x78, 0x5c, 0x44, 0x88,
+    0xaa, 0x36, 0xa8, 0x0b,
+];
+
+/// 创建一个有足够余额支付押金和费用的账户
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, SEED);
+    T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 1_000u32.into());
+    who
+}
+
+/// 最长的存证内容，押金最高
+fn max_claim<T: Config>(fill: u8) -> BoundedVec<u8, T::MaxClaimLength> {
+    vec![fill; T::MaxClaimLength::get() as usize].try_into().unwrap()
+}
+
+/// 由 `owner` 创建一个最长的存证，返回其哈希
+fn create_max_claim<T: Config>(owner: &T::AccountId) -> ClaimHashOf<T> {
+    let claim = max_claim::<T>(1);
+    let claim_hash = PoeModule::<T>::claim_hash(&claim);
+    assert!(PoeModule::<T>::create_claim(RawOrigin::Signed(owner.clone()).into(), claim).is_ok());
+    claim_hash
+}
+
+/// 第 `i` 个内容互不相同的最长存证
+fn distinct_claim<T: Config>(i: u32) -> BoundedVec<u8, T::MaxClaimLength> {
+    let mut claim = max_claim::<T>(0);
+    claim.iter_mut().zip(i.to_le_bytes()).for_each(|(byte, b)| *byte = b);
+    claim
+}
+
+/// 由 `owner` 创建 `n` 个内容不同的存证，返回它们的哈希
+fn create_distinct_claims<T: Config>(owner: &T::AccountId, n: u32) -> BoundedVec<ClaimHashOf<T>, T::MaxBatchSize> {
+    let claims: BoundedVec<_, T::MaxBatchSize> = (0..n).map(distinct_claim::<T>).collect::<Vec<_>>().try_into().unwrap();
+    let claim_hashes = claims.iter().map(|claim| PoeModule::<T>::claim_hash(claim)).collect::<Vec<_>>();
+    assert!(PoeModule::<T>::create_claims(RawOrigin::Signed(owner.clone()).into(), claims).is_ok());
+    claim_hashes.try_into().unwrap()
+}
+
+/// 为 `claim_hash` 添加最多数量的背书，使删除存证时需要清除的背书最多
+fn attest_max<T: Config>(claim_hash: &ClaimHashOf<T>) {
+    for i in 0..T::MaxAttestations::get() {
+        let attester = funded_account::<T>("attester", i);
+        assert!(PoeModule::<T>::attest_claim(RawOrigin::Signed(attester).into(), *claim_hash).is_ok());
+    }
+}
+
+/// 为 `claim_hash` 授予最多数量的许可，使删除存证时需要清除的许可最多
+fn license_max<T: Config>(owner: &T::AccountId, claim_hash: &ClaimHashOf<T>) {
+    let expiry = frame_system::Pallet::<T>::block_number() + 1u32.into();
+    for i in 0..T::MaxLicenses::get() {
+        let licensee: T::AccountId = account("licensee", i, SEED);
+        let origin = RawOrigin::Signed(owner.clone()).into();
+        assert!(PoeModule::<T>::grant_license(origin, *claim_hash, licensee, *claim_hash, expiry).is_ok());
+    }
+}
+
+/// 让 `n` 个账户关注 `claim_hash`，转移或撤销时发出的事件带有这些关注者
+fn watch_n<T: Config>(claim_hash: &ClaimHashOf<T>, n: u32) {
+    for i in 0..n {
+        let watcher: T::AccountId = account("watcher", i, SEED);
+        assert!(PoeModule::<T>::watch_claim(RawOrigin::Signed(watcher).into(), *claim_hash).is_ok());
+    }
+}
+
+/// 让 `claim_hash` 过期：把区块号推进到它的过期区块
+fn expire_claim<T: Config>(claim_hash: &ClaimHashOf<T>) {
+    let expires_at: BlockNumberFor<T> = Proofs::<T>::get(claim_hash).unwrap().expires_at;
+    frame_system::Pallet::<T>::set_block_number(expires_at);
+}
+
+/// 配置了撤销等待期时 `revoke_claim` 总是失败，按整个区块的权重计费使其无法被打包
+fn ensure_instant_revocation<T: Config>() -> Result<(), BenchmarkError> {
+    if T::RevocationDelay::get().is_zero() {
+        Ok(())
+    } else {
+        Err(BenchmarkError::Override(frame_benchmarking::BenchmarkResult::from_weight(
+            T::BlockWeights::get().max_block,
+        )))
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    // 最坏情况：同一内容的旧存证已过期，需要先清理旧记录并退还旧所有者的押金
+    #[benchmark]
+    fn create_claim() {
+        let old_owner = funded_account::<T>("old_owner", 0);
+        let claim_hash = create_max_claim::<T>(&old_owner);
+        expire_claim::<T>(&claim_hash);
+
+        let caller = funded_account::<T>("caller", 0);
+        let claim = max_claim::<T>(1);
+        #[extrinsic_call]
+        create_claim(RawOrigin::Signed(caller.clone()), claim);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(caller));
+    }
+
+    #[benchmark]
+    fn revoke_claim() -> Result<(), BenchmarkError> {
+        ensure_instant_revocation::<T>()?;
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        attest_max::<T>(&claim_hash);
+        license_max::<T>(&caller, &claim_hash);
+        watch_n::<T>(&claim_hash, T::MaxWatchers::get());
+        #[extrinsic_call]
+        revoke_claim(RawOrigin::Signed(caller), claim_hash);
+
+        assert!(!Proofs::<T>::contains_key(claim_hash));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer_claim() {
+        let caller = funded_account::<T>("caller", 0);
+        let target = funded_account::<T>("target", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        watch_n::<T>(&claim_hash, T::MaxWatchers::get());
+        #[extrinsic_call]
+        transfer_claim(RawOrigin::Signed(caller), target.clone(), claim_hash);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(target));
+    }
+
+    // 最坏情况与 create_claim 相同：覆盖一个已过期的存证
+    #[benchmark]
+    fn create_claim_by_hash() {
+        let old_owner = funded_account::<T>("old_owner", 0);
+        let claim_hash = create_max_claim::<T>(&old_owner);
+        expire_claim::<T>(&claim_hash);
+
+        let caller = funded_account::<T>("caller", 0);
+        #[extrinsic_call]
+        create_claim_by_hash(RawOrigin::Signed(caller.clone()), claim_hash);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(caller));
+    }
+
+    #[benchmark]
+    fn set_claim_metadata() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        let title: ClaimTitleOf<T> = vec![b't'; T::MaxTitleLength::get() as usize].try_into().unwrap();
+        let uri: ClaimUriOf<T> = vec![b'u'; T::MaxUriLength::get() as usize].try_into().unwrap();
+        #[extrinsic_call]
+        set_claim_metadata(RawOrigin::Signed(caller), claim_hash, Some(title.clone()), Some(uri));
+
+        assert_eq!(Proofs::<T>::get(claim_hash).and_then(|details| details.title), Some(title));
+    }
+
+    #[benchmark]
+    fn renew_claim() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        let expires_at: BlockNumberFor<T> = Proofs::<T>::get(claim_hash).unwrap().expires_at;
+        #[extrinsic_call]
+        renew_claim(RawOrigin::Signed(caller), claim_hash);
+
+        assert_eq!(
+            Proofs::<T>::get(claim_hash).map(|details| details.expires_at),
+            Some(expires_at.saturating_add(T::ClaimLifetime::get()))
+        );
+    }
+
+    #[benchmark]
+    fn offer_claim() {
+        let caller = funded_account::<T>("caller", 0);
+        let target = funded_account::<T>("target", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        #[extrinsic_call]
+        offer_claim(RawOrigin::Signed(caller), claim_hash, target.clone());
+
+        assert_eq!(PendingTransfers::<T>::get(claim_hash).map(|offer| offer.to), Some(target));
+    }
+
+    #[benchmark]
+    fn accept_claim() {
+        let caller = funded_account::<T>("caller", 0);
+        let owner = funded_account::<T>("owner", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        assert!(PoeModule::<T>::offer_claim(RawOrigin::Signed(owner).into(), claim_hash, caller.clone()).is_ok());
+        #[extrinsic_call]
+        accept_claim(RawOrigin::Signed(caller.clone()), claim_hash);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(caller));
+    }
+
+    #[benchmark]
+    fn cancel_offer() {
+        let caller = funded_account::<T>("caller", 0);
+        let target = funded_account::<T>("target", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        assert!(PoeModule::<T>::offer_claim(RawOrigin::Signed(caller.clone()).into(), claim_hash, target).is_ok());
+        #[extrinsic_call]
+        cancel_offer(RawOrigin::Signed(caller), claim_hash);
+
+        assert!(!PendingTransfers::<T>::contains_key(claim_hash));
+    }
+
+    #[benchmark]
+    fn approve_transfer() {
+        let caller = funded_account::<T>("caller", 0);
+        let delegate = funded_account::<T>("delegate", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        #[extrinsic_call]
+        approve_transfer(RawOrigin::Signed(caller), claim_hash, delegate.clone());
+
+        assert_eq!(Approvals::<T>::get(claim_hash), Some(delegate));
+    }
+
+    #[benchmark]
+    fn cancel_approval() {
+        let caller = funded_account::<T>("caller", 0);
+        let delegate = funded_account::<T>("delegate", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        assert!(PoeModule::<T>::approve_transfer(RawOrigin::Signed(caller.clone()).into(), claim_hash, delegate).is_ok());
+        #[extrinsic_call]
+        cancel_approval(RawOrigin::Signed(caller), claim_hash);
+
+        assert!(!Approvals::<T>::contains_key(claim_hash));
+    }
+
+    #[benchmark]
+    fn transfer_claim_from() {
+        let owner = funded_account::<T>("owner", 0);
+        let caller = funded_account::<T>("caller", 0);
+        let target = funded_account::<T>("target", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        assert!(PoeModule::<T>::approve_transfer(RawOrigin::Signed(owner).into(), claim_hash, caller.clone()).is_ok());
+        #[extrinsic_call]
+        transfer_claim_from(RawOrigin::Signed(caller), claim_hash, target.clone());
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(target));
+    }
+
+    #[benchmark]
+    fn set_operator() {
+        let caller = funded_account::<T>("caller", 0);
+        let operator = funded_account::<T>("operator", 0);
+        #[extrinsic_call]
+        set_operator(RawOrigin::Signed(caller.clone()), operator.clone(), true);
+
+        assert!(Operators::<T>::contains_key(caller, operator));
+    }
+
+    #[benchmark]
+    fn force_revoke_claim() -> Result<(), BenchmarkError> {
+        let owner = funded_account::<T>("owner", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        attest_max::<T>(&claim_hash);
+        license_max::<T>(&owner, &claim_hash);
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        force_revoke_claim(origin as T::RuntimeOrigin, claim_hash);
+
+        assert!(!Proofs::<T>::contains_key(claim_hash));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_transfer_claim() -> Result<(), BenchmarkError> {
+        let owner = funded_account::<T>("owner", 0);
+        let target = funded_account::<T>("target", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        force_transfer_claim(origin as T::RuntimeOrigin, claim_hash, target.clone());
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(target));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn pause() {
+        #[extrinsic_call]
+        pause(RawOrigin::Root);
+
+        assert!(Paused::<T>::get());
+    }
+
+    #[benchmark]
+    fn unpause() {
+        Paused::<T>::put(true);
+        #[extrinsic_call]
+        unpause(RawOrigin::Root);
+
+        assert!(!Paused::<T>::get());
+    }
+
+    #[benchmark]
+    fn create_claims(n: Linear<1, { T::MaxBatchSize::get() }>) {
+        let caller = funded_account::<T>("caller", 0);
+        let claims: BoundedVec<_, T::MaxBatchSize> =
+            (0..n).map(distinct_claim::<T>).collect::<Vec<_>>().try_into().unwrap();
+        #[extrinsic_call]
+        create_claims(RawOrigin::Signed(caller.clone()), claims);
+
+        assert_eq!(PoeModule::<T>::claims_of(&caller).len() as u32, n);
+    }
+
+    #[benchmark]
+    fn revoke_claims(n: Linear<1, { T::MaxBatchSize::get() }>) -> Result<(), BenchmarkError> {
+        ensure_instant_revocation::<T>()?;
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hashes = create_distinct_claims::<T>(&caller, n);
+        #[extrinsic_call]
+        revoke_claims(RawOrigin::Signed(caller.clone()), claim_hashes);
+
+        assert!(PoeModule::<T>::claims_of(&caller).is_empty());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer_claims(n: Linear<1, { T::MaxBatchSize::get() }>) {
+        let caller = funded_account::<T>("caller", 0);
+        let target = funded_account::<T>("target", 0);
+        let claim_hashes = create_distinct_claims::<T>(&caller, n);
+        #[extrinsic_call]
+        transfer_claims(RawOrigin::Signed(caller), target.clone(), claim_hashes);
+
+        assert_eq!(PoeModule::<T>::claims_of(&target).len() as u32, n);
+    }
+
+    #[benchmark]
+    fn set_allow_sponsorship() {
+        let caller = funded_account::<T>("caller", 0);
+        #[extrinsic_call]
+        set_allow_sponsorship(RawOrigin::Signed(caller.clone()), true);
+
+        assert!(AllowSponsorship::<T>::get(caller));
+    }
+
+    // 最坏情况与 create_claim 相同：覆盖一个已过期的存证
+    #[benchmark]
+    fn create_claim_for() {
+        let old_owner = funded_account::<T>("old_owner", 0);
+        let claim_hash = create_max_claim::<T>(&old_owner);
+        expire_claim::<T>(&claim_hash);
+
+        let caller = funded_account::<T>("caller", 0);
+        let owner: T::AccountId = account("owner", 0, SEED);
+        AllowSponsorship::<T>::insert(&owner, true);
+        let claim = max_claim::<T>(1);
+        #[extrinsic_call]
+        create_claim_for(RawOrigin::Signed(caller), owner.clone(), claim);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(owner));
+    }
+
+    #[benchmark]
+    fn commit_claim() {
+        let caller = funded_account::<T>("caller", 0);
+        let commitment = PoeModule::<T>::commitment_of(&max_claim::<T>(1), &[0u8; 32]);
+        #[extrinsic_call]
+        commit_claim(RawOrigin::Signed(caller.clone()), commitment);
+
+        assert!(Commitments::<T>::contains_key(caller, commitment));
+    }
+
+    // 最坏情况：同一内容已被他人在承诺之后注册，需要先撤销
+    #[benchmark]
+    fn reveal_claim() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim = max_claim::<T>(1);
+        let salt = [0u8; 32];
+        let commitment = PoeModule::<T>::commitment_of(&claim, &salt);
+        assert!(PoeModule::<T>::commit_claim(RawOrigin::Signed(caller.clone()).into(), commitment).is_ok());
+
+        let now = frame_system::Pallet::<T>::block_number();
+        frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinRevealDelay::get()).saturating_add(1u32.into()));
+        let front_runner = funded_account::<T>("front_runner", 0);
+        let claim_hash = create_max_claim::<T>(&front_runner);
+        #[extrinsic_call]
+        reveal_claim(RawOrigin::Signed(caller.clone()), claim, salt);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(caller));
+    }
+
+    #[benchmark]
+    fn freeze_claim() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        #[extrinsic_call]
+        freeze_claim(RawOrigin::Signed(caller), claim_hash);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.status), Some(ClaimStatus::Frozen));
+    }
+
+    #[benchmark]
+    fn unfreeze_claim() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        assert!(PoeModule::<T>::freeze_claim(RawOrigin::Signed(caller.clone()).into(), claim_hash).is_ok());
+        #[extrinsic_call]
+        unfreeze_claim(RawOrigin::Signed(caller), claim_hash);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.status), Some(ClaimStatus::Active));
+    }
+
+    #[benchmark]
+    fn raise_dispute() {
+        let owner = funded_account::<T>("owner", 0);
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        #[extrinsic_call]
+        raise_dispute(RawOrigin::Signed(caller), claim_hash, claim_hash);
+
+        assert!(Disputes::<T>::contains_key(claim_hash));
+    }
+
+    // 最坏情况：支持发起方并把存证转给发起方
+    #[benchmark]
+    fn resolve_dispute() -> Result<(), BenchmarkError> {
+        let owner = funded_account::<T>("owner", 0);
+        let challenger = funded_account::<T>("challenger", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        assert!(PoeModule::<T>::raise_dispute(RawOrigin::Signed(challenger.clone()).into(), claim_hash, claim_hash).is_ok());
+        let origin = T::ArbitrationOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        resolve_dispute(origin as T::RuntimeOrigin, claim_hash, DisputeResolution::TransferToChallenger);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(challenger));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn attest_claim() {
+        let owner = funded_account::<T>("owner", 0);
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        #[extrinsic_call]
+        attest_claim(RawOrigin::Signed(caller.clone()), claim_hash);
+
+        assert!(Attestations::<T>::contains_key(claim_hash, caller));
+    }
+
+    #[benchmark]
+    fn revoke_attestation() {
+        let owner = funded_account::<T>("owner", 0);
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        assert!(PoeModule::<T>::attest_claim(RawOrigin::Signed(caller.clone()).into(), claim_hash).is_ok());
+        #[extrinsic_call]
+        revoke_attestation(RawOrigin::Signed(caller.clone()), claim_hash);
+
+        assert!(!Attestations::<T>::contains_key(claim_hash, caller));
+    }
+
+    #[benchmark]
+    fn register_notary() {
+        let caller = funded_account::<T>("caller", 0);
+        #[extrinsic_call]
+        register_notary(RawOrigin::Signed(caller.clone()));
+
+        assert!(Notaries::<T>::contains_key(caller));
+    }
+
+    #[benchmark]
+    fn unregister_notary() {
+        let caller = funded_account::<T>("caller", 0);
+        assert!(PoeModule::<T>::register_notary(RawOrigin::Signed(caller.clone()).into()).is_ok());
+        #[extrinsic_call]
+        unregister_notary(RawOrigin::Signed(caller.clone()));
+
+        assert!(!Notaries::<T>::contains_key(caller));
+    }
+
+    #[benchmark]
+    fn notarize_claim() {
+        let owner = funded_account::<T>("owner", 0);
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        assert!(PoeModule::<T>::register_notary(RawOrigin::Signed(caller.clone()).into()).is_ok());
+        #[extrinsic_call]
+        notarize_claim(RawOrigin::Signed(caller.clone()), claim_hash);
+
+        assert_eq!(Notarizations::<T>::get(claim_hash), Some(caller));
+    }
+
+    #[benchmark]
+    fn slash_notary() -> Result<(), BenchmarkError> {
+        let owner = funded_account::<T>("owner", 0);
+        let notary = funded_account::<T>("notary", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        assert!(PoeModule::<T>::register_notary(RawOrigin::Signed(notary.clone()).into()).is_ok());
+        assert!(PoeModule::<T>::notarize_claim(RawOrigin::Signed(notary.clone()).into(), claim_hash).is_ok());
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        slash_notary(origin as T::RuntimeOrigin, notary.clone(), claim_hash);
+
+        assert!(!Notaries::<T>::contains_key(notary));
+        assert!(!Notarizations::<T>::contains_key(claim_hash));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn supersede_claim() {
+        let caller = funded_account::<T>("caller", 0);
+        let old_claim_hash = create_max_claim::<T>(&caller);
+        let new_claim = max_claim::<T>(2);
+        let new_claim_hash = PoeModule::<T>::claim_hash(&new_claim);
+        #[extrinsic_call]
+        supersede_claim(RawOrigin::Signed(caller), old_claim_hash, new_claim);
+
+        assert_eq!(Supersedes::<T>::get(new_claim_hash), Some(old_claim_hash));
+    }
+
+    #[benchmark]
+    fn create_child_claim() {
+        let caller = funded_account::<T>("caller", 0);
+        let parent_hash = create_max_claim::<T>(&caller);
+        let claim = max_claim::<T>(2);
+        let claim_hash = PoeModule::<T>::claim_hash(&claim);
+        #[extrinsic_call]
+        create_child_claim(RawOrigin::Signed(caller), parent_hash, claim);
+
+        assert_eq!(ParentOf::<T>::get(claim_hash), Some(parent_hash));
+    }
+
+    #[benchmark]
+    fn create_collection() {
+        let caller = funded_account::<T>("caller", 0);
+        let metadata: CollectionMetadataOf<T> =
+            vec![1; T::MaxCollectionMetadataLength::get() as usize].try_into().unwrap();
+        #[extrinsic_call]
+        create_collection(RawOrigin::Signed(caller.clone()), metadata);
+
+        assert_eq!(Collections::<T>::get(0).map(|collection| collection.owner), Some(caller));
+    }
+
+    #[benchmark]
+    fn add_claim_to_collection() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        assert!(PoeModule::<T>::create_collection(RawOrigin::Signed(caller.clone()).into(), Default::default()).is_ok());
+        #[extrinsic_call]
+        add_claim_to_collection(RawOrigin::Signed(caller), 0, claim_hash);
+
+        assert_eq!(ClaimCollection::<T>::get(claim_hash), Some(0));
+    }
+
+    #[benchmark]
+    fn transfer_collection(n: Linear<1, { T::MaxCollectionSize::get() }>) {
+        let caller = funded_account::<T>("caller", 0);
+        let target = funded_account::<T>("target", 0);
+        assert!(PoeModule::<T>::create_collection(RawOrigin::Signed(caller.clone()).into(), Default::default()).is_ok());
+        for i in 0..n {
+            let claim = distinct_claim::<T>(i);
+            let claim_hash = PoeModule::<T>::claim_hash(&claim);
+            assert!(PoeModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim).is_ok());
+            assert!(PoeModule::<T>::add_claim_to_collection(RawOrigin::Signed(caller.clone()).into(), 0, claim_hash).is_ok());
+        }
+        #[extrinsic_call]
+        transfer_collection(RawOrigin::Signed(caller), 0, target.clone());
+
+        assert_eq!(PoeModule::<T>::claims_of(&target).len() as u32, n);
+    }
+
+    // 最坏情况：占用一个已失效的最长命名空间，需要退还原所有者的押金
+    #[benchmark]
+    fn register_namespace() {
+        let prefix: NamespaceOf<T> = vec![1; T::MaxNamespaceLength::get() as usize].try_into().unwrap();
+        let old_owner = funded_account::<T>("old_owner", 0);
+        assert!(PoeModule::<T>::register_namespace(RawOrigin::Signed(old_owner).into(), prefix.clone()).is_ok());
+        let expires_at = Namespaces::<T>::get(&prefix).unwrap().expires_at;
+        frame_system::Pallet::<T>::set_block_number(expires_at);
+
+        let caller = funded_account::<T>("caller", 0);
+        #[extrinsic_call]
+        register_namespace(RawOrigin::Signed(caller.clone()), prefix.clone());
+
+        assert_eq!(Namespaces::<T>::get(&prefix).map(|namespace| namespace.owner), Some(caller));
+    }
+
+    #[benchmark]
+    fn transfer_namespace() {
+        let prefix: NamespaceOf<T> = vec![1; T::MaxNamespaceLength::get() as usize].try_into().unwrap();
+        let caller = funded_account::<T>("caller", 0);
+        let target = funded_account::<T>("target", 0);
+        assert!(PoeModule::<T>::register_namespace(RawOrigin::Signed(caller.clone()).into(), prefix.clone()).is_ok());
+        #[extrinsic_call]
+        transfer_namespace(RawOrigin::Signed(caller), prefix.clone(), target.clone());
+
+        assert_eq!(Namespaces::<T>::get(&prefix).map(|namespace| namespace.owner), Some(target));
+    }
+
+    #[benchmark]
+    fn ban_claim_hash() -> Result<(), BenchmarkError> {
+        let claim_hash = PoeModule::<T>::claim_hash(&max_claim::<T>(1));
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        ban_claim_hash(origin as T::RuntimeOrigin, claim_hash);
+
+        assert!(Banned::<T>::contains_key(claim_hash));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unban_claim_hash() -> Result<(), BenchmarkError> {
+        let claim_hash = PoeModule::<T>::claim_hash(&max_claim::<T>(1));
+        Banned::<T>::insert(claim_hash, ());
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        unban_claim_hash(origin as T::RuntimeOrigin, claim_hash);
+
+        assert!(!Banned::<T>::contains_key(claim_hash));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn ban_account() -> Result<(), BenchmarkError> {
+        let who = funded_account::<T>("who", 0);
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        ban_account(origin as T::RuntimeOrigin, who.clone());
+
+        assert!(BannedAccounts::<T>::contains_key(who));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unban_account() -> Result<(), BenchmarkError> {
+        let who = funded_account::<T>("who", 0);
+        BannedAccounts::<T>::insert(&who, ());
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        unban_account(origin as T::RuntimeOrigin, who.clone());
+
+        assert!(!BannedAccounts::<T>::contains_key(who));
+        Ok(())
+    }
+
+    // 最坏情况：共同所有者列表只差一个就满，需要检查全部已有的共同所有者
+    #[benchmark]
+    fn add_co_owner() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        for i in 1..T::MaxCoOwners::get() {
+            let co_owner = funded_account::<T>("co_owner", i);
+            assert!(PoeModule::<T>::add_co_owner(RawOrigin::Signed(caller.clone()).into(), claim_hash, co_owner).is_ok());
+        }
+        let co_owner = funded_account::<T>("co_owner", 0);
+        #[extrinsic_call]
+        add_co_owner(RawOrigin::Signed(caller), claim_hash, co_owner.clone());
+
+        assert!(CoOwners::<T>::get(claim_hash).contains(&co_owner));
+    }
+
+    #[benchmark]
+    fn remove_co_owner() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        for i in 0..T::MaxCoOwners::get() {
+            let co_owner = funded_account::<T>("co_owner", i);
+            assert!(PoeModule::<T>::add_co_owner(RawOrigin::Signed(caller.clone()).into(), claim_hash, co_owner).is_ok());
+        }
+        let co_owner = funded_account::<T>("co_owner", T::MaxCoOwners::get() - 1);
+        assert!(PoeModule::<T>::approve_co_owner_action(
+            RawOrigin::Signed(co_owner.clone()).into(),
+            claim_hash,
+            CoOwnerAction::Revoke
+        )
+        .is_ok());
+        #[extrinsic_call]
+        remove_co_owner(RawOrigin::Signed(caller), claim_hash, co_owner.clone());
+
+        assert!(!CoOwners::<T>::get(claim_hash).contains(&co_owner));
+    }
+
+    #[benchmark]
+    fn approve_co_owner_action() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        for i in 0..T::MaxCoOwners::get() {
+            let co_owner = funded_account::<T>("co_owner", i);
+            assert!(PoeModule::<T>::add_co_owner(RawOrigin::Signed(caller.clone()).into(), claim_hash, co_owner).is_ok());
+        }
+        let co_owner = funded_account::<T>("co_owner", T::MaxCoOwners::get() - 1);
+        #[extrinsic_call]
+        approve_co_owner_action(RawOrigin::Signed(co_owner.clone()), claim_hash, CoOwnerAction::Transfer(caller));
+
+        assert!(CoOwnerApprovals::<T>::contains_key(claim_hash, co_owner));
+    }
+
+    #[benchmark]
+    fn set_revocation_threshold() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        let signatories: BoundedVec<_, T::MaxSignatories> =
+            (0..T::MaxSignatories::get()).map(|i| funded_account::<T>("signatory", i)).collect::<Vec<_>>().try_into().unwrap();
+        let threshold = T::MaxSignatories::get();
+        #[extrinsic_call]
+        set_revocation_threshold(RawOrigin::Signed(caller), claim_hash, signatories, threshold);
+
+        assert!(RevocationGuards::<T>::contains_key(claim_hash));
+    }
+
+    // 最坏情况：最后一个签名人批准，需要检查前面全部的批准
+    #[benchmark]
+    fn approve_revocation() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        let signatories: BoundedVec<_, T::MaxSignatories> =
+            (0..T::MaxSignatories::get()).map(|i| funded_account::<T>("signatory", i)).collect::<Vec<_>>().try_into().unwrap();
+        let threshold = T::MaxSignatories::get();
+        assert!(PoeModule::<T>::set_revocation_threshold(
+            RawOrigin::Signed(caller).into(),
+            claim_hash,
+            signatories.clone(),
+            threshold
+        )
+        .is_ok());
+        let (last, others) = signatories.split_last().unwrap();
+        for signatory in others {
+            assert!(PoeModule::<T>::approve_revocation(RawOrigin::Signed(signatory.clone()).into(), claim_hash).is_ok());
+        }
+        #[extrinsic_call]
+        approve_revocation(RawOrigin::Signed(last.clone()), claim_hash);
+
+        assert_eq!(RevocationApprovals::<T>::get(claim_hash).len() as u32, threshold);
+    }
+
+    #[benchmark]
+    fn set_recovery_config() {
+        let caller = funded_account::<T>("caller", 0);
+        let guardians: BoundedVec<_, T::MaxGuardians> =
+            (0..T::MaxGuardians::get()).map(|i| funded_account::<T>("guardian", i)).collect::<Vec<_>>().try_into().unwrap();
+        let threshold = T::MaxGuardians::get();
+        #[extrinsic_call]
+        set_recovery_config(RawOrigin::Signed(caller.clone()), guardians, threshold, T::MinRecoveryDelay::get());
+
+        assert!(RecoveryConfigs::<T>::contains_key(caller));
+    }
+
+    // 最坏情况：最后一个监护人发起，恢复随之启动
+    #[benchmark]
+    fn initiate_recovery() {
+        let lost = funded_account::<T>("lost", 0);
+        let new_owner = funded_account::<T>("new_owner", 0);
+        let guardians: Vec<_> = (0..T::MaxGuardians::get()).map(|i| funded_account::<T>("guardian", i)).collect();
+        assert!(PoeModule::<T>::set_recovery_config(
+            RawOrigin::Signed(lost.clone()).into(),
+            guardians.clone().try_into().unwrap(),
+            T::MaxGuardians::get(),
+            T::MinRecoveryDelay::get()
+        )
+        .is_ok());
+        let (last, others) = guardians.split_last().unwrap();
+        for guardian in others {
+            assert!(PoeModule::<T>::initiate_recovery(RawOrigin::Signed(guardian.clone()).into(), lost.clone(), new_owner.clone()).is_ok());
+        }
+        #[extrinsic_call]
+        initiate_recovery(RawOrigin::Signed(last.clone()), lost.clone(), new_owner);
+
+        assert!(ActiveRecoveries::<T>::get(lost).unwrap().started_at.is_some());
+    }
+
+    #[benchmark]
+    fn veto_recovery() {
+        let lost = funded_account::<T>("lost", 0);
+        let guardian = funded_account::<T>("guardian", 0);
+        assert!(PoeModule::<T>::set_recovery_config(
+            RawOrigin::Signed(lost.clone()).into(),
+            vec![guardian.clone()].try_into().unwrap(),
+            1,
+            T::MinRecoveryDelay::get()
+        )
+        .is_ok());
+        assert!(PoeModule::<T>::initiate_recovery(RawOrigin::Signed(guardian.clone()).into(), lost.clone(), guardian).is_ok());
+        #[extrinsic_call]
+        veto_recovery(RawOrigin::Signed(lost.clone()));
+
+        assert!(!ActiveRecoveries::<T>::contains_key(lost));
+    }
+
+    #[benchmark]
+    fn complete_recovery(n: Linear<1, { T::MaxClaimsPerAccount::get() }>) {
+        let lost = funded_account::<T>("lost", 0);
+        let new_owner = funded_account::<T>("new_owner", 0);
+        let guardian = funded_account::<T>("guardian", 0);
+        for i in 0..n {
+            assert!(PoeModule::<T>::create_claim(RawOrigin::Signed(lost.clone()).into(), distinct_claim::<T>(i)).is_ok());
+            // 准备数据不受每个区块的创建数量上限限制
+            ClaimsThisBlock::<T>::kill();
+        }
+        assert!(PoeModule::<T>::set_recovery_config(
+            RawOrigin::Signed(lost.clone()).into(),
+            vec![guardian.clone()].try_into().unwrap(),
+            1,
+            T::MinRecoveryDelay::get()
+        )
+        .is_ok());
+        assert!(PoeModule::<T>::initiate_recovery(RawOrigin::Signed(guardian.clone()).into(), lost.clone(), new_owner.clone()).is_ok());
+        let ready_at = frame_system::Pallet::<T>::block_number().saturating_add(T::MinRecoveryDelay::get());
+        frame_system::Pallet::<T>::set_block_number(ready_at);
+        #[extrinsic_call]
+        complete_recovery(RawOrigin::Signed(guardian), lost);
+
+        assert_eq!(PoeModule::<T>::claims_of(&new_owner).len() as u32, n);
+    }
+
+    #[benchmark]
+    fn set_inheritor() {
+        let caller = funded_account::<T>("caller", 0);
+        let heir = funded_account::<T>("heir", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        #[extrinsic_call]
+        set_inheritor(RawOrigin::Signed(caller), claim_hash, heir, T::MinInactivityPeriod::get());
+
+        assert!(Inheritors::<T>::contains_key(claim_hash));
+    }
+
+    #[benchmark]
+    fn ping() {
+        let caller = funded_account::<T>("caller", 0);
+        #[extrinsic_call]
+        ping(RawOrigin::Signed(caller.clone()));
+
+        assert!(LastActivity::<T>::contains_key(caller));
+    }
+
+    #[benchmark]
+    fn claim_inheritance() {
+        let owner = funded_account::<T>("owner", 0);
+        let heir = funded_account::<T>("heir", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        assert!(PoeModule::<T>::set_inheritor(
+            RawOrigin::Signed(owner).into(),
+            claim_hash,
+            heir.clone(),
+            T::MinInactivityPeriod::get()
+        )
+        .is_ok());
+        let inactive_at = frame_system::Pallet::<T>::block_number().saturating_add(T::MinInactivityPeriod::get());
+        frame_system::Pallet::<T>::set_block_number(inactive_at);
+        #[extrinsic_call]
+        claim_inheritance(RawOrigin::Signed(heir.clone()), claim_hash);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(heir));
+    }
+
+    // 最坏情况：目标区块的预约队列只差一个就满
+    #[benchmark]
+    fn schedule_transfer() {
+        let caller = funded_account::<T>("caller", 0);
+        let target = funded_account::<T>("target", 0);
+        let at = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+        for i in 1..T::MaxScheduledPerBlock::get() {
+            let claim = distinct_claim::<T>(i);
+            let claim_hash = PoeModule::<T>::claim_hash(&claim);
+            assert!(PoeModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim).is_ok());
+            assert!(PoeModule::<T>::schedule_transfer(RawOrigin::Signed(caller.clone()).into(), claim_hash, target.clone(), at).is_ok());
+        }
+        let claim_hash = create_max_claim::<T>(&caller);
+        #[extrinsic_call]
+        schedule_transfer(RawOrigin::Signed(caller), claim_hash, target, at);
+
+        assert!(ScheduledTransfers::<T>::contains_key(claim_hash));
+    }
+
+    #[benchmark]
+    fn cancel_scheduled_transfer() {
+        let caller = funded_account::<T>("caller", 0);
+        let target = funded_account::<T>("target", 0);
+        let at = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+        let claim_hash = create_max_claim::<T>(&caller);
+        assert!(PoeModule::<T>::schedule_transfer(RawOrigin::Signed(caller.clone()).into(), claim_hash, target, at).is_ok());
+        #[extrinsic_call]
+        cancel_scheduled_transfer(RawOrigin::Signed(caller), claim_hash);
+
+        assert!(!ScheduledTransfers::<T>::contains_key(claim_hash));
+    }
+
+    #[benchmark]
+    fn begin_revoke() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        #[extrinsic_call]
+        begin_revoke(RawOrigin::Signed(caller), claim_hash);
+
+        assert!(PendingRevocations::<T>::contains_key(claim_hash));
+    }
+
+    // 最坏情况与 revoke_claim 相同：需要清除全部背书
+    #[benchmark]
+    fn finalize_revoke() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        attest_max::<T>(&claim_hash);
+        license_max::<T>(&caller, &claim_hash);
+        assert!(PoeModule::<T>::begin_revoke(RawOrigin::Signed(caller.clone()).into(), claim_hash).is_ok());
+        let ready_at = frame_system::Pallet::<T>::block_number().saturating_add(T::RevocationDelay::get());
+        frame_system::Pallet::<T>::set_block_number(ready_at);
+        #[extrinsic_call]
+        finalize_revoke(RawOrigin::Signed(caller), claim_hash);
+
+        assert!(!Proofs::<T>::contains_key(claim_hash));
+    }
+
+    #[benchmark]
+    fn cancel_revoke() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        assert!(PoeModule::<T>::begin_revoke(RawOrigin::Signed(caller.clone()).into(), claim_hash).is_ok());
+        #[extrinsic_call]
+        cancel_revoke(RawOrigin::Signed(caller), claim_hash);
+
+        assert!(!PendingRevocations::<T>::contains_key(claim_hash));
+    }
+
+    #[benchmark]
+    fn list_claim_for_sale() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        #[extrinsic_call]
+        list_claim_for_sale(RawOrigin::Signed(caller), claim_hash, 1_000u32.into());
+
+        assert!(Listings::<T>::contains_key(claim_hash));
+    }
+
+    #[benchmark]
+    fn buy_claim() {
+        let seller = funded_account::<T>("seller", 0);
+        let buyer = funded_account::<T>("buyer", 0);
+        let beneficiary = funded_account::<T>("beneficiary", 0);
+        let claim_hash = create_max_claim::<T>(&seller);
+        let price: BalanceOf<T> = 1_000u32.into();
+        assert!(PoeModule::<T>::set_royalty(RawOrigin::Signed(seller.clone()).into(), claim_hash, beneficiary, T::MaxRoyalty::get()).is_ok());
+        assert!(PoeModule::<T>::list_claim_for_sale(RawOrigin::Signed(seller).into(), claim_hash, price).is_ok());
+        #[extrinsic_call]
+        buy_claim(RawOrigin::Signed(buyer.clone()), claim_hash, price);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(buyer));
+    }
+
+    #[benchmark]
+    fn buy_claim_escrowed() {
+        let seller = funded_account::<T>("seller", 0);
+        let buyer = funded_account::<T>("buyer", 0);
+        let claim_hash = create_max_claim::<T>(&seller);
+        let price: BalanceOf<T> = 1_000u32.into();
+        assert!(PoeModule::<T>::list_claim_for_sale(RawOrigin::Signed(seller).into(), claim_hash, price).is_ok());
+        #[extrinsic_call]
+        buy_claim_escrowed(RawOrigin::Signed(buyer.clone()), claim_hash, price);
+
+        assert_eq!(Escrows::<T>::get(claim_hash).map(|escrow| escrow.buyer), Some(buyer));
+    }
+
+    #[benchmark]
+    fn dispute_escrow() {
+        let seller = funded_account::<T>("seller", 0);
+        let buyer = funded_account::<T>("buyer", 0);
+        let claim_hash = create_max_claim::<T>(&seller);
+        let price: BalanceOf<T> = 1_000u32.into();
+        assert!(PoeModule::<T>::list_claim_for_sale(RawOrigin::Signed(seller).into(), claim_hash, price).is_ok());
+        assert!(PoeModule::<T>::buy_claim_escrowed(RawOrigin::Signed(buyer.clone()).into(), claim_hash, price).is_ok());
+        #[extrinsic_call]
+        dispute_escrow(RawOrigin::Signed(buyer), claim_hash, claim_hash);
+
+        assert!(Escrows::<T>::get(claim_hash).map_or(false, |escrow| escrow.dispute.is_some()));
+    }
+
+    // 最坏情况：退款并把存证归还卖家
+    #[benchmark]
+    fn resolve_escrow() -> Result<(), BenchmarkError> {
+        let seller = funded_account::<T>("seller", 0);
+        let buyer = funded_account::<T>("buyer", 0);
+        let claim_hash = create_max_claim::<T>(&seller);
+        let price: BalanceOf<T> = 1_000u32.into();
+        let origin = RawOrigin::Signed(seller.clone()).into();
+        assert!(PoeModule::<T>::list_claim_for_sale(origin, claim_hash, price).is_ok());
+        assert!(PoeModule::<T>::buy_claim_escrowed(RawOrigin::Signed(buyer.clone()).into(), claim_hash, price).is_ok());
+        assert!(PoeModule::<T>::dispute_escrow(RawOrigin::Signed(buyer).into(), claim_hash, claim_hash).is_ok());
+        let origin = T::ArbitrationOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        resolve_escrow(origin as T::RuntimeOrigin, claim_hash, true);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(seller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_bundle(n: Linear<1, { T::MaxBundleSize::get() }>) {
+        let caller = funded_account::<T>("caller", 0);
+        let claims: Vec<_> = (0..n)
+            .map(|i| {
+                let claim = distinct_claim::<T>(i);
+                let claim_hash = PoeModule::<T>::claim_hash(&claim);
+                assert!(PoeModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim).is_ok());
+                claim_hash
+            })
+            .collect();
+        #[extrinsic_call]
+        create_bundle(RawOrigin::Signed(caller), claims.try_into().unwrap(), 1_000u32.into());
+
+        assert_eq!(BundleClaims::<T>::get(0).len() as u32, n);
+    }
+
+    // 最坏情况：每个存证都设置了版税
+    #[benchmark]
+    fn buy_bundle(n: Linear<1, { T::MaxBundleSize::get() }>) {
+        let seller = funded_account::<T>("seller", 0);
+        let buyer = funded_account::<T>("buyer", 0);
+        let beneficiary = funded_account::<T>("beneficiary", 0);
+        let claims: Vec<_> = (0..n)
+            .map(|i| {
+                let claim = distinct_claim::<T>(i);
+                let claim_hash = PoeModule::<T>::claim_hash(&claim);
+                let origin = RawOrigin::Signed(seller.clone());
+                assert!(PoeModule::<T>::create_claim(origin.clone().into(), claim).is_ok());
+                let rate = T::MaxRoyalty::get();
+                assert!(PoeModule::<T>::set_royalty(origin.into(), claim_hash, beneficiary.clone(), rate).is_ok());
+                claim_hash
+            })
+            .collect();
+        let price: BalanceOf<T> = 1_000_000u32.into();
+        let origin = RawOrigin::Signed(seller).into();
+        assert!(PoeModule::<T>::create_bundle(origin, claims.try_into().unwrap(), price).is_ok());
+        #[extrinsic_call]
+        buy_bundle(RawOrigin::Signed(buyer.clone()), 0, price);
+
+        assert_eq!(PoeModule::<T>::claims_of(&buyer).len() as u32, n);
+    }
+
+    // 最坏情况：移出最后一个存证，打包随之取消
+    #[benchmark]
+    fn unbundle_claim() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        let claims = vec![claim_hash].try_into().unwrap();
+        assert!(PoeModule::<T>::create_bundle(RawOrigin::Signed(caller.clone()).into(), claims, 1_000u32.into()).is_ok());
+        #[extrinsic_call]
+        unbundle_claim(RawOrigin::Signed(caller), 0, claim_hash, None);
+
+        assert!(!Bundles::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn cancel_listing() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        assert!(PoeModule::<T>::list_claim_for_sale(RawOrigin::Signed(caller.clone()).into(), claim_hash, 1_000u32.into()).is_ok());
+        #[extrinsic_call]
+        cancel_listing(RawOrigin::Signed(caller), claim_hash);
+
+        assert!(!Listings::<T>::contains_key(claim_hash));
+    }
+
+    #[benchmark]
+    fn list_claim_dutch() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        #[extrinsic_call]
+        list_claim_dutch(RawOrigin::Signed(caller), claim_hash, 2_000u32.into(), 1_000u32.into(), 10u32.into());
+
+        assert!(Listings::<T>::contains_key(claim_hash));
+    }
+
+    #[benchmark]
+    fn set_royalty() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        #[extrinsic_call]
+        set_royalty(RawOrigin::Signed(caller.clone()), claim_hash, caller, T::MaxRoyalty::get());
+
+        assert!(Royalties::<T>::contains_key(claim_hash));
+    }
+
+    // 最坏情况：结束区块的拍卖队列只差一个就满
+    #[benchmark]
+    fn start_auction() {
+        let caller = funded_account::<T>("caller", 0);
+        let duration: BlockNumberFor<T> = 10u32.into();
+        for i in 1..T::MaxAuctionsPerBlock::get() {
+            let claim = distinct_claim::<T>(i);
+            let claim_hash = PoeModule::<T>::claim_hash(&claim);
+            assert!(PoeModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim).is_ok());
+            assert!(PoeModule::<T>::start_auction(RawOrigin::Signed(caller.clone()).into(), claim_hash, 1_000u32.into(), duration).is_ok());
+        }
+        let claim_hash = create_max_claim::<T>(&caller);
+        #[extrinsic_call]
+        start_auction(RawOrigin::Signed(caller), claim_hash, 1_000u32.into(), duration);
+
+        assert!(Auctions::<T>::contains_key(claim_hash));
+    }
+
+    // 最坏情况：已有出价，需要退还给上一个出价人
+    #[benchmark]
+    fn bid() {
+        let seller = funded_account::<T>("seller", 0);
+        let previous = funded_account::<T>("bidder", 0);
+        let bidder = funded_account::<T>("bidder", 1);
+        let claim_hash = create_max_claim::<T>(&seller);
+        assert!(PoeModule::<T>::start_auction(RawOrigin::Signed(seller).into(), claim_hash, 1_000u32.into(), 10u32.into()).is_ok());
+        assert!(PoeModule::<T>::bid(RawOrigin::Signed(previous).into(), claim_hash, 1_000u32.into()).is_ok());
+        #[extrinsic_call]
+        bid(RawOrigin::Signed(bidder.clone()), claim_hash, 2_000u32.into());
+
+        assert_eq!(Auctions::<T>::get(claim_hash).and_then(|auction| auction.best_bid).map(|(who, _)| who), Some(bidder));
+    }
+
+    #[benchmark]
+    fn add_fee_exempt() -> Result<(), BenchmarkError> {
+        let who = funded_account::<T>("who", 0);
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        add_fee_exempt(origin as T::RuntimeOrigin, who.clone());
+
+        assert!(FeeExempt::<T>::contains_key(who));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_fee_exempt() -> Result<(), BenchmarkError> {
+        let who = funded_account::<T>("who", 0);
+        FeeExempt::<T>::insert(&who, ());
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        remove_fee_exempt(origin as T::RuntimeOrigin, who.clone());
+
+        assert!(!FeeExempt::<T>::contains_key(who));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_max_claim_length() {
+        let max_length = T::MaxClaimLength::get();
+        #[extrinsic_call]
+        set_max_claim_length(RawOrigin::Root, max_length);
+
+        assert_eq!(CurrentMaxLength::<T>::get(), max_length);
+    }
+
+    // 最坏情况与 create_claim_by_hash 相同：覆盖一个已过期的存证；签名只在交易池中校验
+    #[benchmark]
+    fn submit_claim_unsigned() -> Result<(), BenchmarkError> {
+        let old_owner = funded_account::<T>("old_owner", 0);
+        let claim_hash = create_max_claim::<T>(&old_owner);
+        expire_claim::<T>(&claim_hash);
+
+        let app_public = <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::generate_pair(None);
+        let public: T::Public =
+            <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic::from(app_public).into();
+        let owner = public.clone().into_account();
+        FeeExempt::<T>::insert(&owner, ());
+        let payload = ClaimPayload { public: public.clone(), claim_hash, block_number: frame_system::Pallet::<T>::block_number() };
+        let signature = <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::sign(&payload.encode(), public)
+            .ok_or(BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        submit_claim_unsigned(RawOrigin::None, payload, signature);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(owner));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn report_availability() {
+        let owner = funded_account::<T>("owner", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        let caller = funded_account::<T>("caller", 0);
+        assert!(PoeModule::<T>::register_notary(RawOrigin::Signed(caller.clone()).into()).is_ok());
+        #[extrinsic_call]
+        report_availability(RawOrigin::Signed(caller), claim_hash, false);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.status), Some(ClaimStatus::ContentUnavailable));
+    }
+
+    #[benchmark]
+    fn record_anchor() {
+        let caller = funded_account::<T>("caller", 0);
+        assert!(PoeModule::<T>::register_notary(RawOrigin::Signed(caller.clone()).into()).is_ok());
+        let root = PoeModule::<T>::claim_hash(b"root");
+        let now = frame_system::Pallet::<T>::block_number();
+        #[extrinsic_call]
+        record_anchor(RawOrigin::Signed(caller), root, now, now, 1, [1; 32]);
+
+        assert!(Anchors::<T>::contains_key(root));
+    }
+
+    #[benchmark]
+    fn create_batch_root() {
+        let caller = funded_account::<T>("caller", 0);
+        let root = PoeModule::<T>::claim_hash(b"root");
+        #[extrinsic_call]
+        create_batch_root(RawOrigin::Signed(caller), root, u32::MAX);
+
+        assert!(BatchRoots::<T>::contains_key(root));
+    }
+
+    // 证明第一个叶子，每一层都有兄弟节点，证明长度即树的深度
+    #[benchmark]
+    fn prove_membership(d: Linear<0, 32>) {
+        let d = d.min(T::MaxProofDepth::get());
+        let owner = funded_account::<T>("owner", 0);
+        let leaf = PoeModule::<T>::claim_hash(b"leaf");
+        let siblings: Vec<_> = (0..d).map(|i| PoeModule::<T>::claim_hash(&i.to_le_bytes())).collect();
+        let root = siblings.iter().fold(leaf, |hash, sibling| PoeModule::<T>::merkle_node(&hash, sibling));
+        let now = frame_system::Pallet::<T>::block_number();
+        let count = 2u64.pow(d).min(u32::MAX as u64) as u32;
+        BatchRoots::<T>::insert(&root, BatchRoot { owner: owner.clone(), count, created_at: now, deposit: Zero::zero() });
+        let proof = MerkleProof { leaf_index: 0, siblings: siblings.try_into().unwrap() };
+
+        let caller = funded_account::<T>("caller", 0);
+        #[extrinsic_call]
+        prove_membership(RawOrigin::Signed(caller), root, leaf, proof);
+
+        assert_eq!(Proofs::<T>::get(leaf).map(|details| details.owner), Some(owner));
+    }
+
+    #[benchmark]
+    fn remove_batch_root() {
+        let caller = funded_account::<T>("caller", 0);
+        let root = PoeModule::<T>::claim_hash(b"root");
+        assert!(PoeModule::<T>::create_batch_root(RawOrigin::Signed(caller.clone()).into(), root, 1).is_ok());
+        #[extrinsic_call]
+        remove_batch_root(RawOrigin::Signed(caller), root);
+
+        assert!(!BatchRoots::<T>::contains_key(root));
+    }
+
+    #[benchmark]
+    fn create_signed_claim() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim: BoundedVec<u8, T::MaxClaimLength> = vec![1].try_into().unwrap();
+        let claim_hash = PoeModule::<T>::claim_hash(&claim);
+        let author = AuthorKey::Ed25519(sp_core::ed25519::Public::from_raw(AUTHOR_PUBLIC));
+        let signature = AuthorSignature::Ed25519(sp_core::ed25519::Signature::from_raw(AUTHOR_SIGNATURE));
+        #[extrinsic_call]
+        create_signed_claim(RawOrigin::Signed(caller), claim, author.clone(), signature);
+
+        assert_eq!(ClaimAuthors::<T>::get(claim_hash), Some(author));
+    }
+
+    #[benchmark]
+    fn create_claim_from_preimage() {
+        let caller = funded_account::<T>("caller", 0);
+        // 原文长度超过 `MaxClaimLength`，押金按上限收取
+        let content = vec![1u8; T::MaxClaimLength::get() as usize + 1];
+        let claim_hash = T::Preimages::note(content.into()).expect("preimage can be noted");
+        #[extrinsic_call]
+        create_claim_from_preimage(RawOrigin::Signed(caller), claim_hash, true);
+
+        assert!(RetainedPreimages::<T>::contains_key(claim_hash));
+    }
+
+    // 运行时没有把存证镜像为 NFT 时该调用总是失败，跳过
+    #[benchmark]
+    fn sync_claim_owner() -> Result<(), BenchmarkError> {
+        let owner = funded_account::<T>("owner", 0);
+        let holder = funded_account::<T>("holder", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        // 模拟 NFT 在外部被转移
+        T::NftMirror::transfer(&claim_hash, &holder)?;
+        if T::NftMirror::owner_of(&claim_hash).as_ref() != Some(&holder) {
+            return Err(BenchmarkError::Weightless);
+        }
+        #[extrinsic_call]
+        sync_claim_owner(RawOrigin::Signed(holder.clone()), claim_hash);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(holder));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_trusted_para() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        set_trusted_para(origin as T::RuntimeOrigin, 2000, true);
+
+        assert!(TrustedParas::<T>::contains_key(2000));
+        Ok(())
+    }
+
+    // 运行时不支持 XCM 时该调用总是失败，跳过
+    #[benchmark]
+    fn transfer_claim_to_para() -> Result<(), BenchmarkError> {
+        let dest = Location::new(1, [Junction::Parachain(2000)]);
+        validate_send::<T::XcmSender>(dest, Xcm::new()).map_err(|_| BenchmarkError::Weightless)?;
+        TrustedParas::<T>::insert(2000, ());
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        #[extrinsic_call]
+        transfer_claim_to_para(RawOrigin::Signed(caller.clone()), claim_hash, 2000, caller.clone());
+
+        assert!(!Proofs::<T>::contains_key(claim_hash));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_register_remote_claim() -> Result<(), BenchmarkError> {
+        let origin = T::RemoteOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let location = T::RemoteOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+        let para_id = remote::sibling_para(&location).ok_or(BenchmarkError::Weightless)?;
+        TrustedParas::<T>::insert(para_id, ());
+        let owner = funded_account::<T>("owner", 0);
+        let claim_hash = PoeModule::<T>::claim_hash(b"remote");
+        #[extrinsic_call]
+        force_register_remote_claim(origin as T::RuntimeOrigin, claim_hash, owner.clone());
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(owner));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn report_price() {
+        let caller = funded_account::<T>("caller", 0);
+        assert!(PoeModule::<T>::register_notary(RawOrigin::Signed(caller.clone()).into()).is_ok());
+        #[extrinsic_call]
+        report_price(RawOrigin::Signed(caller), FixedU128::from_u32(1));
+
+        assert!(NativePrice::<T>::exists());
+    }
+
+    #[benchmark]
+    fn report_claim() {
+        let owner = funded_account::<T>("owner", 0);
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        #[extrinsic_call]
+        report_claim(RawOrigin::Signed(caller), claim_hash, claim_hash);
+
+        assert!(Reports::<T>::contains_key(claim_hash));
+    }
+
+    // 最坏情况：举报成立，罚没押金并撤销带有最多背书的存证
+    #[benchmark]
+    fn settle_report() -> Result<(), BenchmarkError> {
+        let owner = funded_account::<T>("owner", 0);
+        let reporter = funded_account::<T>("reporter", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        attest_max::<T>(&claim_hash);
+        license_max::<T>(&owner, &claim_hash);
+        assert!(PoeModule::<T>::report_claim(RawOrigin::Signed(reporter).into(), claim_hash, claim_hash).is_ok());
+        let origin = T::ArbitrationOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        settle_report(origin as T::RuntimeOrigin, claim_hash, true);
+
+        assert!(!Proofs::<T>::contains_key(claim_hash));
+        Ok(())
+    }
+
+    // 最坏情况：新的被许可人，需要增加许可计数
+    #[benchmark]
+    fn grant_license() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        let licensee: T::AccountId = account("licensee", 0, SEED);
+        let expiry = frame_system::Pallet::<T>::block_number() + 1u32.into();
+        #[extrinsic_call]
+        grant_license(RawOrigin::Signed(caller), claim_hash, licensee.clone(), claim_hash, expiry);
+
+        assert!(Licenses::<T>::contains_key(claim_hash, licensee));
+    }
+
+    #[benchmark]
+    fn revoke_license() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        license_max::<T>(&caller, &claim_hash);
+        let licensee: T::AccountId = account("licensee", 0, SEED);
+        #[extrinsic_call]
+        revoke_license(RawOrigin::Signed(caller), claim_hash, licensee.clone());
+
+        assert!(!Licenses::<T>::contains_key(claim_hash, licensee));
+    }
+
+    #[benchmark]
+    fn set_license_fee() {
+        let caller = funded_account::<T>("caller", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        let licensee: T::AccountId = account("licensee", 0, SEED);
+        let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        let origin = RawOrigin::Signed(caller.clone()).into();
+        assert!(PoeModule::<T>::grant_license(origin, claim_hash, licensee.clone(), claim_hash, expiry).is_ok());
+        let amount = T::Currency::minimum_balance();
+        #[extrinsic_call]
+        set_license_fee(RawOrigin::Signed(caller), claim_hash, licensee.clone(), amount, 1u32.into());
+
+        assert!(LicenseFees::<T>::contains_key(claim_hash, licensee));
+    }
+
+    // 最坏情况：被许可人接受付款计划，付款成功并在下一期排队
+    #[benchmark]
+    fn settle_license() {
+        let owner = funded_account::<T>("owner", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        let licensee = funded_account::<T>("licensee", 0);
+        let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        let origin = RawOrigin::Signed(owner.clone()).into();
+        assert!(PoeModule::<T>::grant_license(origin, claim_hash, licensee.clone(), claim_hash, expiry).is_ok());
+        let origin = RawOrigin::Signed(owner).into();
+        let amount = T::Currency::minimum_balance();
+        assert!(PoeModule::<T>::set_license_fee(origin, claim_hash, licensee.clone(), amount, 1u32.into()).is_ok());
+        #[extrinsic_call]
+        settle_license(RawOrigin::Signed(licensee.clone()), claim_hash, licensee.clone());
+
+        assert!(PoeModule::<T>::has_license(&claim_hash, &licensee));
+    }
+
+    // 最坏情况：关注者列表只差一个名额就满，需要遍历全部已有的关注者
+    #[benchmark]
+    fn watch_claim() {
+        let owner = funded_account::<T>("owner", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        watch_n::<T>(&claim_hash, T::MaxWatchers::get().saturating_sub(1));
+        let caller = funded_account::<T>("caller", 0);
+        #[extrinsic_call]
+        watch_claim(RawOrigin::Signed(caller.clone()), claim_hash);
+
+        assert!(Watchers::<T>::get(claim_hash).contains(&caller));
+    }
+
+    // 最坏情况：调用者排在满员的关注者列表末尾
+    #[benchmark]
+    fn unwatch_claim() {
+        let owner = funded_account::<T>("owner", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        watch_n::<T>(&claim_hash, T::MaxWatchers::get());
+        let caller: T::AccountId = account("watcher", T::MaxWatchers::get() - 1, SEED);
+        #[extrinsic_call]
+        unwatch_claim(RawOrigin::Signed(caller.clone()), claim_hash);
+
+        assert!(!Watchers::<T>::get(claim_hash).contains(&caller));
+    }
+
+    // 最坏情况：背书人添加最长的注释，需要额外读取背书记录；注释列表只差一条就满
+    #[benchmark]
+    fn annotate_claim() {
+        let owner = funded_account::<T>("owner", 0);
+        let claim_hash = create_max_claim::<T>(&owner);
+        let text: AnnotationTextOf<T> = vec![b'a'; T::MaxAnnotationLength::get() as usize].try_into().unwrap();
+        for _ in 1..T::MaxAnnotations::get() {
+            let origin = RawOrigin::Signed(owner.clone()).into();
+            assert!(PoeModule::<T>::annotate_claim(origin, claim_hash, text.clone()).is_ok());
+        }
+        // 不允许背书人注释时退回到所有者添加
+        let caller = if T::AttestersCanAnnotate::get() {
+            let attester = funded_account::<T>("attester", 0);
+            assert!(PoeModule::<T>::attest_claim(RawOrigin::Signed(attester.clone()).into(), claim_hash).is_ok());
+            attester
+        } else {
+            owner
+        };
+        #[extrinsic_call]
+        annotate_claim(RawOrigin::Signed(caller), claim_hash, text);
+
+        assert_eq!(Annotations::<T>::get(claim_hash).len() as u32, T::MaxAnnotations::get());
+    }
+
+    #[benchmark]
+    fn transfer_with_vesting() {
+        let caller = funded_account::<T>("caller", 0);
+        let target = funded_account::<T>("target", 0);
+        let claim_hash = create_max_claim::<T>(&caller);
+        watch_n::<T>(&claim_hash, T::MaxWatchers::get());
+        let duration: BlockNumberFor<T> = 100u32.into();
+        #[extrinsic_call]
+        transfer_with_vesting(RawOrigin::Signed(caller), claim_hash, target.clone(), duration);
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(target));
+        assert!(Vestings::<T>::contains_key(claim_hash));
+    }
+
+    #[benchmark]
+    fn lock_as_collateral() {
+        let caller = funded_account::<T>("caller", 0);
+        let locker: T::AccountId = account("locker", 0, SEED);
+        let claim_hash = create_max_claim::<T>(&caller);
+        #[extrinsic_call]
+        lock_as_collateral(RawOrigin::Signed(caller), claim_hash, locker.clone());
+
+        assert_eq!(CollateralLocks::<T>::get(claim_hash), Some(locker));
+    }
+
+    #[benchmark]
+    fn unlock_collateral() {
+        let caller = funded_account::<T>("caller", 0);
+        let locker: T::AccountId = account("locker", 0, SEED);
+        let claim_hash = create_max_claim::<T>(&caller);
+        let origin = RawOrigin::Signed(caller).into();
+        assert!(PoeModule::<T>::lock_as_collateral(origin, claim_hash, locker.clone()).is_ok());
+        #[extrinsic_call]
+        unlock_collateral(RawOrigin::Signed(locker), claim_hash);
+
+        assert!(!CollateralLocks::<T>::contains_key(claim_hash));
+    }
+
+    // 多区块迁移中转换一个存证
+    #[benchmark]
+    fn migrate_v2_step() {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let claim_hash = PoeModule::<T>::claim_hash(b"legacy");
+        migrations::v1::Proofs::<T>::insert(claim_hash, (owner.clone(), BlockNumberFor::<T>::zero()));
+        StorageVersion::new(1).put::<PoeModule<T>>();
+        let mut meter = WeightMeter::with_limit(T::WeightInfo::migrate_v2_step());
+        #[block]
+        {
+            migrations::v2::LazyMigrateToV2::<T>::step(None, &mut meter).unwrap();
+        }
+
+        assert_eq!(Proofs::<T>::get(claim_hash).map(|details| details.owner), Some(owner));
+    }
+
+    impl_benchmark_test_suite!(PoeModule, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,104 @@
+//! IPFS 内容标识（CID）的格式校验
+//!
+//! 打开 `ValidateContentIds` 后，按内容创建存证前会检查内容能否解析为 CIDv0 或 CIDv1。
+//! 这里只校验编码格式，不检查内容是否真的存在于 IPFS 网络中。
+
+use sp_std::vec::Vec;
+
+/// 常见的 CID 不超过 100 个字符，限制长度也避免 base58 解码的平方复杂度占用过多区块时间
+const MAX_CID_LENGTH: usize = 128;
+
+/// sha2-256 多哈希的前缀：哈希算法 `0x12`，摘要长度 32
+const SHA2_256_PREFIX: [u8; 2] = [0x12, 0x20];
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// 内容是否为合法的 CID，支持以下形式：
+/// - 文本 CIDv0：以 `Qm` 开头的 46 个 base58btc 字符
+/// - 文本 CIDv1：multibase 前缀 `b`（小写 base32）或 `z`（base58btc）加二进制 CIDv1
+/// - 二进制 CIDv0（sha2-256 多哈希）或二进制 CIDv1
+pub(crate) fn is_valid(content: &[u8]) -> bool {
+    if content.len() > MAX_CID_LENGTH {
+        return false;
+    }
+    match content {
+        [b'Q', b'm', ..] if content.len() == 46 => base58_decode(content).map_or(false, |bytes| is_v0(&bytes)),
+        [b'b', rest @ ..] => base32_decode(rest).map_or(false, |bytes| is_v1(&bytes)),
+        [b'z', rest @ ..] => base58_decode(rest).map_or(false, |bytes| is_v1(&bytes)),
+        _ => is_v0(content) || is_v1(content),
+    }
+}
+
+/// 二进制 CIDv0 就是一个 sha2-256 多哈希
+fn is_v0(bytes: &[u8]) -> bool {
+    bytes.len() == 34 && bytes.starts_with(&SHA2_256_PREFIX)
+}
+
+/// 二进制 CIDv1：版本 `1`、内容编码、多哈希（算法、摘要长度、摘要），均以无符号变长整数编码
+fn is_v1(bytes: &[u8]) -> bool {
+    parse_v1(bytes).is_some()
+}
+
+fn parse_v1(bytes: &[u8]) -> Option<()> {
+    let (version, rest) = read_varint(bytes)?;
+    let (_codec, rest) = read_varint(rest)?;
+    let (_hash_code, rest) = read_varint(rest)?;
+    let (digest_len, digest) = read_varint(rest)?;
+    (version == 1 && digest.len() as u64 == digest_len).then_some(())
+}
+
+/// 读取一个 multiformats 无符号变长整数，最多 9 个字节，且必须是最短编码
+fn read_varint(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            if i > 0 && *byte == 0 {
+                return None;
+            }
+            return Some((value, &bytes[i + 1..]));
+        }
+    }
+    None
+}
+
+fn base58_decode(text: &[u8]) -> Option<Vec<u8>> {
+    // 按小端累加，最后再反转
+    let mut bytes = Vec::with_capacity(text.len());
+    for c in text {
+        let mut carry = BASE58_ALPHABET.iter().position(|digit| digit == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    // 前导的 `1` 对应前导的零字节
+    bytes.extend(text.iter().take_while(|c| **c == b'1').map(|_| 0));
+    bytes.reverse();
+    Some(bytes)
+}
+
+/// RFC 4648 小写 base32，不带填充；末尾剩余的不足一个字节的位必须为 0
+fn base32_decode(text: &[u8]) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len() * 5 / 8);
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    for c in text {
+        let value = match c {
+            b'a'..=b'z' => c - b'a',
+            b'2'..=b'7' => c - b'2' + 26,
+            _ => return None,
+        };
+        buffer = ((buffer << 5) | u32::from(value)) & 0xffff;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    (bits < 5 && buffer & ((1 << bits) - 1) == 0).then_some(bytes)
+}
@@ -0,0 +1,31 @@
+//! 链下工作机使用的密钥类型
+//!
+//! 锚定服务把 `poe!` 类型的 sr25519 密钥插入节点的密钥库后，链下工作机即可用它签名
+//! 提交的载荷；签名对应的账户需要在免费名单中，链上才会接受其无签名交易。
+
+use sp_core::sr25519::Signature as Sr25519Signature;
+use sp_runtime::{
+    app_crypto::{app_crypto, sr25519},
+    traits::Verify,
+    KeyTypeId, MultiSignature, MultiSigner,
+};
+
+/// 链下工作机密钥在密钥库中的类型
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"poe!");
+
+app_crypto!(sr25519, KEY_TYPE);
+
+/// 运行时中 `AuthorityId` 使用的签名方案
+pub struct AuthorityId;
+
+impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthorityId {
+    type RuntimeAppPublic = Public;
+    type GenericSignature = sp_core::sr25519::Signature;
+    type GenericPublic = sp_core::sr25519::Public;
+}
+
+impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature> for AuthorityId {
+    type RuntimeAppPublic = Public;
+    type GenericSignature = sp_core::sr25519::Signature;
+    type GenericPublic = sp_core::sr25519::Public;
+}
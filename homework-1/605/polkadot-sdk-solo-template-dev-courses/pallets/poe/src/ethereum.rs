@@ -0,0 +1,57 @@
+//! 链下工作机锚定存证根时使用的以太坊 JSON-RPC 编解码
+//!
+//! 运行时环境中没有 JSON 库，这里只拼接和解析锚定所需的最小报文：
+//! 用 `eth_sendTransaction` 由节点中已解锁的账户给自己发送一笔 `data` 为存证根的交易，
+//! 并从响应中取出交易哈希。
+
+use sp_std::vec::Vec;
+
+/// `eth_sendTransaction` 请求体，`from` 为 `0x` 开头的账户地址，`data` 为原始字节
+pub(crate) fn send_transaction_body(from: &[u8], data: &[u8]) -> Vec<u8> {
+    [
+        &br#"{"jsonrpc":"2.0","id":1,"method":"eth_sendTransaction","params":[{"from":""#[..],
+        from,
+        br#"","to":""#,
+        from,
+        br#"","data":"0x"#,
+        hex_encode(data).as_slice(),
+        br#""}]}"#,
+    ]
+    .concat()
+}
+
+/// 从 JSON-RPC 响应中取出 `result` 字段的 32 字节交易哈希，响应为错误或格式不符时返回 `None`
+pub(crate) fn parse_tx_hash(body: &[u8]) -> Option<[u8; 32]> {
+    const KEY: &[u8] = br#""result""#;
+    let start = body.windows(KEY.len()).position(|window| window == KEY)? + KEY.len();
+    let rest = skip_whitespace(&body[start..]).strip_prefix(b":")?;
+    let rest = skip_whitespace(rest).strip_prefix(br#""0x"#)?;
+    if rest.len() < 65 || rest[64] != b'"' {
+        return None;
+    }
+
+    let mut tx_hash = [0u8; 32];
+    for (byte, pair) in tx_hash.iter_mut().zip(rest[..64].chunks(2)) {
+        *byte = (hex_value(pair[0])? << 4) | hex_value(pair[1])?;
+    }
+    Some(tx_hash)
+}
+
+fn hex_encode(data: &[u8]) -> Vec<u8> {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    data.iter().flat_map(|byte| [DIGITS[(byte >> 4) as usize], DIGITS[(byte & 0xf) as usize]]).collect()
+}
+
+fn hex_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn skip_whitespace(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|byte| !byte.is_ascii_whitespace()).unwrap_or(bytes.len());
+    &bytes[start..]
+}
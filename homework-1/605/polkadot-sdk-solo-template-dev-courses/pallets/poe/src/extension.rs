@@ -0,0 +1,99 @@
+//! 交易池中的存证去重
+//!
+//! [`DeduplicateClaims`] 为创建存证的交易附加以存证哈希为内容的 `provides` 标签，
+//! 交易池中同一内容的登记最多只保留一笔，竞争的重复登记不会一起进入区块浪费区块空间。
+
+use crate::{Call, ClaimHashOf, Config, Pallet};
+use codec::{Decode, Encode};
+use frame_support::traits::IsSubType;
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{DispatchInfoOf, SignedExtension},
+    transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
+};
+use sp_std::{fmt, marker::PhantomData, vec::Vec};
+
+/// `provides` 标签的前缀
+const TAG_PREFIX: &str = "PoeClaim";
+
+/// 为 `create_claim`、`create_claim_by_hash`、`create_claim_for` 和 `create_claims` 交易
+/// 附加每个存证哈希的 `provides` 标签，其他交易不受影响
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct DeduplicateClaims<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> DeduplicateClaims<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// 交易要创建的存证哈希
+    fn claim_hashes(call: &<T as frame_system::Config>::RuntimeCall) -> Vec<ClaimHashOf<T>>
+    where
+        <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+    {
+        match call.is_sub_type() {
+            Some(Call::create_claim { claim }) | Some(Call::create_claim_for { claim, .. }) => {
+                sp_std::vec![Pallet::<T>::claim_hash(claim)]
+            }
+            Some(Call::create_claim_by_hash { claim_hash }) => sp_std::vec![*claim_hash],
+            Some(Call::create_claims { claims }) => claims.iter().map(|claim| Pallet::<T>::claim_hash(claim)).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl<T: Config + Send + Sync> Default for DeduplicateClaims<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Config + Send + Sync> fmt::Debug for DeduplicateClaims<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DeduplicateClaims")
+    }
+}
+
+impl<T: Config + Send + Sync> SignedExtension for DeduplicateClaims<T>
+where
+    <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+    const IDENTIFIER: &'static str = "DeduplicateClaims";
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::RuntimeCall;
+    type AdditionalSigned = ();
+    type Pre = ();
+
+    fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        _who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> TransactionValidity {
+        let claim_hashes = Self::claim_hashes(call);
+        if claim_hashes.is_empty() {
+            return Ok(ValidTransaction::default());
+        }
+        claim_hashes
+            .into_iter()
+            .fold(ValidTransaction::with_tag_prefix(TAG_PREFIX), |builder, claim_hash| builder.and_provides(claim_hash))
+            .build()
+    }
+
+    fn pre_dispatch(
+        self,
+        _who: &Self::AccountId,
+        _call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        // 标签只在交易池中起作用，执行时的重复检查由调用本身完成
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+//! 基于 `pallet_identity` 的存证准入策略，需要启用 `identity` feature
+//!
+//! 把 [`IdentityPolicy`] 设为 `Config::ClaimPolicy`（或放进元组与其他策略组合），
+//! 只有获得注册员 `Reasonable` 或 `KnownGood` 认证的账户才能拥有新的存证。
+
+use crate::{ClaimHashOf, ClaimPolicy, Config, Error};
+use frame_support::pallet_prelude::*;
+use pallet_identity::Judgement;
+use sp_std::marker::PhantomData;
+
+/// 要求存证所有者拥有经过认证的链上身份，否则返回 `IdentityRequired`
+pub struct IdentityPolicy<T>(PhantomData<T>);
+
+impl<T: Config + pallet_identity::Config> ClaimPolicy<T::AccountId, ClaimHashOf<T>> for IdentityPolicy<T> {
+    fn can_create(owner: &T::AccountId, _claim_hash: &ClaimHashOf<T>) -> DispatchResult {
+        let judged = pallet_identity::Pallet::<T>::identity(owner).map_or(false, |(registration, _)| {
+            registration
+                .judgements
+                .iter()
+                .any(|(_, judgement)| matches!(judgement, Judgement::Reasonable | Judgement::KnownGood))
+        });
+        ensure!(judged, Error::<T>::IdentityRequired);
+        Ok(())
+    }
+}
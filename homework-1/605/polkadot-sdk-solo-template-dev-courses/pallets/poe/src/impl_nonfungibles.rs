@@ -0,0 +1,68 @@
+//! 为本 pallet 实现 `nonfungibles_v2` 接口
+//!
+//! 所有存证属于同一个集合 `()`，物品编号就是存证哈希，通用的 NFT 市场和 XCM 资产适配器
+//! 可以直接查询和转移存证。通过接口发起的转移由调用方负责权限检查，不再要求所有者签名
+//! 或共同所有者批准，但仍遵守冻结、争议、挂单和拍卖的限制。
+
+use super::*;
+use frame_support::{
+    ensure,
+    pallet_prelude::DispatchResult,
+    traits::tokens::nonfungibles_v2::{Inspect, Transfer},
+};
+use sp_std::vec::Vec;
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+    type ItemId = ClaimHashOf<T>;
+    type CollectionId = ();
+
+    fn owner(_collection: &Self::CollectionId, item: &Self::ItemId) -> Option<T::AccountId> {
+        Self::live_claim(item).ok().map(|details| details.owner)
+    }
+
+    /// 存证的标题和外部资源地址分别以 `title` 和 `uri` 为键
+    fn attribute(_collection: &Self::CollectionId, item: &Self::ItemId, key: &[u8]) -> Option<Vec<u8>> {
+        let details = Self::live_claim(item).ok()?;
+        match key {
+            b"title" => details.title.map(|title| title.into_inner()),
+            b"uri" => details.uri.map(|uri| uri.into_inner()),
+            _ => None,
+        }
+    }
+
+    fn can_transfer(_collection: &Self::CollectionId, item: &Self::ItemId) -> bool {
+        Self::live_claim(item).map_or(false, |details| {
+            details.status != ClaimStatus::Frozen
+                && !Disputes::<T>::contains_key(item)
+                && !Listings::<T>::contains_key(item)
+                && !Auctions::<T>::contains_key(item)
+        })
+    }
+}
+
+impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
+    fn transfer(_collection: &Self::CollectionId, item: &Self::ItemId, destination: &T::AccountId) -> DispatchResult {
+        let details = Self::live_claim(item)?;
+        Self::do_transfer(*item, details, destination.clone())
+    }
+
+    /// 禁止转移即冻结存证
+    fn disable_transfer(_collection: &Self::CollectionId, item: &Self::ItemId) -> DispatchResult {
+        let mut details = Self::live_claim(item)?;
+        ensure!(details.status != ClaimStatus::Frozen, Error::<T>::ClaimFrozen);
+        details.status = ClaimStatus::Frozen;
+        Self::deposit_event(Event::ClaimFrozen { who: details.owner.clone(), claim_hash: *item });
+        Proofs::<T>::insert(item, details);
+        Ok(())
+    }
+
+    /// 允许转移即解冻存证
+    fn enable_transfer(_collection: &Self::CollectionId, item: &Self::ItemId) -> DispatchResult {
+        let mut details = Self::live_claim(item)?;
+        ensure!(details.status == ClaimStatus::Frozen, Error::<T>::ClaimNotFrozen);
+        details.status = ClaimStatus::Active;
+        Self::deposit_event(Event::ClaimThawed { who: details.owner.clone(), claim_hash: *item });
+        Proofs::<T>::insert(item, details);
+        Ok(())
+    }
+}
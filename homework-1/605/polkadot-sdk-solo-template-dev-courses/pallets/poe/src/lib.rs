@@ -8,119 +8,5633 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod cid;
+pub mod crypto;
+mod ethereum;
+pub mod extension;
+mod impl_nonfungibles;
+#[cfg(feature = "identity")]
+pub mod identity;
+pub mod migrations;
+pub mod nft;
+mod price;
+pub mod remote;
+pub mod traits;
+pub mod types;
+pub mod weights;
+pub use extension::*;
+pub use traits::*;
+pub use types::*;
+pub use weights::*;
+
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::{ensure, pallet_prelude::*};
-    use frame_system::{ensure_signed, pallet_prelude::*};
+    use super::*;
+    use frame_support::{
+        dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo, WithPostDispatchInfo},
+        ensure,
+        pallet_prelude::*,
+        storage::with_storage_layer,
+        traits::{
+            BalanceStatus, Currency, ExistenceRequirement, Imbalance, OnUnbalanced, QueryPreimage, ReservableCurrency,
+            StorePreimage, WithdrawReasons,
+        },
+    };
+    use frame_system::{
+        ensure_none, ensure_root, ensure_signed,
+        offchain::{
+            AppCrypto, CreateSignedTransaction, SendSignedTransaction, SendUnsignedTransaction, SignedPayload, Signer,
+        },
+        pallet_prelude::*,
+    };
+    use sp_runtime::{
+        offchain::{http, storage::StorageValueRef, Duration, StorageKind},
+        traits::{CheckedDiv, Hash, IdentifyAccount, One, SaturatedConversion, Saturating, Zero},
+        FixedPointNumber, FixedU128, Perbill,
+    };
+    use sp_std::{vec, vec::Vec};
+    use xcm::latest::{send_xcm, Instruction, Junction, Location, OriginKind, SendXcm, WeightLimit, Xcm};
+
+    /// 当前的存储版本
+    ///
+    /// - v1：`Proofs` 以存证内容的哈希作为键
+    /// - v2：`Proofs` 的值由元组改为 [`ClaimDetails`]
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+    /// 链下本地存储中等待链下工作机提交的存证哈希列表（SCALE 编码的 `Vec<ClaimHash>`）的键，
+    /// 锚定服务可以通过 `offchain_localStorageSet` RPC 写入
+    pub const PENDING_CLAIMS_KEY: &[u8] = b"poe::pending-claims";
+
+    /// 链下本地存储中可用性检查游标（上次检查到的 `Proofs` 原始键）的键
+    const AVAILABILITY_CURSOR_KEY: &[u8] = b"poe::availability-cursor";
+
+    /// 链下本地存储中以太坊 JSON-RPC 节点地址（UTF-8 字符串）的键，未设置时不锚定
+    pub const ANCHOR_ENDPOINT_KEY: &[u8] = b"poe::anchor-endpoint";
+
+    /// 链下本地存储中发送锚定交易的以太坊账户（`0x` 开头的地址）的键，该账户需要在节点中解锁
+    pub const ANCHOR_ACCOUNT_KEY: &[u8] = b"poe::anchor-account";
+
+    /// 链下本地存储中原生代币价格接口地址（UTF-8 字符串）的键，接口返回以法币计价的单价，未设置时不喂价
+    pub const PRICE_ENDPOINT_KEY: &[u8] = b"poe::price-endpoint";
+
+    /// 链下工作机中单个 HTTP 请求的超时时间（毫秒）
+    const HTTP_TIMEOUT_MS: u64 = 3_000;
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     // #[pallet::generate_store(pub(super) trait Store)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// 存证内容的最大长度上限，实际限制为 `CurrentMaxLength`，治理只能在该上限以内调整
         #[pallet::constant]
         type MaxClaimLength: Get<u32>;
+        /// 存证标题的最大长度
+        #[pallet::constant]
+        type MaxTitleLength: Get<u32>;
+        /// 存证外部资源地址的最大长度
+        #[pallet::constant]
+        type MaxUriLength: Get<u32>;
+        /// 存证注释的最大长度
+        #[pallet::constant]
+        type MaxAnnotationLength: Get<u32>;
+        /// 存证的有效期（区块数），过期后存证视为不存在，可被重新创建
+        #[pallet::constant]
+        type ClaimLifetime: Get<BlockNumberFor<Self>>;
+        /// 每个区块最多可以创建的存证数量，防止大量登记占满区块
+        #[pallet::constant]
+        type MaxClaimsPerBlock: Get<u32>;
+        /// `on_initialize` 中每个区块最多检查（并清理）的过期索引条目和存证数量
+        #[pallet::constant]
+        type MaxExpirationsPerBlock: Get<u32>;
+        /// 同一区块过期的存证数量上限，即过期索引 `ExpiringAt` 每个区块的容量
+        #[pallet::constant]
+        type MaxExpiringPerBlock: Get<u32>;
+        /// 存证过期前多少个区块发出 `ClaimExpiringSoon` 提醒，便于钱包提示所有者续期；设为 0 表示不提醒
+        #[pallet::constant]
+        type WarningPeriod: Get<BlockNumberFor<Self>>;
+        /// 用于收取存证相关费用和押金的货币
+        type Currency: ReservableCurrency<Self::AccountId>;
+        /// 创建存证时保留的基础押金
+        ///
+        /// 实际押金为 `基础押金 + DepositPerByte * 存证长度`，撤销或过期时退还，
+        /// 转移时随存证转给新所有者。基础押金按 `FiatDeposit` 和喂价每个周期重新计算，
+        /// 尚未算出时使用该值。
+        #[pallet::constant]
+        type DepositBase: Get<BalanceOf<Self>>;
+        /// 存证内容每字节的押金
+        #[pallet::constant]
+        type DepositPerByte: Get<BalanceOf<Self>>;
+        /// 基础押金对应的法币价值，例如 1 表示价值 1 美元的原生代币
+        #[pallet::constant]
+        type FiatDeposit: Get<FixedU128>;
+        /// 一个完整原生代币对应的最小单位数量，喂价以完整代币计价
+        #[pallet::constant]
+        type NativeUnit: Get<BalanceOf<Self>>;
+        /// 每隔多少个区块按最新喂价重新计算一次基础押金，设为 0 表示始终使用 `DepositBase`
+        #[pallet::constant]
+        type DepositEra: Get<BlockNumberFor<Self>>;
+        /// 喂价的最长有效期（区块数），过期的喂价不用于重新计算押金
+        #[pallet::constant]
+        type MaxPriceAge: Get<BlockNumberFor<Self>>;
+        /// 续期存证时收取的费用，设为 0 表示免费续期；费用直接销毁
+        #[pallet::constant]
+        type RenewalFee: Get<BalanceOf<Self>>;
+        /// 创建存证时收取的费用，与押金不同，费用不会退还；设为 0 表示不收费
+        #[pallet::constant]
+        type CreationFee: Get<BalanceOf<Self>>;
+        /// 创建费用的去向，例如国库；设为 `()` 时费用直接销毁
+        type OnCreationFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+        /// 转移邀请的有效期（区块数），过期后接收方无法再接受
+        #[pallet::constant]
+        type OfferLifetime: Get<BlockNumberFor<Self>>;
+        /// 每个账户最多可以拥有的存证数量，防止单个账户抢占注册表
+        #[pallet::constant]
+        type MaxClaimsPerAccount: Get<u32>;
+        /// 整个注册表最多容纳的存证数量
+        #[pallet::constant]
+        type MaxTotalClaims: Get<u32>;
+        /// 批量调用中一次最多处理的存证数量
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+        /// 提交承诺后至少经过多少个区块才能揭示
+        #[pallet::constant]
+        type MinRevealDelay: Get<BlockNumberFor<Self>>;
+        /// 发起争议时需要保留的保证金
+        #[pallet::constant]
+        type DisputeBond: Get<BalanceOf<Self>>;
+        /// 新存证的挑战期（区块数），挑战期内存证处于待确认状态，任何人都可以通过 `raise_dispute` 发起挑战；
+        /// 挑战期结束且没有未仲裁的争议时自动确认。设为 0 表示存证创建后立即确认，但不经过挑战期的存证不增加声誉
+        #[pallet::constant]
+        type ChallengeWindow: Get<BlockNumberFor<Self>>;
+        /// 可以仲裁争议和虚假存证举报的来源
+        type ArbitrationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// 举报虚假存证时需要保留的保证金，举报被驳回时罚没
+        #[pallet::constant]
+        type ReportBond: Get<BalanceOf<Self>>;
+        /// 举报成立时，从存证押金中罚没的资金奖励给举报人的比例，其余部分交给 `OnSlash`
+        #[pallet::constant]
+        type ReporterReward: Get<Perbill>;
+        /// 罚没的存证押金和举报保证金的去向，例如国库；设为 `()` 时直接销毁
+        type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+        /// 举报成立时存证所有者扣除的声誉
+        #[pallet::constant]
+        type FraudReputationPenalty: Get<u32>;
+        /// 账户经过挑战期确认且仍然存在的存证达到该数量时获得不可转移的徽章，设为 0 表示不颁发徽章
+        #[pallet::constant]
+        type BadgeThreshold: Get<u32>;
+        /// 每个存证最多可以同时授予的使用许可数量
+        #[pallet::constant]
+        type MaxLicenses: Get<u32>;
+        /// 每个存证最多可以同时关注的账户数量
+        #[pallet::constant]
+        type MaxWatchers: Get<u32>;
+        /// 每个存证最多保存的注释数量
+        #[pallet::constant]
+        type MaxAnnotations: Get<u32>;
+        /// 背书人是否可以为背书过的存证添加注释；关闭时只有所有者和操作员可以添加
+        #[pallet::constant]
+        type AttestersCanAnnotate: Get<bool>;
+        /// 每个存证最多可以获得的背书数量
+        #[pallet::constant]
+        type MaxAttestations: Get<u32>;
+        /// 注册为公证人需要保留的质押
+        #[pallet::constant]
+        type NotaryStake: Get<BalanceOf<Self>>;
+        /// 每个存证最多保留的历史所有者记录数，超出时丢弃最早的记录
+        #[pallet::constant]
+        type MaxHistoryLen: Get<u32>;
+        /// 每个存证最多可以挂载的子存证数量
+        #[pallet::constant]
+        type MaxChildren: Get<u32>;
+        /// 存证集合元数据的最大长度
+        #[pallet::constant]
+        type MaxCollectionMetadataLength: Get<u32>;
+        /// 每个集合最多包含的存证数量
+        #[pallet::constant]
+        type MaxCollectionSize: Get<u32>;
+        /// 每个打包出售最多包含的存证数量
+        #[pallet::constant]
+        type MaxBundleSize: Get<u32>;
+        /// 命名空间前缀的最大长度
+        #[pallet::constant]
+        type MaxNamespaceLength: Get<u32>;
+        /// 注册命名空间时保留的押金
+        #[pallet::constant]
+        type NamespaceDeposit: Get<BalanceOf<Self>>;
+        /// 命名空间的有效期（区块数），所有者可以在到期前重新注册以续期
+        #[pallet::constant]
+        type NamespaceLifetime: Get<BlockNumberFor<Self>>;
+        /// 创建存证前的准入检查，不需要限制时设为 `()`
+        type ClaimPolicy: ClaimPolicy<Self::AccountId, ClaimHashOf<Self>>;
+        /// 每个存证最多可以拥有的共同所有者数量（不含主所有者）
+        #[pallet::constant]
+        type MaxCoOwners: Get<u32>;
+        /// 撤销门限中最多可以设置的签名人数量
+        #[pallet::constant]
+        type MaxSignatories: Get<u32>;
+        /// 社交恢复中最多可以设置的监护人数量
+        #[pallet::constant]
+        type MaxGuardians: Get<u32>;
+        /// 社交恢复的最短等待区块数，保证所有者有时间否决
+        #[pallet::constant]
+        type MinRecoveryDelay: Get<BlockNumberFor<Self>>;
+        /// 继承设置中允许的最短不活动期（区块数）
+        #[pallet::constant]
+        type MinInactivityPeriod: Get<BlockNumberFor<Self>>;
+        /// 每个区块最多可以预约执行的存证转移数量，许可费的自动结算队列使用同一上限
+        #[pallet::constant]
+        type MaxScheduledPerBlock: Get<u32>;
+        /// 两步撤销的等待期（区块数）：`begin_revoke` 之后至少经过该区块数才能 `finalize_revoke`
+        ///
+        /// 设为 0 时 `revoke_claim` 可以立即撤销；大于 0 时只能使用两步撤销。
+        #[pallet::constant]
+        type RevocationDelay: Get<BlockNumberFor<Self>>;
+        /// 转移冷却期（区块数）：存证转移后至少经过该区块数才能再次转移，抑制市场上的对敲交易；设为 0 表示不限制
+        ///
+        /// 管理员、仲裁、账户恢复和 NFT 镜像同步导致的转移不受限制。
+        #[pallet::constant]
+        type TransferCooldown: Get<BlockNumberFor<Self>>;
+        /// 出售存证时从成交价中扣除的协议费比例，费用直接销毁；设为 0 表示不收费
+        #[pallet::constant]
+        type SaleFee: Get<Perbill>;
+        /// 创建者可以设置的最高版税比例
+        #[pallet::constant]
+        type MaxRoyalty: Get<Perbill>;
+        /// 每个区块最多可以结束的拍卖数量，托管出售的到期放款使用同一上限
+        #[pallet::constant]
+        type MaxAuctionsPerBlock: Get<u32>;
+        /// 托管出售的争议期（区块数）：存证转给买家后货款由买家保留该区块数，期间买家可以发起争议，
+        /// 否则到期自动支付给卖家；设为 0 表示不支持托管出售
+        #[pallet::constant]
+        type EscrowPeriod: Get<BlockNumberFor<Self>>;
+        /// 链下工作机签名载荷使用的密钥类型
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+        /// 链下工作机提交的无签名交易在交易池中的优先级
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+        /// 链下工作机每隔多少个区块检查一次存证 `uri` 的可用性，设为 0 表示不检查
+        #[pallet::constant]
+        type AvailabilityCheckInterval: Get<BlockNumberFor<Self>>;
+        /// 每次可用性检查最多检查的存证数量，剩余的存证在下一次检查中继续
+        #[pallet::constant]
+        type MaxAvailabilityChecks: Get<u32>;
+        /// 链下工作机每隔多少个区块把这段时间内创建的存证的默克尔根锚定到以太坊，设为 0 表示不锚定
+        #[pallet::constant]
+        type AnchorInterval: Get<BlockNumberFor<Self>>;
+        /// 批量存证的默克尔证明最多包含的兄弟节点数量，即树的最大深度
+        #[pallet::constant]
+        type MaxProofDepth: Get<u32>;
+        /// 计算存证哈希、提交-揭示承诺和默克尔节点使用的摘要算法，可与链本身的 `Hashing` 不同；
+        /// 需要与以太坊工具的结果一致时设为 `Keccak256`，已有存证的链修改前必须先迁移存储
+        type ClaimHasher: Hash<Output = ClaimHashOf<Self>>;
+        /// 是否要求按内容创建的存证必须是合法的 IPFS CID（CIDv0 或 CIDv1），
+        /// 只按哈希登记的存证无法校验，不受影响
+        #[pallet::constant]
+        type ValidateContentIds: Get<bool>;
+        /// 查询和请求保留原文的预映像提供者，通常为 `pallet_preimage`，哈希算法必须与 `ClaimHasher` 一致；
+        /// 原文由用户直接登记到预映像 pallet，`StorePreimage` 只在基准测试中用于预先登记原文
+        type Preimages: QueryPreimage<H = Self::ClaimHasher> + StorePreimage;
+        /// 存证创建后的回调，返回错误时整个调用回滚；不需要时设为 `()`
+        type OnClaimCreated: OnClaimCreated<Self::AccountId, ClaimHashOf<Self>>;
+        /// 存证被撤销（包括强制撤销）后的回调，返回错误时整个调用回滚；不需要时设为 `()`
+        type OnClaimRevoked: OnClaimRevoked<Self::AccountId, ClaimHashOf<Self>>;
+        /// 把存证镜像为 NFT 的实现，返回错误时创建或转移存证的调用回滚；不需要时设为 `()`
+        type NftMirror: ClaimNftMirror<Self::AccountId, ClaimHashOf<Self>>;
+        /// 把存证转给兄弟平行链时发送 XCM 消息的路由，不支持跨链时设为 `()`
+        type XcmSender: SendXcm;
+        /// 其他链通过 XCM 调用 `force_register_remote_claim` 的来源，成功时返回来源链的位置；
+        /// 平行链运行时通常设为 `pallet_xcm::EnsureXcm<remote::IsTrustedSibling<Runtime>>`，
+        /// 不支持跨链时设为 `EnsureNever`
+        type RemoteOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Location>;
+        /// 其他链上的应用通过 XCM 调用 `create_claim_via_xcm` 的来源，成功时返回存证所有者在本链的账户；
+        /// 平行链运行时通常在 `pallet_xcm::EnsureXcm<允许的来源>` 外用 `MapSuccess` 把位置转换为主权账户，
+        /// 不支持跨链时设为 `EnsureNever`
+        type XcmOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+        /// 可以不经所有者同意强制撤销或转移存证的管理员来源（如治理或 Root）
+        type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// 本 pallet 中各个调用的权重
+        type WeightInfo: WeightInfo;
     }
 
+    /// 存证的键：存证内容经过 `T::ClaimHasher` 计算得到的摘要
+    pub type ClaimHashOf<T> = <T as frame_system::Config>::Hash;
+
     #[pallet::storage]
     #[pallet::getter(fn proofs)]
     pub type Proofs<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
-        BoundedVec<u8, T::MaxClaimLength>,
-        (T::AccountId, BlockNumberFor<T>)
+        ClaimHashOf<T>,
+        ClaimDetails<T>
     >;
 
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        ClaimCreated(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
-        ClaimRevoked(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
-        ClaimTransfered(T::AccountId, T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
-    }
+    /// 账户 -> 存证哈希 的反向索引，用于枚举某个账户拥有的全部存证
+    #[pallet::storage]
+    pub type OwnerClaims<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        (),
+        OptionQuery,
+    >;
 
-    #[pallet::error]
-    pub enum Error<T> {
-        ProofAlreadyExist,
-        ClaimLengthTooLarge,
-        ClaimNotExist,
-        NotClaimOwner,
-    }
+    /// 接受他人代付创建存证的账户
+    #[pallet::storage]
+    pub type AllowSponsorship<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
 
-    #[pallet::hooks]
-    // 为上面定义的Pallet结构体实现钩子函数：Hooks<BlockNumberFor<T>>
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}   // 没有定义钩子函数
+    /// 提交-揭示流程中的承诺：(提交者, hash(存证内容 ++ 盐)) => 承诺详情
+    #[pallet::storage]
+    pub type Commitments<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        ClaimCommitment<BlockNumberFor<T>, BalanceOf<T>>,
+        OptionQuery,
+    >;
 
-    #[pallet::call]
-    impl <T: Config> Pallet<T> {
-        #[pallet::call_index(0)]
-        #[pallet::weight({0})]
-        pub fn create_claim(
-            origin: OriginFor<T>,
-            claim: BoundedVec<u8, T::MaxClaimLength>
-        ) -> DispatchResult {
-            let sender = ensure_signed(origin)?;
+    /// 尚未仲裁的争议，每个存证最多一个，争议期间存证不能转移
+    #[pallet::storage]
+    pub type Disputes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        Dispute<T::AccountId, ClaimHashOf<T>, BlockNumberFor<T>, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// 尚未仲裁的虚假存证举报，每个存证最多一个，举报期间存证不能转移或由所有者撤销
+    #[pallet::storage]
+    pub type Reports<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        FraudReport<T::AccountId, ClaimHashOf<T>, BlockNumberFor<T>, BalanceOf<T>>,
+        OptionQuery,
+    >;
 
-            ensure!(claim.len() <= T::MaxClaimLength::get() as usize, Error::<T>::ClaimLengthTooLarge);
-            ensure!(!Proofs::<T>::contains_key(&claim), Error::<T>::ProofAlreadyExist);
+    /// 仍处于挑战期的存证 => 挑战期结束的区块号，确认后删除
+    #[pallet::storage]
+    pub type ProvisionalClaims<T: Config> =
+        StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, BlockNumberFor<T>, OptionQuery>;
 
-            Proofs::<T>::insert(
-                &claim,
-                (sender.clone(), frame_system::Pallet::<T>::block_number())
-            );
+    /// 区块号 => 该区块 `on_initialize` 中挑战期结束的存证，同一区块创建的存证在同一区块结束挑战期
+    #[pallet::storage]
+    pub type ConfirmationQueue<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<ClaimHashOf<T>, T::MaxClaimsPerBlock>,
+        ValueQuery,
+    >;
 
-            Self::deposit_event(Event::ClaimCreated(sender, claim));
+    /// 第三方对存证的背书：存证哈希 => 背书账户 => 背书区块号
+    #[pallet::storage]
+    pub type Attestations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
 
-            Ok(().into())
-        }
+    /// 每个存证当前的背书数量
+    #[pallet::storage]
+    pub type AttestationCount<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, u32, ValueQuery>;
 
-        #[pallet::call_index(1)]
-        #[pallet::weight({0})]
-        pub fn revoke_claim(
-            origin: OriginFor<T>,
-            claim: BoundedVec<u8, T::MaxClaimLength>
-        ) -> DispatchResult {
-            let sender = ensure_signed(origin)?;
+    /// 存证所有者授予的使用许可：存证哈希 => 被许可人 => 许可条款，存证转移后许可保持不变
+    #[pallet::storage]
+    pub type Licenses<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        License<ClaimHashOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
 
-            let (owner, _) = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+    /// 每个存证当前的许可数量，不超过 `MaxLicenses`
+    #[pallet::storage]
+    pub type LicenseCount<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, u32, ValueQuery>;
+
+    /// 付费许可的付款计划：存证哈希 => 被许可人 => 每期费用、周期和已付款的截止区块
+    #[pallet::storage]
+    pub type LicenseFees<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        LicenseFee<BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
 
-            ensure!(owner == sender, Error::<T>::NotClaimOwner);
+    /// 区块号 => 该区块 `on_initialize` 中需要自动结算许可费的许可
+    #[pallet::storage]
+    pub type LicenseFeeQueue<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(ClaimHashOf<T>, T::AccountId), T::MaxScheduledPerBlock>,
+        ValueQuery,
+    >;
 
-            Proofs::<T>::remove(&claim);
+    /// 存证的溯源注释，按添加顺序保存，存证转移后保留，删除时一并清除
+    #[pallet::storage]
+    pub type Annotations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        BoundedVec<Annotation<T::AccountId, AnnotationTextOf<T>, BlockNumberFor<T>>, T::MaxAnnotations>,
+        ValueQuery,
+    >;
 
-            Self::deposit_event(Event::ClaimRevoked(sender, claim));
+    /// 归属式转移的存证：新所有者在 `unlocks_at` 之前只有使用权（背书、注释），不能转移、出售或撤销
+    #[pallet::storage]
+    pub type Vestings<T: Config> =
+        StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, Vesting<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
 
-            Ok(().into())
-        }
+    /// 作为借贷抵押品锁定的存证及登记的锁定方，锁定期间存证不能转移或撤销，只有锁定方可以解锁
+    #[pallet::storage]
+    pub type CollateralLocks<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, T::AccountId, OptionQuery>;
 
-        #[pallet::call_index(2)]
-        #[pallet::weight({0})]
-        pub fn transfer_claim(
-            origin: OriginFor<T>,
-            target: T::AccountId,
-            claim: BoundedVec<u8, T::MaxClaimLength>
-        ) -> DispatchResult {
-            let sender = ensure_signed(origin)?;
-            // 获取存证，如果存证不存在则返回错误
-            let (owner, _) = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+    /// 关注存证的账户：存证转移或撤销时额外发出带有关注者列表的 `WatchedClaimChanged` 事件
+    #[pallet::storage]
+    pub type Watchers<T: Config> =
+        StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, BoundedVec<T::AccountId, T::MaxWatchers>, ValueQuery>;
 
-            // 确保调用者是存证的所有者
-            ensure!(owner == sender, Error::<T>::NotClaimOwner);
+    /// 已注册的公证人及其保留的质押
+    #[pallet::storage]
+    pub type Notaries<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
 
-            // 更新存证的所有者
-            Proofs::<T>::insert(
-                &claim,
-                (target.clone(), frame_system::Pallet::<T>::block_number())
-            );
+    /// 带有公证徽章的存证及为其公证的公证人
+    #[pallet::storage]
+    pub type Notarizations<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, T::AccountId, OptionQuery>;
 
-            // 触发事件
-            Self::deposit_event(Event::ClaimTransfered(sender, target, claim));
+    /// 存证的历史所有者及其转出时的区块号，按时间顺序排列，用于溯源审计
+    #[pallet::storage]
+    pub type OwnershipHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        BoundedVec<(T::AccountId, BlockNumberFor<T>), T::MaxHistoryLen>,
+        ValueQuery,
+    >;
 
-            Ok(().into())
-        }
+    /// 新版本存证 => 它取代的旧版本存证
+    #[pallet::storage]
+    pub type Supersedes<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, ClaimHashOf<T>, OptionQuery>;
 
-    }
+    /// 旧版本存证 => 取代它的新版本存证，与 `Supersedes` 一起构成版本链
+    #[pallet::storage]
+    pub type SupersededBy<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, ClaimHashOf<T>, OptionQuery>;
+
+    /// 父存证 => 挂载在其下的子存证，例如一部作品的各个章节
+    #[pallet::storage]
+    pub type Children<T: Config> =
+        StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, BoundedVec<ClaimHashOf<T>, T::MaxChildren>, ValueQuery>;
+
+    /// 子存证 => 父存证
+    #[pallet::storage]
+    pub type ParentOf<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, ClaimHashOf<T>, OptionQuery>;
+
+    /// 下一个存证集合的编号
+    #[pallet::storage]
+    pub type NextCollectionId<T: Config> = StorageValue<_, CollectionId, ValueQuery>;
+
+    /// 集合编号 => 集合详情
+    #[pallet::storage]
+    pub type Collections<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        CollectionId,
+        Collection<T::AccountId, CollectionMetadataOf<T>>,
+        OptionQuery,
+    >;
+
+    /// 集合编号 => 集合中的存证
+    #[pallet::storage]
+    pub type CollectionClaims<T: Config> =
+        StorageMap<_, Blake2_128Concat, CollectionId, BoundedVec<ClaimHashOf<T>, T::MaxCollectionSize>, ValueQuery>;
+
+    /// 存证 => 所属的集合，每个存证最多属于一个集合
+    #[pallet::storage]
+    pub type ClaimCollection<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, CollectionId, OptionQuery>;
+
+    /// 命名空间前缀 => 命名空间详情
+    #[pallet::storage]
+    pub type Namespaces<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        NamespaceOf<T>,
+        Namespace<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// 被治理禁止登记的存证哈希（如已知的违法内容）
+    #[pallet::storage]
+    pub type Banned<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, (), OptionQuery>;
 
-}
\ No newline at end of file
+    /// 由治理设置的免费账户（如大学、登记机构）：`create_claim` 免交易费，创建存证时不保留押金、不收创建费用
+    #[pallet::storage]
+    pub type FeeExempt<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// 被治理禁止创建和接收存证的账户
+    #[pallet::storage]
+    pub type BannedAccounts<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// 存证 => 共同所有者（不含 `ClaimDetails::owner`）
+    ///
+    /// 存证有共同所有者时，主所有者撤销或转移存证需要全部共同所有者事先批准。
+    #[pallet::storage]
+    pub type CoOwners<T: Config> =
+        StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, BoundedVec<T::AccountId, T::MaxCoOwners>, ValueQuery>;
+
+    /// (存证, 共同所有者) => 该共同所有者批准的操作
+    #[pallet::storage]
+    pub type CoOwnerApprovals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        CoOwnerAction<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// 存证 => 撤销门限，设置后所有者撤销存证前需要收集足够的签名人批准
+    #[pallet::storage]
+    pub type RevocationGuards<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, RevocationGuard<T>, OptionQuery>;
+
+    /// 存证 => 已批准撤销的签名人
+    #[pallet::storage]
+    pub type RevocationApprovals<T: Config> =
+        StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, BoundedVec<T::AccountId, T::MaxSignatories>, ValueQuery>;
+
+    /// 所有者 => 社交恢复方案
+    #[pallet::storage]
+    pub type RecoveryConfigs<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, RecoveryConfig<T>, OptionQuery>;
+
+    /// 丢失私钥的账户 => 正在进行的恢复
+    #[pallet::storage]
+    pub type ActiveRecoveries<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ActiveRecovery<T>, OptionQuery>;
+
+    /// 存证 => 继承设置，所有者长期不活动时存证可以转给继承人
+    #[pallet::storage]
+    pub type Inheritors<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        Inheritance<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// 账户 => 最近一次活动（`ping` 或设置继承）的区块号
+    #[pallet::storage]
+    pub type LastActivity<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, ValueQuery>;
+
+    /// 存证 => 预约的转移，每个存证最多一个
+    #[pallet::storage]
+    pub type ScheduledTransfers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        ScheduledTransfer<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// 区块号 => 该区块 `on_initialize` 中需要执行转移的存证
+    #[pallet::storage]
+    pub type TransferQueue<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<ClaimHashOf<T>, T::MaxScheduledPerBlock>,
+        ValueQuery,
+    >;
+
+    /// 存证 => 发起两步撤销的区块号
+    #[pallet::storage]
+    pub type PendingRevocations<T: Config> =
+        StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, BlockNumberFor<T>, OptionQuery>;
+
+    /// 存证 => 出售挂单，挂单期间存证只能通过 `buy_claim` 转移
+    #[pallet::storage]
+    pub type Listings<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        Listing<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// 存证 => 对存证内容签名的作者公钥，作者与所有者可以不同，存证转移后保持不变
+    #[pallet::storage]
+    pub type ClaimAuthors<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, AuthorKey, OptionQuery>;
+
+    /// 通过预映像创建、并请求预映像 pallet 保留原文的存证，存证删除时取消请求
+    #[pallet::storage]
+    pub type RetainedPreimages<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, (), OptionQuery>;
+
+    /// 可以互相转移存证的兄弟平行链编号，由管理员维护
+    #[pallet::storage]
+    pub type TrustedParas<T: Config> = StorageMap<_, Twox64Concat, u32, (), OptionQuery>;
+
+    /// 存证 => 创建者设置的版税，存证转移后保持不变
+    #[pallet::storage]
+    pub type Royalties<T: Config> =
+        StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, Royalty<T::AccountId>, OptionQuery>;
+
+    /// 存证 => 进行中的拍卖，拍卖期间存证不能转移
+    #[pallet::storage]
+    pub type Auctions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        Auction<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// 区块号 => 该区块 `on_initialize` 中需要结算的拍卖
+    #[pallet::storage]
+    pub type AuctionEnds<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<ClaimHashOf<T>, T::MaxAuctionsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// 存证 => 尚未放款的托管出售
+    #[pallet::storage]
+    pub type Escrows<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        Escrow<T::AccountId, ClaimHashOf<T>, BlockNumberFor<T>, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// 区块号 => 该区块 `on_initialize` 中争议期结束、需要放款的托管出售
+    #[pallet::storage]
+    pub type EscrowReleases<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<ClaimHashOf<T>, T::MaxAuctionsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// 下一个打包出售的编号
+    #[pallet::storage]
+    pub type NextBundleId<T: Config> = StorageValue<_, BundleId, ValueQuery>;
+
+    /// 打包编号 => 打包出售的卖家和总价
+    #[pallet::storage]
+    pub type Bundles<T: Config> =
+        StorageMap<_, Blake2_128Concat, BundleId, Bundle<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
+    /// 打包编号 => 打包中的存证
+    #[pallet::storage]
+    pub type BundleClaims<T: Config> =
+        StorageMap<_, Blake2_128Concat, BundleId, BoundedVec<ClaimHashOf<T>, T::MaxBundleSize>, ValueQuery>;
+
+    /// 存证 => 所在的打包出售，每个存证最多属于一个打包
+    #[pallet::storage]
+    pub type ClaimBundle<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, BundleId, OptionQuery>;
+
+    /// 已锚定到以太坊的存证根及锚定记录
+    #[pallet::storage]
+    pub type Anchors<T: Config> =
+        StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, AnchorRecord<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+
+    /// 公证人最近一次提交的原生代币价格
+    #[pallet::storage]
+    pub type NativePrice<T: Config> = StorageValue<_, PriceReport<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+
+    /// 本周期按喂价计算出的基础押金，未设置时使用 `DepositBase`
+    #[pallet::storage]
+    pub type EraDepositBase<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
+
+    /// 批量登记的默克尔根，其中的单个存证可以在需要时凭证明上链
+    #[pallet::storage]
+    pub type BatchRoots<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        BatchRoot<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// 每个账户写入链下索引的存证活动数量，即下一条活动的编号
+    #[pallet::storage]
+    pub type ActivityCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// 每个账户当前拥有的存证数量
+    #[pallet::storage]
+    pub type ClaimCountOf<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// 当前存储中的存证总数（尚未被清理的过期存证也计算在内），O(1) 查询
+    #[pallet::storage]
+    #[pallet::getter(fn total_claims)]
+    pub type TotalClaims<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// 当前区块内的存证统计，区块结束时保存为 `LastBlockStats` 并计入 `CumulativeStats`
+    #[pallet::storage]
+    pub type BlockStats<T: Config> = StorageValue<_, ClaimStats, ValueQuery>;
+
+    /// 最近一个结束的区块的存证统计
+    #[pallet::storage]
+    #[pallet::getter(fn last_block_stats)]
+    pub type LastBlockStats<T: Config> = StorageValue<_, ClaimStats, ValueQuery>;
+
+    /// 账户 => 声誉：存证经过挑战期确认和获得他人背书时加 1，撤回背书或确认的存证被删除时减 1，
+    /// 存证被举报成立时扣除 `FraudReputationPenalty`，可以为负
+    #[pallet::storage]
+    #[pallet::getter(fn reputation)]
+    pub type Reputation<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, i32, ValueQuery>;
+
+    /// 存证 => 挑战期结束确认时获得声誉的所有者，存证删除时从该账户扣回
+    #[pallet::storage]
+    pub type ConfirmationCredits<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, T::AccountId, OptionQuery>;
+
+    /// 账户 => 作为所有者经过挑战期确认的存证数量，存证转移后仍计入确认时的所有者，存证删除时减少
+    #[pallet::storage]
+    pub type ConfirmedClaimCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// 账户 => 灵魂绑定徽章：确认的存证数量达到 `BadgeThreshold` 时颁发，与账户绑定，不能转移
+    #[pallet::storage]
+    #[pallet::getter(fn badge_of)]
+    pub type Badges<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Badge<BlockNumberFor<T>>, OptionQuery>;
+
+    /// 到最近一个结束的区块为止的累计存证统计
+    #[pallet::storage]
+    #[pallet::getter(fn cumulative_stats)]
+    pub type CumulativeStats<T: Config> = StorageValue<_, ClaimStats, ValueQuery>;
+
+    /// 两阶段转移中尚未被接受的转移邀请，每个存证最多一个
+    #[pallet::storage]
+    pub type PendingTransfers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        PendingTransfer<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// 每个存证被授权代为转移的账户，存证转移或撤销后自动清除
+    #[pallet::storage]
+    pub type Approvals<T: Config> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, T::AccountId, OptionQuery>;
+
+    /// 所有者授权的操作员：操作员可以转移或撤销该所有者的全部存证
+    #[pallet::storage]
+    pub type Operators<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::type_value]
+    pub fn DefaultMaxClaimLength<T: Config>() -> u32 {
+        T::MaxClaimLength::get()
+    }
+
+    /// 当前允许的最大存证长度，由治理在 `MaxClaimLength` 以内调整，默认等于 `MaxClaimLength`
+    #[pallet::storage]
+    pub type CurrentMaxLength<T: Config> = StorageValue<_, u32, ValueQuery, DefaultMaxClaimLength<T>>;
+
+    /// 当前区块已创建的存证数量，每个区块开始时清零
+    #[pallet::storage]
+    pub type ClaimsThisBlock<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// 紧急暂停开关，为 `true` 时除 `unpause` 外的所有调用都会失败
+    #[pallet::storage]
+    pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// `on_idle` 遍历注册表的游标：上一次检查到的存证哈希，下次从它之后继续检查
+    #[pallet::storage]
+    pub type ExpirySweepCursor<T: Config> = StorageValue<_, ClaimHashOf<T>, OptionQuery>;
+
+    /// 过期索引：区块号 => 在该区块过期的存证，创建时写入，续期时移到新的区块，删除存证时移除
+    ///
+    /// 读取时仍按存证当前的过期区块核对。v1 迁移来的存证在迁移时写入，区块已满时顺延到之后的区块；
+    /// `on_idle` 遍历 `Proofs` 只作为兜底清理。
+    #[pallet::storage]
+    pub type ExpiringAt<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<ClaimHashOf<T>, T::MaxExpiringPerBlock>,
+        ValueQuery,
+    >;
+
+    /// 过期索引中尚未处理完的最早区块，为空表示当前区块之前的索引都已处理
+    #[pallet::storage]
+    pub type ExpiryBacklog<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// 事件通过 [`Pallet::deposit_event`] 发出，所有与存证相关的事件都以存证哈希作为索引主题
+    #[pallet::event]
+    pub enum Event<T: Config> {
+        /// 创建了存证
+        ClaimCreated { who: T::AccountId, claim_hash: ClaimHashOf<T>, block: BlockNumberFor<T> },
+        /// 存证被撤销
+        ClaimRevoked { who: T::AccountId, claim_hash: ClaimHashOf<T>, block: BlockNumberFor<T> },
+        /// 存证被转移给 `target`
+        ClaimTransfered {
+            who: T::AccountId,
+            target: T::AccountId,
+            claim_hash: ClaimHashOf<T>,
+            block: BlockNumberFor<T>,
+        },
+        /// 所有者设置了存证的标题和外部资源地址
+        ClaimMetadataSet { who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 过期的存证被清理
+        ClaimExpired { who: T::AccountId, claim_hash: ClaimHashOf<T>, block: BlockNumberFor<T> },
+        /// 存证被续期，附带新的过期区块号
+        ClaimRenewed { who: T::AccountId, claim_hash: ClaimHashOf<T>, expires_at: BlockNumberFor<T> },
+        /// 存证将在 `expires_at` 区块过期，提醒所有者及时续期
+        ClaimExpiringSoon { who: T::AccountId, claim_hash: ClaimHashOf<T>, expires_at: BlockNumberFor<T> },
+        /// 所有者向接收方发出转移邀请
+        ClaimOffered { who: T::AccountId, target: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 转移邀请被所有者取消
+        ClaimOfferCancelled { who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 所有者授权代理账户转移存证
+        TransferApproved { who: T::AccountId, delegate: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 所有者取消转移授权
+        ApprovalCancelled { who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 所有者设置或取消操作员，`approved` 表示是否授权
+        OperatorSet { who: T::AccountId, operator: T::AccountId, approved: bool },
+        /// pallet 被暂停
+        PalletPaused,
+        /// pallet 恢复运行
+        PalletUnpaused,
+        /// 账户设置是否接受代付创建存证
+        SponsorshipAllowed { who: T::AccountId, allowed: bool },
+        /// 代付方为所有者创建了存证
+        ClaimSponsored { who: T::AccountId, owner: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 存证被所有者冻结
+        ClaimFrozen { who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 存证被所有者解冻
+        ClaimThawed { who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 公证人报告了存证内容的可用性
+        ContentAvailabilityReported { who: T::AccountId, claim_hash: ClaimHashOf<T>, available: bool },
+        /// 公证人记录了存证根在以太坊上的锚定交易
+        AnchorRecorded { who: T::AccountId, root: ClaimHashOf<T>, tx_hash: [u8; 32] },
+        /// 创建了附带作者签名的存证
+        SignedClaimCreated { who: T::AccountId, claim_hash: ClaimHashOf<T>, author: AuthorKey },
+        /// 通过已登记的预映像创建了存证
+        PreimageClaimCreated { who: T::AccountId, claim_hash: ClaimHashOf<T>, len: u32, retained: bool },
+        /// 管理员设置了兄弟平行链是否可信
+        TrustedParaSet { para_id: u32, trusted: bool },
+        /// 存证已转给兄弟平行链上的账户，本链的记录已删除
+        ClaimSentToPara { who: T::AccountId, claim_hash: ClaimHashOf<T>, para_id: u32, target: T::AccountId },
+        /// 登记了从兄弟平行链转入的存证
+        RemoteClaimRegistered { para_id: u32, who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 登记了批量存证的默克尔根
+        BatchRootCreated { who: T::AccountId, root: ClaimHashOf<T>, count: u32 },
+        /// 批量存证中的一个存证凭默克尔证明上链
+        BatchMembershipProven { root: ClaimHashOf<T>, claim_hash: ClaimHashOf<T> },
+        /// 所有者删除了批量存证的默克尔根
+        BatchRootRemoved { who: T::AccountId, root: ClaimHashOf<T> },
+        /// 对存证发起争议
+        DisputeRaised { who: T::AccountId, claim_hash: ClaimHashOf<T>, evidence: ClaimHashOf<T> },
+        /// 争议被仲裁
+        DisputeResolved { claim_hash: ClaimHashOf<T>, resolution: DisputeResolution },
+        /// 账户为存证背书
+        ClaimAttested { who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 账户撤回背书
+        AttestationRevoked { who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 账户质押后注册为公证人
+        NotaryRegistered { who: T::AccountId },
+        /// 公证人注销，质押已退还
+        NotaryUnregistered { who: T::AccountId },
+        /// 公证人为存证添加公证徽章
+        ClaimNotarized { who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 公证人因错误公证被罚没质押
+        NotarySlashed { who: T::AccountId, amount: BalanceOf<T> },
+        /// 所有者登记了存证的新版本
+        ClaimSuperseded { who: T::AccountId, previous: ClaimHashOf<T>, claim_hash: ClaimHashOf<T> },
+        /// 在父存证下创建了子存证
+        ChildClaimCreated { who: T::AccountId, parent: ClaimHashOf<T>, claim_hash: ClaimHashOf<T> },
+        /// 创建了存证集合
+        CollectionCreated { who: T::AccountId, collection_id: CollectionId },
+        /// 存证被加入集合
+        ClaimAddedToCollection { collection_id: CollectionId, claim_hash: ClaimHashOf<T> },
+        /// 集合连同其中的存证被转移
+        CollectionTransferred { who: T::AccountId, target: T::AccountId, collection_id: CollectionId },
+        /// 注册或续期了命名空间
+        NamespaceRegistered { who: T::AccountId, prefix: NamespaceOf<T>, expires_at: BlockNumberFor<T> },
+        /// 命名空间被转移
+        NamespaceTransferred { who: T::AccountId, target: T::AccountId, prefix: NamespaceOf<T> },
+        /// 存证哈希被禁止登记
+        ClaimHashBanned { claim_hash: ClaimHashOf<T> },
+        /// 存证哈希被解除禁止
+        ClaimHashUnbanned { claim_hash: ClaimHashOf<T> },
+        /// 账户被禁止创建和接收存证
+        AccountBanned { who: T::AccountId },
+        /// 账户被解除禁止
+        AccountUnbanned { who: T::AccountId },
+        /// 账户被加入免费名单
+        FeeExemptionGranted { who: T::AccountId },
+        /// 账户被移出免费名单
+        FeeExemptionRevoked { who: T::AccountId },
+        /// 调整了允许的最大存证长度
+        MaxClaimLengthSet { max_length: u32 },
+        /// 添加了共同所有者
+        CoOwnerAdded { claim_hash: ClaimHashOf<T>, co_owner: T::AccountId },
+        /// 移除了共同所有者
+        CoOwnerRemoved { claim_hash: ClaimHashOf<T>, co_owner: T::AccountId },
+        /// 共同所有者批准了操作
+        CoOwnerActionApproved { who: T::AccountId, claim_hash: ClaimHashOf<T>, action: CoOwnerAction<T::AccountId> },
+        /// 设置了撤销门限
+        RevocationThresholdSet {
+            claim_hash: ClaimHashOf<T>,
+            signatories: BoundedVec<T::AccountId, T::MaxSignatories>,
+            threshold: u32,
+        },
+        /// 签名人批准撤销存证
+        RevocationApproved { who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 设置了社交恢复方案
+        RecoveryConfigSet { who: T::AccountId },
+        /// 监护人发起恢复
+        RecoveryVouched { who: T::AccountId, lost: T::AccountId, rescuer: T::AccountId },
+        /// 监护人数量达到门限，等待期开始
+        RecoveryStarted { lost: T::AccountId, rescuer: T::AccountId },
+        /// 所有者否决了恢复
+        RecoveryVetoed { who: T::AccountId },
+        /// 恢复完成
+        RecoveryCompleted { lost: T::AccountId, rescuer: T::AccountId, count: u32 },
+        /// 设置了存证的继承人
+        InheritorSet { claim_hash: ClaimHashOf<T>, inheritor: T::AccountId, inactivity_period: BlockNumberFor<T> },
+        /// 账户报告了活动
+        Heartbeat { who: T::AccountId },
+        /// 继承人取得了存证
+        InheritanceClaimed { owner: T::AccountId, who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 预约了存证转移
+        TransferScheduled {
+            claim_hash: ClaimHashOf<T>,
+            who: T::AccountId,
+            target: T::AccountId,
+            at: BlockNumberFor<T>,
+        },
+        /// 取消了预约的存证转移
+        ScheduledTransferCancelled { claim_hash: ClaimHashOf<T> },
+        /// 预约的存证转移执行失败（如存证被冻结或处于争议中）
+        ScheduledTransferFailed { claim_hash: ClaimHashOf<T>, error: DispatchError },
+        /// 发起了两步撤销
+        RevocationBegun { claim_hash: ClaimHashOf<T>, ready_at: BlockNumberFor<T> },
+        /// 取消了两步撤销
+        RevocationCancelled { claim_hash: ClaimHashOf<T> },
+        /// 存证挂单出售
+        ClaimListed { who: T::AccountId, claim_hash: ClaimHashOf<T>, price: BalanceOf<T> },
+        /// 存证以荷兰式挂单出售
+        DutchListingCreated {
+            who: T::AccountId,
+            claim_hash: ClaimHashOf<T>,
+            start_price: BalanceOf<T>,
+            floor_price: BalanceOf<T>,
+            floor_at: BlockNumberFor<T>,
+        },
+        /// 取消了出售挂单
+        ListingCancelled { claim_hash: ClaimHashOf<T> },
+        /// 存证成交
+        ClaimSold { seller: T::AccountId, buyer: T::AccountId, claim_hash: ClaimHashOf<T>, price: BalanceOf<T> },
+        /// 创建者设置了版税
+        RoyaltySet { claim_hash: ClaimHashOf<T>, beneficiary: T::AccountId, rate: Perbill },
+        /// 成交时支付了版税
+        RoyaltyPaid { claim_hash: ClaimHashOf<T>, beneficiary: T::AccountId, amount: BalanceOf<T> },
+        /// 发起了拍卖
+        AuctionStarted {
+            who: T::AccountId,
+            claim_hash: ClaimHashOf<T>,
+            min_bid: BalanceOf<T>,
+            ends_at: BlockNumberFor<T>,
+        },
+        /// 出价成为当前最高价
+        BidPlaced { who: T::AccountId, claim_hash: ClaimHashOf<T>, amount: BalanceOf<T> },
+        /// 拍卖成交
+        AuctionSettled { seller: T::AccountId, buyer: T::AccountId, claim_hash: ClaimHashOf<T>, price: BalanceOf<T> },
+        /// 拍卖结束时没有出价，存证留在卖家手中
+        AuctionEndedWithoutBids { claim_hash: ClaimHashOf<T> },
+        /// 拍卖结算失败（如存证被冻结或处于争议中），出价退还给出价人
+        AuctionSettlementFailed { claim_hash: ClaimHashOf<T>, error: DispatchError },
+        /// 账户提交了存证承诺
+        ClaimCommitted { who: T::AccountId, commitment: ClaimHashOf<T> },
+        /// 账户揭示承诺并获得存证，附带承诺时的区块号
+        ClaimRevealed { who: T::AccountId, claim_hash: ClaimHashOf<T>, committed_at: BlockNumberFor<T> },
+        /// 公证人提交了原生代币价格
+        PriceReported { who: T::AccountId, price: FixedU128 },
+        /// 按最新喂价重新计算了基础押金
+        DepositBaseUpdated { deposit: BalanceOf<T>, price: FixedU128 },
+        /// 账户举报了虚假存证
+        ClaimReported { who: T::AccountId, claim_hash: ClaimHashOf<T>, evidence: ClaimHashOf<T> },
+        /// 举报成立：存证被撤销，押金被罚没，其中 `reward` 奖励给举报人
+        ReportUpheld {
+            claim_hash: ClaimHashOf<T>,
+            reporter: T::AccountId,
+            slashed: BalanceOf<T>,
+            reward: BalanceOf<T>,
+        },
+        /// 举报被驳回，举报人的保证金被罚没
+        ReportRejected { claim_hash: ClaimHashOf<T>, reporter: T::AccountId, bond: BalanceOf<T> },
+        /// 存证的挑战期结束且没有成立的挑战，存证已确认
+        ClaimConfirmed { who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 账户确认的存证达到 `BadgeThreshold`，获得了徽章
+        BadgeMinted { who: T::AccountId, confirmed: u32 },
+        /// 所有者授予或更新了存证的使用许可
+        LicenseGranted {
+            claim_hash: ClaimHashOf<T>,
+            licensee: T::AccountId,
+            terms_hash: ClaimHashOf<T>,
+            expiry: BlockNumberFor<T>,
+        },
+        /// 所有者撤销了存证的使用许可
+        LicenseRevoked { claim_hash: ClaimHashOf<T>, licensee: T::AccountId },
+        /// 所有者为许可设置了付款计划，被许可人结算第一期后生效
+        LicenseFeeSet {
+            claim_hash: ClaimHashOf<T>,
+            licensee: T::AccountId,
+            amount: BalanceOf<T>,
+            period: BlockNumberFor<T>,
+        },
+        /// 被许可人向所有者支付了许可费，许可已付款至 `paid_until` 区块
+        LicenseFeePaid {
+            claim_hash: ClaimHashOf<T>,
+            licensee: T::AccountId,
+            amount: BalanceOf<T>,
+            paid_until: BlockNumberFor<T>,
+        },
+        /// 被许可人未能支付到期的许可费，许可被撤销
+        LicenseFeeMissed { claim_hash: ClaimHashOf<T>, licensee: T::AccountId, due: BalanceOf<T> },
+        /// 存证以托管方式出售给买家，货款在 `release_at` 区块放款给卖家
+        ClaimSoldInEscrow {
+            seller: T::AccountId,
+            buyer: T::AccountId,
+            claim_hash: ClaimHashOf<T>,
+            price: BalanceOf<T>,
+            release_at: BlockNumberFor<T>,
+        },
+        /// 买家在争议期内对托管出售发起争议，等待仲裁
+        EscrowDisputed { buyer: T::AccountId, claim_hash: ClaimHashOf<T>, evidence: ClaimHashOf<T> },
+        /// 托管的货款已支付给卖家
+        EscrowReleased { seller: T::AccountId, claim_hash: ClaimHashOf<T>, price: BalanceOf<T> },
+        /// 仲裁支持买家：货款退还给买家，存证归还卖家
+        EscrowRefunded { buyer: T::AccountId, claim_hash: ClaimHashOf<T>, price: BalanceOf<T> },
+        /// 争议期结束但放款失败，托管保留等待仲裁
+        EscrowReleaseFailed { claim_hash: ClaimHashOf<T>, error: DispatchError },
+        /// 卖家把多个存证打包挂单出售
+        BundleListed { who: T::AccountId, bundle_id: BundleId, price: BalanceOf<T> },
+        /// 打包中的全部存证已一次性出售给买家
+        BundleSold { seller: T::AccountId, buyer: T::AccountId, bundle_id: BundleId, price: BalanceOf<T> },
+        /// 存证从打包出售中移出
+        ClaimUnbundled { bundle_id: BundleId, claim_hash: ClaimHashOf<T> },
+        /// 打包出售的总价已更新
+        BundlePriceUpdated { bundle_id: BundleId, price: BalanceOf<T> },
+        /// 打包中已没有存证，打包出售取消
+        BundleCancelled { bundle_id: BundleId },
+        /// 账户开始关注存证
+        ClaimWatched { who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 账户取消关注存证
+        ClaimUnwatched { who: T::AccountId, claim_hash: ClaimHashOf<T> },
+        /// 存证添加了一条注释，`index` 为注释在列表中的位置
+        ClaimAnnotated { who: T::AccountId, claim_hash: ClaimHashOf<T>, index: u32 },
+        /// 存证以归属方式转给 `target`，完整的所有权在 `unlocks_at` 区块解锁
+        VestingStarted {
+            who: T::AccountId,
+            target: T::AccountId,
+            claim_hash: ClaimHashOf<T>,
+            unlocks_at: BlockNumberFor<T>,
+        },
+        /// 存证被所有者锁定为 `locker` 的抵押品
+        CollateralLocked { who: T::AccountId, claim_hash: ClaimHashOf<T>, locker: T::AccountId },
+        /// 锁定方解除了存证的抵押锁定
+        CollateralUnlocked { claim_hash: ClaimHashOf<T>, locker: T::AccountId },
+        /// 被关注的存证已转移或撤销，`owner` 为转移后的所有者，撤销或过期删除时为 `None`
+        WatchedClaimChanged {
+            claim_hash: ClaimHashOf<T>,
+            owner: Option<T::AccountId>,
+            watchers: BoundedVec<T::AccountId, T::MaxWatchers>,
+        },
+    }
+
+    impl<T: Config> Event<T> {
+        /// 事件涉及的存证哈希，作为事件的索引主题。版本链和父子关系事件同时包含两个存证
+        fn topics(&self) -> Vec<ClaimHashOf<T>> {
+            match self {
+                Event::ClaimSuperseded { previous: other, claim_hash, .. }
+                | Event::ChildClaimCreated { parent: other, claim_hash, .. } => vec![*other, *claim_hash],
+                Event::ClaimCreated { claim_hash, .. }
+                | Event::ClaimRevoked { claim_hash, .. }
+                | Event::ClaimTransfered { claim_hash, .. }
+                | Event::ClaimMetadataSet { claim_hash, .. }
+                | Event::ClaimExpired { claim_hash, .. }
+                | Event::ClaimRenewed { claim_hash, .. }
+                | Event::ClaimExpiringSoon { claim_hash, .. }
+                | Event::ClaimOffered { claim_hash, .. }
+                | Event::ClaimOfferCancelled { claim_hash, .. }
+                | Event::TransferApproved { claim_hash, .. }
+                | Event::ApprovalCancelled { claim_hash, .. }
+                | Event::ClaimSponsored { claim_hash, .. }
+                | Event::ClaimFrozen { claim_hash, .. }
+                | Event::ClaimThawed { claim_hash, .. }
+                | Event::ContentAvailabilityReported { claim_hash, .. }
+                | Event::SignedClaimCreated { claim_hash, .. }
+                | Event::PreimageClaimCreated { claim_hash, .. }
+                | Event::ClaimSentToPara { claim_hash, .. }
+                | Event::RemoteClaimRegistered { claim_hash, .. }
+                | Event::BatchMembershipProven { claim_hash, .. }
+                | Event::DisputeRaised { claim_hash, .. }
+                | Event::DisputeResolved { claim_hash, .. }
+                | Event::ClaimReported { claim_hash, .. }
+                | Event::ReportUpheld { claim_hash, .. }
+                | Event::ReportRejected { claim_hash, .. }
+                | Event::ClaimConfirmed { claim_hash, .. }
+                | Event::LicenseGranted { claim_hash, .. }
+                | Event::LicenseRevoked { claim_hash, .. }
+                | Event::LicenseFeeSet { claim_hash, .. }
+                | Event::LicenseFeePaid { claim_hash, .. }
+                | Event::LicenseFeeMissed { claim_hash, .. }
+                | Event::ClaimSoldInEscrow { claim_hash, .. }
+                | Event::EscrowDisputed { claim_hash, .. }
+                | Event::EscrowReleased { claim_hash, .. }
+                | Event::EscrowRefunded { claim_hash, .. }
+                | Event::EscrowReleaseFailed { claim_hash, .. }
+                | Event::ClaimUnbundled { claim_hash, .. }
+                | Event::ClaimWatched { claim_hash, .. }
+                | Event::ClaimUnwatched { claim_hash, .. }
+                | Event::WatchedClaimChanged { claim_hash, .. }
+                | Event::ClaimAnnotated { claim_hash, .. }
+                | Event::VestingStarted { claim_hash, .. }
+                | Event::CollateralLocked { claim_hash, .. }
+                | Event::CollateralUnlocked { claim_hash, .. }
+                | Event::ClaimAttested { claim_hash, .. }
+                | Event::AttestationRevoked { claim_hash, .. }
+                | Event::ClaimNotarized { claim_hash, .. }
+                | Event::ClaimAddedToCollection { claim_hash, .. }
+                | Event::ClaimHashBanned { claim_hash, .. }
+                | Event::ClaimHashUnbanned { claim_hash, .. }
+                | Event::CoOwnerAdded { claim_hash, .. }
+                | Event::CoOwnerRemoved { claim_hash, .. }
+                | Event::CoOwnerActionApproved { claim_hash, .. }
+                | Event::RevocationThresholdSet { claim_hash, .. }
+                | Event::RevocationApproved { claim_hash, .. }
+                | Event::InheritorSet { claim_hash, .. }
+                | Event::InheritanceClaimed { claim_hash, .. }
+                | Event::TransferScheduled { claim_hash, .. }
+                | Event::ScheduledTransferCancelled { claim_hash, .. }
+                | Event::ScheduledTransferFailed { claim_hash, .. }
+                | Event::RevocationBegun { claim_hash, .. }
+                | Event::RevocationCancelled { claim_hash, .. }
+                | Event::ClaimListed { claim_hash, .. }
+                | Event::DutchListingCreated { claim_hash, .. }
+                | Event::ListingCancelled { claim_hash, .. }
+                | Event::ClaimSold { claim_hash, .. }
+                | Event::RoyaltySet { claim_hash, .. }
+                | Event::RoyaltyPaid { claim_hash, .. }
+                | Event::AuctionStarted { claim_hash, .. }
+                | Event::BidPlaced { claim_hash, .. }
+                | Event::AuctionSettled { claim_hash, .. }
+                | Event::AuctionEndedWithoutBids { claim_hash, .. }
+                | Event::AuctionSettlementFailed { claim_hash, .. }
+                | Event::ClaimRevealed { claim_hash, .. } => vec![*claim_hash],
+                _ => Vec::new(),
+            }
+        }
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        ProofAlreadyExist,
+        ClaimLengthTooLarge,
+        ClaimNotExist,
+        NotClaimOwner,
+        /// 存证没有待接受的转移邀请
+        OfferNotExist,
+        /// 调用者不是转移邀请的接收方
+        NotOfferTarget,
+        /// 转移邀请已过期
+        OfferExpired,
+        /// 调用者没有被授权转移该存证
+        NotApproved,
+        /// pallet 已被暂停
+        PalletPaused,
+        /// 账户拥有的存证数量已达上限
+        TooManyClaims,
+        /// 所有者没有开启接受代付
+        SponsorshipNotAllowed,
+        /// 相同的承诺已经提交过
+        CommitmentAlreadyExist,
+        /// 调用者没有提交过与揭示内容对应的承诺
+        CommitmentNotExist,
+        /// 距离提交承诺的区块数还不足 `MinRevealDelay`
+        RevealTooEarly,
+        /// 存证已被冻结，不能转移
+        ClaimFrozen,
+        /// 存证没有被冻结
+        ClaimNotFrozen,
+        /// 存证已有尚未仲裁的争议
+        DisputeAlreadyExist,
+        /// 存证没有争议
+        DisputeNotExist,
+        /// 存证处于争议中，不能转移
+        ClaimDisputed,
+        /// 不能对自己的存证发起争议
+        CannotDisputeOwnClaim,
+        /// 调用者已经为该存证背书
+        AlreadyAttested,
+        /// 存证的背书数量已达上限
+        TooManyAttestations,
+        /// 调用者没有为该存证背书
+        AttestationNotExist,
+        /// 调用者已经是公证人
+        AlreadyNotary,
+        /// 账户不是公证人
+        NotNotary,
+        /// 存证已经有公证徽章
+        AlreadyNotarized,
+        /// 存证已经有新版本
+        AlreadySuperseded,
+        /// 父存证的子存证数量已达上限
+        TooManyChildren,
+        /// 集合不存在
+        CollectionNotExist,
+        /// 调用者不是集合的所有者
+        NotCollectionOwner,
+        /// 集合中的存证数量已达上限
+        CollectionFull,
+        /// 存证已经属于某个集合
+        ClaimAlreadyInCollection,
+        /// 集合编号已用尽
+        NoAvailableCollectionId,
+        /// 命名空间前缀不能为空
+        InvalidNamespace,
+        /// 命名空间已被他人注册且尚未失效
+        NamespaceTaken,
+        /// 命名空间不存在或已失效
+        NamespaceNotExist,
+        /// 调用者不是命名空间的所有者
+        NotNamespaceOwner,
+        /// 存证内容落在他人的命名空间内
+        NamespaceReserved,
+        /// 存证所有者没有经过认证的链上身份
+        IdentityRequired,
+        /// 存证哈希已被禁止登记
+        ClaimBanned,
+        /// 存证哈希没有被禁止
+        ClaimNotBanned,
+        /// 账户已被禁止创建和接收存证
+        AccountBanned,
+        /// 账户没有被禁止
+        AccountNotBanned,
+        /// 账户已经是共同所有者
+        AlreadyCoOwner,
+        /// 账户不是共同所有者
+        NotCoOwner,
+        /// 共同所有者数量已达上限
+        TooManyCoOwners,
+        /// 还有共同所有者没有批准该操作
+        CoOwnerApprovalMissing,
+        /// 门限必须大于 0 且不超过签名人数量，签名人不能重复
+        InvalidThreshold,
+        /// 调用者不是撤销门限的签名人
+        NotSignatory,
+        /// 签名人已经批准过撤销
+        AlreadyApprovedRevocation,
+        /// 撤销批准数量未达到门限
+        RevocationNotApproved,
+        /// 账户没有设置社交恢复方案
+        RecoveryNotConfigured,
+        /// 调用者不是监护人
+        NotGuardian,
+        /// 监护人已经发起过恢复
+        AlreadyVouched,
+        /// 发起恢复的新账户与进行中的恢复不一致
+        RecoveryTargetMismatch,
+        /// 没有进行中的恢复
+        RecoveryNotExist,
+        /// 恢复尚未达到门限或等待期未结束
+        RecoveryNotReady,
+        /// 等待期短于 `MinRecoveryDelay`
+        RecoveryDelayTooShort,
+        /// 有进行中的恢复时不能修改恢复方案
+        RecoveryInProgress,
+        /// 继承人不能是所有者本人
+        InvalidHeir,
+        /// 不活动期短于 `MinInactivityPeriod`
+        InactivityPeriodTooShort,
+        /// 存证没有设置继承人
+        NoInheritor,
+        /// 所有者仍在活动期内
+        OwnerStillActive,
+        /// 存证已经有预约的转移
+        TransferAlreadyScheduled,
+        /// 存证没有预约的转移
+        ScheduledTransferNotExist,
+        /// 预约的执行区块必须晚于当前区块
+        InvalidScheduleBlock,
+        /// 目标区块预约的转移数量已达上限
+        TooManyScheduledTransfers,
+        /// 配置了撤销等待期，需要使用 `begin_revoke` / `finalize_revoke`
+        RevocationDelayed,
+        /// 存证已经在等待撤销
+        RevocationAlreadyPending,
+        /// 存证没有在等待撤销
+        RevocationNotPending,
+        /// 撤销等待期尚未结束
+        RevocationDelayNotElapsed,
+        /// 存证正在挂单出售，不能直接转移
+        ClaimListed,
+        /// 存证没有挂单出售
+        NotListed,
+        /// 不能购买自己的存证
+        CannotBuyOwnClaim,
+        /// 当前售价高于买家愿意支付的最高价
+        PriceTooHigh,
+        /// 存证正在拍卖，不能转移或挂单
+        ClaimInAuction,
+        /// 存证没有进行中的拍卖
+        AuctionNotExist,
+        /// 拍卖已经结束
+        AuctionEnded,
+        /// 拍卖时长必须大于 0
+        InvalidAuctionDuration,
+        /// 结束区块的拍卖数量已达到 `MaxAuctionsPerBlock`
+        TooManyAuctions,
+        /// 出价低于最低出价或没有超过当前最高价
+        BidTooLow,
+        /// 卖家不能参与自己的拍卖
+        CannotBidOnOwnAuction,
+        /// 底价高于起始价
+        InvalidPriceRange,
+        /// 版税比例超过 `MaxRoyalty`
+        RoyaltyTooHigh,
+        /// 只有尚未转移过的存证的创建者可以设置版税
+        NotOriginalCreator,
+        /// 账户已在免费名单中
+        AlreadyFeeExempt,
+        /// 账户不在免费名单中
+        NotFeeExempt,
+        /// 最大存证长度必须在 1 到 `MaxClaimLength` 之间
+        InvalidMaxClaimLength,
+        /// 当前区块创建的存证数量已达到 `MaxClaimsPerBlock`
+        BlockLimitReached,
+        /// 报告的可用性与存证当前的状态相同
+        AvailabilityUnchanged,
+        /// 该存证根已经记录过锚定交易
+        AnchorAlreadyRecorded,
+        /// 锚定的区块范围无效
+        InvalidAnchorRange,
+        /// 该默克尔根已经登记
+        BatchRootAlreadyExists,
+        /// 默克尔根未登记
+        BatchRootNotExist,
+        /// 批量存证的数量必须大于 0
+        InvalidBatchSize,
+        /// 默克尔证明与登记的默克尔根不符
+        InvalidMerkleProof,
+        /// 作者对存证内容的签名无效，或签名与公钥的算法不一致
+        InvalidAuthorSignature,
+        /// 开启 CID 校验时，存证内容不是合法的 CIDv0 或 CIDv1
+        InvalidContentId,
+        /// 预映像 pallet 中没有该哈希对应的原文
+        PreimageNotExist,
+        /// 存证没有镜像为 NFT
+        NftNotMirrored,
+        /// 存证的所有者已经是 NFT 的持有者
+        NftOwnerUnchanged,
+        /// 平行链不在 `TrustedParas` 中，或 XCM 来源不是兄弟平行链
+        UntrustedPara,
+        /// XCM 消息无法发送到目标平行链
+        XcmSendFailed,
+        /// 价格必须大于 0
+        InvalidPrice,
+        /// 该存证已有尚未仲裁的举报
+        ReportAlreadyExist,
+        /// 举报不存在
+        ReportNotExist,
+        /// 存证被举报，仲裁前不能转移或撤销
+        ClaimUnderReport,
+        /// 不能举报自己的存证
+        CannotReportOwnClaim,
+        /// 存证的许可数量已达到上限
+        TooManyLicenses,
+        /// 许可不存在
+        LicenseNotExist,
+        /// 许可的到期区块必须晚于当前区块
+        InvalidLicenseExpiry,
+        /// 许可费和付款周期都必须大于 0
+        InvalidLicenseFee,
+        /// 许可没有付款计划
+        LicenseFeeNotExist,
+        /// 付款计划尚未被被许可人接受，只有被许可人可以结算第一期
+        LicenseFeeNotAccepted,
+        /// 只有被许可人或存证所有者可以结算许可费
+        NotLicenseParty,
+        /// 当前配置不支持托管出售
+        EscrowDisabled,
+        /// 存证的托管出售尚未放款，不能转移或撤销
+        ClaimInEscrow,
+        /// 存证没有托管出售
+        EscrowNotExist,
+        /// 只有托管出售的买家可以发起争议
+        NotEscrowBuyer,
+        /// 托管出售的争议期已结束
+        EscrowPeriodEnded,
+        /// 托管出售已有争议
+        EscrowAlreadyDisputed,
+        /// 同一区块放款的托管出售数量已达到上限
+        TooManyEscrows,
+        /// 存证在转移冷却期内，暂时不能再次转移
+        CooldownActive,
+        /// 打包出售至少需要一个存证
+        EmptyBundle,
+        /// 存证已在打包出售中，不能单独出售或转移
+        ClaimInBundle,
+        /// 打包出售不存在
+        BundleNotExist,
+        /// 调用者不是打包出售的卖家
+        NotBundleSeller,
+        /// 存证不在该打包出售中
+        ClaimNotInBundle,
+        /// 打包编号已用尽
+        NoAvailableBundleId,
+        /// 已经关注了该存证
+        AlreadyWatching,
+        /// 没有关注该存证
+        NotWatching,
+        /// 存证的关注者数量已达到上限
+        TooManyWatchers,
+        /// 注释内容不能为空
+        EmptyAnnotation,
+        /// 存证的注释数量已达到上限
+        TooManyAnnotations,
+        /// 只有所有者、操作员或背书人可以添加注释
+        NotAnnotator,
+        /// 同一区块过期的存证数量已达到上限
+        TooManyExpiring,
+        /// 归属期必须大于 0
+        InvalidVestingDuration,
+        /// 存证处于归属期内，新所有者暂时不能转移、出售或撤销
+        ClaimVesting,
+        /// 存证已作为抵押品锁定，不能转移、撤销或再次锁定
+        ClaimLocked,
+        /// 存证没有被锁定
+        NotLocked,
+        /// 只有登记的锁定方可以解锁存证
+        NotLocker,
+    }
+
+    /// 创世时预置的存证，便于测试网和分叉链带着已有的注册表启动
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// `(存证内容, 所有者)` 列表
+        pub claims: Vec<(Vec<u8>, T::AccountId)>,
+        /// 初始的 `CurrentMaxLength`，不设置时使用 `MaxClaimLength`
+        pub max_length: Option<u32>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            if let Some(max_length) = self.max_length {
+                assert!(max_length <= T::MaxClaimLength::get(), "genesis max_length exceeds MaxClaimLength");
+                CurrentMaxLength::<T>::put(max_length);
+            }
+
+            let expires_at = T::ClaimLifetime::get();
+            for (claim, owner) in &self.claims {
+                assert!(
+                    claim.len() <= T::MaxClaimLength::get() as usize,
+                    "genesis claim is longer than MaxClaimLength"
+                );
+                let claim_hash = Pallet::<T>::claim_hash(claim);
+                assert!(!Proofs::<T>::contains_key(&claim_hash), "duplicate genesis claim");
+
+                // 创世存证不保留押金，也不受每个账户的数量上限限制
+                Proofs::<T>::insert(&claim_hash, ClaimDetails::new(owner.clone(), Zero::zero(), expires_at, Zero::zero()));
+                ExpiringAt::<T>::try_mutate(expires_at, |claims| claims.try_push(claim_hash))
+                    .expect("genesis claims exceed MaxExpiringPerBlock");
+                OwnerClaims::<T>::insert(owner, &claim_hash, ());
+                ClaimCountOf::<T>::mutate(owner, |count| *count = count.saturating_add(1));
+                TotalClaims::<T>::mutate(|total| *total = total.saturating_add(1));
+            }
+        }
+    }
+
+    #[pallet::hooks]
+    // 为上面定义的Pallet结构体实现钩子函数：Hooks<BlockNumberFor<T>>
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// 每个区块开始时按过期索引清理到期的存证，防止状态无限增长
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            ClaimsThisBlock::<T>::kill();
+            let executed = Self::execute_scheduled_transfers(now);
+            let settled = Self::settle_auctions(now);
+            let released = Self::release_escrows(now);
+            let confirmed = Self::confirm_provisional_claims(now);
+            let license_settled = Self::settle_license_fees(now);
+            let (checked, removed) = Self::expire_indexed_claims(now, T::MaxExpirationsPerBlock::get());
+            let deposit_updated = Self::update_deposit_base(now);
+            let warned = Self::expiry_warnings_due(now);
+
+            // 清除 ClaimsThisBlock，以及 on_finalize 中统计的读写
+            T::DbWeight::get()
+                .reads_writes(2, 4)
+                .saturating_add(Self::expiry_warnings_weight(warned))
+                .saturating_add(Self::scheduled_transfers_weight(executed))
+                .saturating_add(Self::auctions_weight(settled))
+                .saturating_add(Self::escrows_weight(released))
+                .saturating_add(Self::confirmations_weight(confirmed))
+                .saturating_add(Self::license_fees_weight(license_settled))
+                .saturating_add(Self::expiry_index_weight(checked, removed))
+                .saturating_add(deposit_updated)
+        }
+
+        /// 利用区块剩余的权重遍历注册表，清理不在过期索引中的过期存证，游标保证清理进度跨区块延续
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            // 游标的固定开销之外，按最坏情况（每个检查的存证都需要清理）估算能处理的数量
+            let Some(budget) = remaining_weight.checked_sub(&Self::sweep_weight(0, 0)) else {
+                return Weight::zero();
+            };
+            let limit = budget
+                .checked_div_per_component(&Self::sweep_weight(1, 1).saturating_sub(Self::sweep_weight(0, 0)))
+                .map_or(u32::MAX, |limit| limit.min(u32::MAX as u64) as u32);
+
+            let (checked, removed) = Self::sweep_expired_claims(now, limit);
+
+            Self::sweep_weight(checked, removed)
+        }
+
+        /// 保存本区块的统计快照并计入累计统计，并为即将过期的存证发出提醒
+        fn on_finalize(now: BlockNumberFor<T>) {
+            let stats = BlockStats::<T>::take();
+            CumulativeStats::<T>::mutate(|total| total.accrue(&stats));
+            LastBlockStats::<T>::put(stats);
+            Self::warn_expiring_claims(now);
+        }
+
+        /// 把链下本地存储中等待提交的存证以无签名交易提交上链
+        fn offchain_worker(now: BlockNumberFor<T>) {
+            Self::submit_pending_claims(now);
+            Self::check_availability(now);
+            Self::anchor_recent_claims(now);
+            Self::report_native_price(now);
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_now: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
+    // 管理员调用（强制撤销/转移、暂停、仲裁、罚没、禁止名单和免费名单）使用 Operational 类别，
+    // 区块被普通交易占满时仍可打包；授权检查通过后不收交易费，未授权的调用照常付费
+    #[pallet::call]
+    impl <T: Config> Pallet<T> {
+        /// 使用存证的原始内容创建存证，链上只保存内容的哈希
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_claim())]
+        pub fn create_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let claim_hash = Self::claim_hash(&claim);
+            Self::ensure_claim_available(&claim_hash)?;
+            Self::ensure_claim_length(&claim)?;
+            Self::ensure_content_id(&claim)?;
+            Self::ensure_namespace_allowed(&sender, &claim)?;
+
+            Self::do_create_claim(sender.clone(), sender.clone(), claim_hash, claim.len() as u32)?;
+
+            // 免费名单中的账户不支付交易费
+            let pays_fee = if FeeExempt::<T>::contains_key(&sender) { Pays::No } else { Pays::Yes };
+            Ok(pays_fee.into())
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::revoke_claim())]
+        pub fn revoke_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            ensure!(T::RevocationDelay::get().is_zero(), Error::<T>::RevocationDelayed);
+            let details = Self::live_claim(&claim_hash)?;
+
+            // 所有者本人或其操作员可以撤销
+            ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+            Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Revoke)?;
+            Self::ensure_revocation_approved(&claim_hash)?;
+            ensure!(!Reports::<T>::contains_key(&claim_hash), Error::<T>::ClaimUnderReport);
+            Self::ensure_claim_movable(&claim_hash)?;
+
+            let removed = Self::do_revoke(claim_hash, details)?;
+            Ok(Some(Self::revoke_weight(T::WeightInfo::revoke_claim(), 1, removed)).into())
+        }
+
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::transfer_claim())]
+        pub fn transfer_claim(
+            origin: OriginFor<T>,
+            target: T::AccountId,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+            // 获取存证，如果存证不存在或已过期则返回错误
+            let details = Self::live_claim(&claim_hash)?;
+
+            // 确保调用者是存证的所有者或其操作员
+            ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+            Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Transfer(target.clone()))?;
+
+            let co_owners = Self::co_owner_count(&claim_hash);
+            Self::do_transfer(claim_hash, details, target)?;
+
+            Ok(Some(Self::transfer_weight(T::WeightInfo::transfer_claim(), 1, co_owners)).into())
+        }
+
+        /// 使用链下预先计算好的哈希创建存证，适用于无法整体上链的大文件
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::create_claim_by_hash())]
+        pub fn create_claim_by_hash(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+            Self::ensure_claim_available(&claim_hash)?;
+
+            // 无法得知原文长度，按最大长度收取押金
+            Self::do_create_claim(sender.clone(), sender, claim_hash, T::MaxClaimLength::get())?;
+            Ok(().into())
+        }
+
+        /// 设置存证的标题和外部资源地址，传入 `None` 表示清除
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_claim_metadata())]
+        pub fn set_claim_metadata(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            title: Option<ClaimTitleOf<T>>,
+            uri: Option<ClaimUriOf<T>>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let mut details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+
+            // 更换地址后之前的不可访问标记不再适用，等待重新检查
+            if details.uri != uri && details.status == ClaimStatus::ContentUnavailable {
+                details.status = ClaimStatus::Active;
+            }
+            details.title = title;
+            details.uri = uri;
+            Proofs::<T>::insert(&claim_hash, details);
+
+            Self::deposit_event(Event::ClaimMetadataSet { who: sender, claim_hash });
+
+            Ok(())
+        }
+
+        /// 将存证的过期时间延长一个 `ClaimLifetime`，保留原来的创建区块号
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::renew_claim())]
+        pub fn renew_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let mut details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+
+            let fee = T::RenewalFee::get();
+            if !fee.is_zero() {
+                // 续期费用不归任何账户，直接销毁
+                let _ = T::Currency::withdraw(
+                    &sender,
+                    fee,
+                    WithdrawReasons::FEE,
+                    ExistenceRequirement::KeepAlive,
+                )?;
+            }
+
+            Self::unindex_expiry(&claim_hash, details.expires_at);
+            details.expires_at = details.expires_at.saturating_add(T::ClaimLifetime::get());
+            let expires_at = details.expires_at;
+            Self::index_expiry(&claim_hash, expires_at)?;
+            Proofs::<T>::insert(&claim_hash, details);
+
+            Self::deposit_event(Event::ClaimRenewed { who: sender, claim_hash, expires_at });
+
+            Ok(())
+        }
+
+        /// 向 `target` 发出转移邀请，`target` 调用 `accept_claim` 后所有权才会转移
+        ///
+        /// 新的邀请会覆盖该存证之前的邀请。
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::offer_claim())]
+        pub fn offer_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            target: T::AccountId
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+
+            let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(T::OfferLifetime::get());
+            PendingTransfers::<T>::insert(
+                &claim_hash,
+                PendingTransfer { from: sender.clone(), to: target.clone(), expires_at },
+            );
+
+            Self::deposit_event(Event::ClaimOffered { who: sender, target, claim_hash });
+
+            Ok(())
+        }
+
+        /// 接收方接受转移邀请，存证及押金转给调用者
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::accept_claim())]
+        pub fn accept_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let offer = PendingTransfers::<T>::get(&claim_hash).ok_or(Error::<T>::OfferNotExist)?;
+            ensure!(offer.to == sender, Error::<T>::NotOfferTarget);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < offer.expires_at,
+                Error::<T>::OfferExpired
+            );
+
+            // 发出邀请后所有权发生过变化的邀请视为无效
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == offer.from, Error::<T>::OfferNotExist);
+            Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Transfer(sender.clone()))?;
+
+            Self::do_transfer(claim_hash, details, sender)
+        }
+
+        /// 所有者取消尚未被接受的转移邀请，也可用于清理已过期的邀请
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::cancel_offer())]
+        pub fn cancel_offer(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let offer = PendingTransfers::<T>::get(&claim_hash).ok_or(Error::<T>::OfferNotExist)?;
+            ensure!(offer.from == sender, Error::<T>::NotClaimOwner);
+
+            PendingTransfers::<T>::remove(&claim_hash);
+
+            Self::deposit_event(Event::ClaimOfferCancelled { who: sender, claim_hash });
+
+            Ok(())
+        }
+
+        /// 授权 `delegate` 代为转移存证，每个存证同时只有一个被授权账户，新的授权覆盖旧的
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::approve_transfer())]
+        pub fn approve_transfer(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            delegate: T::AccountId
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+
+            Approvals::<T>::insert(&claim_hash, &delegate);
+
+            Self::deposit_event(Event::TransferApproved { who: sender, delegate, claim_hash });
+
+            Ok(())
+        }
+
+        /// 所有者取消存证的转移授权
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::cancel_approval())]
+        pub fn cancel_approval(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(Approvals::<T>::contains_key(&claim_hash), Error::<T>::NotApproved);
+
+            Approvals::<T>::remove(&claim_hash);
+
+            Self::deposit_event(Event::ApprovalCancelled { who: sender, claim_hash });
+
+            Ok(())
+        }
+
+        /// 被授权的账户代替所有者把存证转给 `target`，转移后授权失效
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::transfer_claim_from())]
+        pub fn transfer_claim_from(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            target: T::AccountId
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(Approvals::<T>::get(&claim_hash) == Some(sender), Error::<T>::NotApproved);
+            Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Transfer(target.clone()))?;
+
+            Self::do_transfer(claim_hash, details, target)
+        }
+
+        /// 设置或取消 `operator` 为调用者全部存证的操作员
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::set_operator())]
+        pub fn set_operator(
+            origin: OriginFor<T>,
+            operator: T::AccountId,
+            approved: bool
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            if approved {
+                Operators::<T>::insert(&sender, &operator, ());
+            } else {
+                Operators::<T>::remove(&sender, &operator);
+            }
+
+            Self::deposit_event(Event::OperatorSet { who: sender, operator, approved });
+
+            Ok(())
+        }
+
+        /// 管理员强制撤销存证，用于删除抄袭或违法的存证，押金退还给所有者
+        #[pallet::call_index(13)]
+        #[pallet::weight((T::WeightInfo::force_revoke_claim(), DispatchClass::Operational))]
+        pub fn force_revoke_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            let removed = Self::do_revoke(claim_hash, details)?;
+            Ok(PostDispatchInfo {
+                actual_weight: Some(Self::revoke_weight(T::WeightInfo::force_revoke_claim(), 1, removed)),
+                pays_fee: Pays::No,
+            })
+        }
+
+        /// 管理员强制把存证转给 `target`，押金随存证一起转移
+        ///
+        /// 托管中或作为抵押品锁定的存证仍然不能转移，需要先仲裁托管或由锁定方解锁。
+        #[pallet::call_index(14)]
+        #[pallet::weight((T::WeightInfo::force_transfer_claim(), DispatchClass::Operational))]
+        pub fn force_transfer_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            target: T::AccountId
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            // 强制转移会同时解除冻结、出售挂单、拍卖和打包出售
+            let mut details = Self::live_claim(&claim_hash)?;
+            details.status = ClaimStatus::Active;
+            Self::withdraw_from_sale(&claim_hash);
+            Self::do_forced_transfer(claim_hash, details, target)?;
+
+            Ok(Pays::No.into())
+        }
+
+        /// 暂停 pallet，用于事故处理或迁移期间冻结存证注册表
+        #[pallet::call_index(15)]
+        #[pallet::weight((T::WeightInfo::pause(), DispatchClass::Operational))]
+        pub fn pause(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            Paused::<T>::put(true);
+
+            Self::deposit_event(Event::PalletPaused);
+
+            Ok(Pays::No.into())
+        }
+
+        /// 恢复 pallet 的正常运行
+        #[pallet::call_index(16)]
+        #[pallet::weight((T::WeightInfo::unpause(), DispatchClass::Operational))]
+        pub fn unpause(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            Paused::<T>::kill();
+
+            Self::deposit_event(Event::PalletUnpaused);
+
+            Ok(Pays::No.into())
+        }
+
+        /// 批量创建存证，任意一个失败则整批回滚，每个存证各自触发 `ClaimCreated` 事件
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::create_claims(claims.len() as u32))]
+        pub fn create_claims(
+            origin: OriginFor<T>,
+            claims: BoundedVec<BoundedVec<u8, T::MaxClaimLength>, T::MaxBatchSize>
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let count = claims.len() as u32;
+            let mut overwritten = 0u32;
+            for claim in claims {
+                Self::ensure_claim_length(&claim)?;
+                Self::ensure_content_id(&claim)?;
+                Self::ensure_namespace_allowed(&sender, &claim)?;
+                let claim_hash = Self::claim_hash(&claim);
+                // 未过期的存证会使创建失败，创建成功时已有的记录一定是被清理的过期存证
+                if Proofs::<T>::contains_key(&claim_hash) {
+                    overwritten += 1;
+                }
+                Self::do_create_claim(sender.clone(), sender.clone(), claim_hash, claim.len() as u32)?;
+            }
+
+            Ok(Some(Self::create_weight(T::WeightInfo::create_claims(count), count, overwritten)).into())
+        }
+
+        /// 批量撤销存证，权限规则与 `revoke_claim` 相同
+        ///
+        /// 调用本身是事务性的：任意一个存证撤销失败时整批回滚。
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::revoke_claims(claim_hashes.len() as u32))]
+        pub fn revoke_claims(
+            origin: OriginFor<T>,
+            claim_hashes: BoundedVec<ClaimHashOf<T>, T::MaxBatchSize>
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            ensure!(T::RevocationDelay::get().is_zero(), Error::<T>::RevocationDelayed);
+            let count = claim_hashes.len() as u32;
+            let mut removed = 0u32;
+            for claim_hash in claim_hashes {
+                let details = Self::live_claim(&claim_hash)?;
+                ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+                Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Revoke)?;
+                Self::ensure_revocation_approved(&claim_hash)?;
+                ensure!(!Reports::<T>::contains_key(&claim_hash), Error::<T>::ClaimUnderReport);
+                Self::ensure_claim_movable(&claim_hash)?;
+                removed.saturating_accrue(Self::do_revoke(claim_hash, details)?);
+            }
+
+            Ok(Some(Self::revoke_weight(T::WeightInfo::revoke_claims(count), count, removed)).into())
+        }
+
+        /// 把一批存证全部转给 `target`，权限规则与 `transfer_claim` 相同，任意一个失败时整批回滚
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::transfer_claims(claim_hashes.len() as u32))]
+        pub fn transfer_claims(
+            origin: OriginFor<T>,
+            target: T::AccountId,
+            claim_hashes: BoundedVec<ClaimHashOf<T>, T::MaxBatchSize>
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let count = claim_hashes.len() as u32;
+            let mut co_owners = 0u32;
+            for claim_hash in claim_hashes {
+                let details = Self::live_claim(&claim_hash)?;
+                ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+                Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Transfer(target.clone()))?;
+                co_owners.saturating_accrue(Self::co_owner_count(&claim_hash));
+                Self::do_transfer(claim_hash, details, target.clone())?;
+            }
+
+            Ok(Some(Self::transfer_weight(T::WeightInfo::transfer_claims(count), count, co_owners)).into())
+        }
+
+        /// 设置调用者是否接受他人代付创建存证
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::set_allow_sponsorship())]
+        pub fn set_allow_sponsorship(origin: OriginFor<T>, allowed: bool) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            if allowed {
+                AllowSponsorship::<T>::insert(&sender, true);
+            } else {
+                AllowSponsorship::<T>::remove(&sender);
+            }
+
+            Self::deposit_event(Event::SponsorshipAllowed { who: sender, allowed });
+
+            Ok(())
+        }
+
+        /// 为 `owner` 代付创建存证：调用者支付交易费和押金，存证登记在 `owner` 名下
+        ///
+        /// `owner` 需要先通过 `set_allow_sponsorship` 开启接受代付。押金在存证删除时退还给调用者。
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::create_claim_for())]
+        pub fn create_claim_for(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            ensure!(AllowSponsorship::<T>::get(&owner), Error::<T>::SponsorshipNotAllowed);
+            Self::ensure_claim_length(&claim)?;
+            Self::ensure_content_id(&claim)?;
+            Self::ensure_namespace_allowed(&owner, &claim)?;
+
+            let claim_hash = Self::claim_hash(&claim);
+            Self::do_create_claim(owner.clone(), sender.clone(), claim_hash, claim.len() as u32)?;
+
+            Self::deposit_event(Event::ClaimSponsored { who: sender, owner, claim_hash });
+
+            Ok(())
+        }
+
+        /// 提交存证承诺 `hash(存证内容 ++ 盐)`，之后再用 `reveal_claim` 揭示
+        ///
+        /// 直接调用 `create_claim` 会在交易池中暴露存证内容，可能被出块者抢先注册；
+        /// 先提交承诺可以以承诺所在的区块确定优先权。提交时保留本周期的基础押金，揭示时退还同样的数额。
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::commit_claim())]
+        pub fn commit_claim(
+            origin: OriginFor<T>,
+            commitment: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            ensure!(!Commitments::<T>::contains_key(&sender, &commitment), Error::<T>::CommitmentAlreadyExist);
+
+            let deposit = Self::deposit_for(0);
+            T::Currency::reserve(&sender, deposit)?;
+            Commitments::<T>::insert(
+                &sender,
+                &commitment,
+                ClaimCommitment { committed_at: frame_system::Pallet::<T>::block_number(), deposit },
+            );
+
+            Self::deposit_event(Event::ClaimCommitted { who: sender, commitment });
+
+            Ok(())
+        }
+
+        /// 揭示之前提交的承诺并创建存证，存证的创建区块号为承诺所在的区块
+        ///
+        /// 如果同一内容已被他人在承诺之后注册（例如抢跑），该存证被撤销并改由调用者拥有；
+        /// 该存证处于争议、举报、托管、归属期或被抵押时揭示失败，承诺保留到这些流程结束后再揭示。
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::reveal_claim())]
+        pub fn reveal_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            salt: [u8; 32]
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+            Self::ensure_claim_length(&claim)?;
+            Self::ensure_content_id(&claim)?;
+            Self::ensure_namespace_allowed(&sender, &claim)?;
+
+            let commitment = Self::commitment_of(&claim, &salt);
+            let ClaimCommitment { committed_at, deposit } =
+                Commitments::<T>::take(&sender, &commitment).ok_or(Error::<T>::CommitmentNotExist)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= committed_at.saturating_add(T::MinRevealDelay::get()),
+                Error::<T>::RevealTooEarly
+            );
+            T::Currency::unreserve(&sender, deposit);
+
+            let claim_hash = Self::claim_hash(&claim);
+            // 承诺之后才注册的存证让位给承诺者
+            if let Ok(existing) = Self::live_claim(&claim_hash) {
+                if existing.created_at > committed_at {
+                    ensure!(!Disputes::<T>::contains_key(&claim_hash), Error::<T>::ClaimDisputed);
+                    ensure!(!Reports::<T>::contains_key(&claim_hash), Error::<T>::ClaimUnderReport);
+                    Self::ensure_claim_movable(&claim_hash)?;
+                    Self::do_revoke(claim_hash, existing)?;
+                }
+            }
+
+            Self::do_create_claim(sender.clone(), sender.clone(), claim_hash, claim.len() as u32)?;
+            Proofs::<T>::mutate(&claim_hash, |details| {
+                if let Some(details) = details {
+                    details.created_at = committed_at;
+                }
+            });
+
+            Self::deposit_event(Event::ClaimRevealed { who: sender, claim_hash, committed_at });
+
+            Ok(())
+        }
+
+        /// 所有者冻结存证，冻结期间不能转移，但仍可撤销
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::freeze_claim())]
+        pub fn freeze_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let mut details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(details.status != ClaimStatus::Frozen, Error::<T>::ClaimFrozen);
+
+            details.status = ClaimStatus::Frozen;
+            Proofs::<T>::insert(&claim_hash, details);
+
+            Self::deposit_event(Event::ClaimFrozen { who: sender, claim_hash });
+
+            Ok(())
+        }
+
+        /// 所有者解冻存证
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::unfreeze_claim())]
+        pub fn unfreeze_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let mut details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(details.status == ClaimStatus::Frozen, Error::<T>::ClaimNotFrozen);
+
+            details.status = ClaimStatus::Active;
+            Proofs::<T>::insert(&claim_hash, details);
+
+            Self::deposit_event(Event::ClaimThawed { who: sender, claim_hash });
+
+            Ok(())
+        }
+
+        /// 对他人的存证发起争议，保留 `DisputeBond` 作为保证金，争议期间存证不能转移
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::raise_dispute())]
+        pub fn raise_dispute(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            evidence: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner != sender, Error::<T>::CannotDisputeOwnClaim);
+            ensure!(!Disputes::<T>::contains_key(&claim_hash), Error::<T>::DisputeAlreadyExist);
+
+            let bond = T::DisputeBond::get();
+            T::Currency::reserve(&sender, bond)?;
+            Disputes::<T>::insert(
+                &claim_hash,
+                Dispute {
+                    challenger: sender.clone(),
+                    evidence,
+                    bond,
+                    raised_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::DisputeRaised { who: sender, claim_hash, evidence });
+
+            Ok(())
+        }
+
+        /// 仲裁争议：驳回时没收发起方的保证金（销毁），支持发起方时退还保证金并转移或撤销存证
+        ///
+        /// 存证已过期但尚未清理时，支持发起方只退还保证金，存证留给过期清理删除。
+        /// 转给发起方时与 `force_transfer_claim` 相同，托管中或作为抵押品锁定的存证不能转移。
+        #[pallet::call_index(27)]
+        #[pallet::weight((T::WeightInfo::resolve_dispute(), DispatchClass::Operational))]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            resolution: DisputeResolution
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            T::ArbitrationOrigin::ensure_origin(origin)?;
+
+            let dispute = Disputes::<T>::take(&claim_hash).ok_or(Error::<T>::DisputeNotExist)?;
+            match (resolution, Self::live_claim(&claim_hash).ok()) {
+                (DisputeResolution::Reject, _) => {
+                    let _ = T::Currency::slash_reserved(&dispute.challenger, dispute.bond);
+                },
+                (_, None) => {
+                    T::Currency::unreserve(&dispute.challenger, dispute.bond);
+                },
+                (DisputeResolution::TransferToChallenger, Some(mut details)) => {
+                    T::Currency::unreserve(&dispute.challenger, dispute.bond);
+                    details.status = ClaimStatus::Active;
+                    Self::withdraw_from_sale(&claim_hash);
+                    Self::do_forced_transfer(claim_hash, details, dispute.challenger)?;
+                },
+                (DisputeResolution::Revoke, Some(details)) => {
+                    T::Currency::unreserve(&dispute.challenger, dispute.bond);
+                    Self::do_revoke(claim_hash, details)?;
+                },
+            }
+
+            // 挑战期已结束的存证在争议驳回或转给发起方后确认
+            Self::confirm_if_due(&claim_hash);
+
+            Self::deposit_event(Event::DisputeResolved { claim_hash, resolution });
+
+            Ok(Pays::No.into())
+        }
+
+        /// 为存证背书，审计方或合著者可以借此在链上认可一份存证
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::attest_claim())]
+        pub fn attest_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(!Attestations::<T>::contains_key(&claim_hash, &sender), Error::<T>::AlreadyAttested);
+            AttestationCount::<T>::try_mutate(&claim_hash, |count| -> DispatchResult {
+                ensure!(*count < T::MaxAttestations::get(), Error::<T>::TooManyAttestations);
+                *count += 1;
+                Ok(())
+            })?;
+            Attestations::<T>::insert(&claim_hash, &sender, frame_system::Pallet::<T>::block_number());
+            // 为自己的存证背书不计入声誉
+            if details.owner != sender {
+                Self::adjust_reputation(&details.owner, 1);
+            }
+
+            Self::deposit_event(Event::ClaimAttested { who: sender, claim_hash });
+
+            Ok(())
+        }
+
+        /// 撤回自己对存证的背书
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::revoke_attestation())]
+        pub fn revoke_attestation(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Attestations::<T>::contains_key(&claim_hash, &sender), Error::<T>::AttestationNotExist);
+            Attestations::<T>::remove(&claim_hash, &sender);
+            AttestationCount::<T>::mutate(&claim_hash, |count| *count = count.saturating_sub(1));
+            if let Some(details) = Proofs::<T>::get(&claim_hash).filter(|details| details.owner != sender) {
+                Self::adjust_reputation(&details.owner, -1);
+            }
+
+            Self::deposit_event(Event::AttestationRevoked { who: sender, claim_hash });
+
+            Ok(())
+        }
+
+        /// 保留 `NotaryStake` 作为质押，注册为公证人
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::register_notary())]
+        pub fn register_notary(origin: OriginFor<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            ensure!(!Notaries::<T>::contains_key(&sender), Error::<T>::AlreadyNotary);
+
+            let stake = T::NotaryStake::get();
+            T::Currency::reserve(&sender, stake)?;
+            Notaries::<T>::insert(&sender, stake);
+
+            Self::deposit_event(Event::NotaryRegistered { who: sender });
+
+            Ok(())
+        }
+
+        /// 公证人注销并取回质押，已添加的公证徽章保留
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::unregister_notary())]
+        pub fn unregister_notary(origin: OriginFor<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let stake = Notaries::<T>::take(&sender).ok_or(Error::<T>::NotNotary)?;
+            T::Currency::unreserve(&sender, stake);
+
+            Self::deposit_event(Event::NotaryUnregistered { who: sender });
+
+            Ok(())
+        }
+
+        /// 公证人为存证添加公证徽章
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::notarize_claim())]
+        pub fn notarize_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Notaries::<T>::contains_key(&sender), Error::<T>::NotNotary);
+            Self::live_claim(&claim_hash)?;
+            ensure!(!Notarizations::<T>::contains_key(&claim_hash), Error::<T>::AlreadyNotarized);
+
+            Notarizations::<T>::insert(&claim_hash, &sender);
+
+            Self::deposit_event(Event::ClaimNotarized { who: sender, claim_hash });
+
+            Ok(())
+        }
+
+        /// 治理罚没公证人的质押并取消其公证人资格，同时移除其为 `claim_hash` 添加的错误徽章
+        #[pallet::call_index(33)]
+        #[pallet::weight((T::WeightInfo::slash_notary(), DispatchClass::Operational))]
+        pub fn slash_notary(
+            origin: OriginFor<T>,
+            notary: T::AccountId,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let stake = Notaries::<T>::take(&notary).ok_or(Error::<T>::NotNotary)?;
+            let (_, not_slashed) = T::Currency::slash_reserved(&notary, stake);
+            if Notarizations::<T>::get(&claim_hash).as_ref() == Some(&notary) {
+                Notarizations::<T>::remove(&claim_hash);
+            }
+
+            Self::deposit_event(Event::NotarySlashed { who: notary, amount: stake.saturating_sub(not_slashed) });
+
+            Ok(Pays::No.into())
+        }
+
+        /// 登记 `old_claim_hash` 的修订版本 `new_claim`，并记录两者之间的版本链
+        ///
+        /// 新版本按 `create_claim` 的规则创建并保留押金，旧版本保持不变。每个版本只能被取代一次。
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::supersede_claim())]
+        pub fn supersede_claim(
+            origin: OriginFor<T>,
+            old_claim_hash: ClaimHashOf<T>,
+            new_claim: BoundedVec<u8, T::MaxClaimLength>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&old_claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(!SupersededBy::<T>::contains_key(&old_claim_hash), Error::<T>::AlreadySuperseded);
+            Self::ensure_claim_length(&new_claim)?;
+            Self::ensure_content_id(&new_claim)?;
+            Self::ensure_namespace_allowed(&sender, &new_claim)?;
+
+            let new_claim_hash = Self::claim_hash(&new_claim);
+            Self::do_create_claim(sender.clone(), sender.clone(), new_claim_hash, new_claim.len() as u32)?;
+            Supersedes::<T>::insert(&new_claim_hash, &old_claim_hash);
+            SupersededBy::<T>::insert(&old_claim_hash, &new_claim_hash);
+
+            Self::deposit_event(Event::ClaimSuperseded {
+                who: sender,
+                previous: old_claim_hash,
+                claim_hash: new_claim_hash,
+            });
+
+            Ok(())
+        }
+
+        /// 在 `parent_hash` 下创建子存证，只有父存证的所有者可以挂载
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::create_child_claim())]
+        pub fn create_child_claim(
+            origin: OriginFor<T>,
+            parent_hash: ClaimHashOf<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let parent = Self::live_claim(&parent_hash)?;
+            ensure!(parent.owner == sender, Error::<T>::NotClaimOwner);
+            Self::ensure_claim_length(&claim)?;
+            Self::ensure_content_id(&claim)?;
+            Self::ensure_namespace_allowed(&sender, &claim)?;
+
+            let claim_hash = Self::claim_hash(&claim);
+            Self::do_create_claim(sender.clone(), sender.clone(), claim_hash, claim.len() as u32)?;
+            Children::<T>::try_mutate(&parent_hash, |children| children.try_push(claim_hash))
+                .map_err(|_| Error::<T>::TooManyChildren)?;
+            ParentOf::<T>::insert(&claim_hash, &parent_hash);
+
+            Self::deposit_event(Event::ChildClaimCreated { who: sender, parent: parent_hash, claim_hash });
+
+            Ok(())
+        }
+
+        /// 创建一个空的存证集合，调用者成为集合的所有者
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::create_collection())]
+        pub fn create_collection(origin: OriginFor<T>, metadata: CollectionMetadataOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let collection_id = NextCollectionId::<T>::get();
+            let next_id = collection_id.checked_add(1).ok_or(Error::<T>::NoAvailableCollectionId)?;
+            NextCollectionId::<T>::put(next_id);
+            Collections::<T>::insert(collection_id, Collection { owner: sender.clone(), metadata });
+
+            Self::deposit_event(Event::CollectionCreated { who: sender, collection_id });
+
+            Ok(())
+        }
+
+        /// 把调用者拥有的存证加入调用者拥有的集合
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::add_claim_to_collection())]
+        pub fn add_claim_to_collection(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            claim_hash: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let collection = Collections::<T>::get(collection_id).ok_or(Error::<T>::CollectionNotExist)?;
+            ensure!(collection.owner == sender, Error::<T>::NotCollectionOwner);
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(!ClaimCollection::<T>::contains_key(&claim_hash), Error::<T>::ClaimAlreadyInCollection);
+
+            CollectionClaims::<T>::try_mutate(collection_id, |claims| claims.try_push(claim_hash))
+                .map_err(|_| Error::<T>::CollectionFull)?;
+            ClaimCollection::<T>::insert(&claim_hash, collection_id);
+
+            Self::deposit_event(Event::ClaimAddedToCollection { collection_id, claim_hash });
+
+            Ok(())
+        }
+
+        /// 把集合连同其中全部未过期的存证转给 `target`，任意一个存证无法转移时整体回滚
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::transfer_collection(T::MaxCollectionSize::get()))]
+        pub fn transfer_collection(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            target: T::AccountId
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let mut collection = Collections::<T>::get(collection_id).ok_or(Error::<T>::CollectionNotExist)?;
+            ensure!(collection.owner == sender, Error::<T>::NotCollectionOwner);
+
+            // 先更新集合所有者，`do_transfer` 据此保留存证的集合成员关系
+            collection.owner = target.clone();
+            Collections::<T>::insert(collection_id, collection);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            for claim_hash in CollectionClaims::<T>::get(collection_id) {
+                // 已过期的存证留给过期清理处理，清理时会从集合中移出
+                if let Some(details) = Proofs::<T>::get(&claim_hash).filter(|details| !details.is_expired(now)) {
+                    Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Transfer(target.clone()))?;
+                    Self::do_transfer(claim_hash, details, target.clone())?;
+                }
+            }
+
+            Self::deposit_event(Event::CollectionTransferred { who: sender, target, collection_id });
+
+            Ok(())
+        }
+
+        /// 注册命名空间 `prefix`，之后以该前缀开头的存证内容只能由调用者登记
+        ///
+        /// 注册时保留 `NamespaceDeposit`。所有者在失效前再次注册即为续期；已失效的命名空间
+        /// 可以被任何人重新注册，原所有者的押金随之退还。已有的存证不受影响。
+        #[pallet::call_index(39)]
+        #[pallet::weight(T::WeightInfo::register_namespace())]
+        pub fn register_namespace(origin: OriginFor<T>, prefix: NamespaceOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            ensure!(!prefix.is_empty(), Error::<T>::InvalidNamespace);
+            // 不能在他人的命名空间之内再注册更长的前缀
+            Self::ensure_namespace_allowed(&sender, &prefix)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let expires_at = now.saturating_add(T::NamespaceLifetime::get());
+            let deposit = match Namespaces::<T>::get(&prefix) {
+                Some(namespace) if namespace.owner == sender => namespace.deposit,
+                Some(namespace) => {
+                    ensure!(namespace.is_expired(now), Error::<T>::NamespaceTaken);
+                    T::Currency::unreserve(&namespace.owner, namespace.deposit);
+                    let deposit = T::NamespaceDeposit::get();
+                    T::Currency::reserve(&sender, deposit)?;
+                    deposit
+                },
+                None => {
+                    let deposit = T::NamespaceDeposit::get();
+                    T::Currency::reserve(&sender, deposit)?;
+                    deposit
+                },
+            };
+            Namespaces::<T>::insert(&prefix, Namespace { owner: sender.clone(), expires_at, deposit });
+
+            Self::deposit_event(Event::NamespaceRegistered { who: sender, prefix, expires_at });
+
+            Ok(())
+        }
+
+        /// 把未失效的命名空间转给 `target`，押金随之转移，失效区块号保持不变
+        #[pallet::call_index(40)]
+        #[pallet::weight(T::WeightInfo::transfer_namespace())]
+        pub fn transfer_namespace(
+            origin: OriginFor<T>,
+            prefix: NamespaceOf<T>,
+            target: T::AccountId
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let mut namespace = Namespaces::<T>::get(&prefix)
+                .filter(|namespace| !namespace.is_expired(frame_system::Pallet::<T>::block_number()))
+                .ok_or(Error::<T>::NamespaceNotExist)?;
+            ensure!(namespace.owner == sender, Error::<T>::NotNamespaceOwner);
+
+            T::Currency::repatriate_reserved(&sender, &target, namespace.deposit, BalanceStatus::Reserved)?;
+            namespace.owner = target.clone();
+            Namespaces::<T>::insert(&prefix, namespace);
+
+            Self::deposit_event(Event::NamespaceTransferred { who: sender, target, prefix });
+
+            Ok(())
+        }
+
+        /// 管理员禁止登记 `claim_hash`，之后任何途径都不能再创建该存证
+        ///
+        /// 已存在的存证不受影响，需要时配合 `force_revoke_claim` 删除。
+        #[pallet::call_index(41)]
+        #[pallet::weight((T::WeightInfo::ban_claim_hash(), DispatchClass::Operational))]
+        pub fn ban_claim_hash(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            ensure!(!Banned::<T>::contains_key(&claim_hash), Error::<T>::ClaimBanned);
+            Banned::<T>::insert(&claim_hash, ());
+
+            Self::deposit_event(Event::ClaimHashBanned { claim_hash });
+
+            Ok(Pays::No.into())
+        }
+
+        /// 管理员解除对 `claim_hash` 的禁止
+        #[pallet::call_index(42)]
+        #[pallet::weight((T::WeightInfo::unban_claim_hash(), DispatchClass::Operational))]
+        pub fn unban_claim_hash(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            ensure!(Banned::<T>::take(&claim_hash).is_some(), Error::<T>::ClaimNotBanned);
+
+            Self::deposit_event(Event::ClaimHashUnbanned { claim_hash });
+
+            Ok(Pays::No.into())
+        }
+
+        /// 管理员禁止 `who` 创建或接收存证，其已有的存证仍可撤销或转出
+        #[pallet::call_index(43)]
+        #[pallet::weight((T::WeightInfo::ban_account(), DispatchClass::Operational))]
+        pub fn ban_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            ensure!(!BannedAccounts::<T>::contains_key(&who), Error::<T>::AccountBanned);
+            BannedAccounts::<T>::insert(&who, ());
+
+            Self::deposit_event(Event::AccountBanned { who });
+
+            Ok(Pays::No.into())
+        }
+
+        /// 管理员解除对 `who` 的禁止
+        #[pallet::call_index(44)]
+        #[pallet::weight((T::WeightInfo::unban_account(), DispatchClass::Operational))]
+        pub fn unban_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            ensure!(BannedAccounts::<T>::take(&who).is_some(), Error::<T>::AccountNotBanned);
+
+            Self::deposit_event(Event::AccountUnbanned { who });
+
+            Ok(Pays::No.into())
+        }
+
+        /// 主所有者添加共同所有者，之后撤销或转移存证需要全部共同所有者批准
+        #[pallet::call_index(45)]
+        #[pallet::weight(T::WeightInfo::add_co_owner())]
+        pub fn add_co_owner(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>, who: T::AccountId) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(who != details.owner, Error::<T>::AlreadyCoOwner);
+
+            CoOwners::<T>::try_mutate(&claim_hash, |co_owners| {
+                ensure!(!co_owners.contains(&who), Error::<T>::AlreadyCoOwner);
+                co_owners.try_push(who.clone()).map_err(|_| Error::<T>::TooManyCoOwners)
+            })?;
+
+            Self::deposit_event(Event::CoOwnerAdded { claim_hash, co_owner: who });
+
+            Ok(())
+        }
+
+        /// 移除共同所有者，主所有者可以移除任何共同所有者，共同所有者也可以自行退出
+        #[pallet::call_index(46)]
+        #[pallet::weight(T::WeightInfo::remove_co_owner())]
+        pub fn remove_co_owner(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>, who: T::AccountId) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(sender == details.owner || sender == who, Error::<T>::NotClaimOwner);
+
+            CoOwners::<T>::try_mutate(&claim_hash, |co_owners| {
+                let index = co_owners.iter().position(|co_owner| *co_owner == who).ok_or(Error::<T>::NotCoOwner)?;
+                co_owners.remove(index);
+                Ok::<_, Error<T>>(())
+            })?;
+            CoOwnerApprovals::<T>::remove(&claim_hash, &who);
+
+            Self::deposit_event(Event::CoOwnerRemoved { claim_hash, co_owner: who });
+
+            Ok(())
+        }
+
+        /// 共同所有者批准对存证的撤销或转移，覆盖之前的批准；操作执行或存证转移后批准失效
+        #[pallet::call_index(47)]
+        #[pallet::weight(T::WeightInfo::approve_co_owner_action())]
+        pub fn approve_co_owner_action(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            action: CoOwnerAction<T::AccountId>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            Self::live_claim(&claim_hash)?;
+            ensure!(CoOwners::<T>::get(&claim_hash).contains(&sender), Error::<T>::NotCoOwner);
+            CoOwnerApprovals::<T>::insert(&claim_hash, &sender, action.clone());
+
+            Self::deposit_event(Event::CoOwnerActionApproved { who: sender, claim_hash, action });
+
+            Ok(())
+        }
+
+        /// 为存证设置撤销门限：之后 `revoke_claim` 只有在至少 `threshold` 个签名人调用
+        /// `approve_revocation` 后才能执行，防止单个泄露的私钥销毁存证
+        ///
+        /// 门限随存证转移保留。已设置门限时再次调用同样需要批准数量达到原门限，修改后批准清零。
+        #[pallet::call_index(48)]
+        #[pallet::weight(T::WeightInfo::set_revocation_threshold())]
+        pub fn set_revocation_threshold(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            signatories: BoundedVec<T::AccountId, T::MaxSignatories>,
+            threshold: u32
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(threshold > 0 && threshold as usize <= signatories.len(), Error::<T>::InvalidThreshold);
+            let mut unique = signatories.clone().into_inner();
+            unique.sort();
+            unique.dedup();
+            ensure!(unique.len() == signatories.len(), Error::<T>::InvalidThreshold);
+            Self::ensure_revocation_approved(&claim_hash)?;
+
+            RevocationGuards::<T>::insert(&claim_hash, RevocationGuard { signatories: signatories.clone(), threshold });
+            RevocationApprovals::<T>::remove(&claim_hash);
+
+            Self::deposit_event(Event::RevocationThresholdSet { claim_hash, signatories, threshold });
+
+            Ok(())
+        }
+
+        /// 签名人批准撤销存证，每个签名人只能批准一次
+        #[pallet::call_index(49)]
+        #[pallet::weight(T::WeightInfo::approve_revocation())]
+        pub fn approve_revocation(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            Self::live_claim(&claim_hash)?;
+            let guard = RevocationGuards::<T>::get(&claim_hash).ok_or(Error::<T>::NotSignatory)?;
+            ensure!(guard.signatories.contains(&sender), Error::<T>::NotSignatory);
+
+            RevocationApprovals::<T>::try_mutate(&claim_hash, |approvals| {
+                ensure!(!approvals.contains(&sender), Error::<T>::AlreadyApprovedRevocation);
+                // 签名人不重复，批准数量不会超过签名人数量
+                approvals.try_push(sender.clone()).map_err(|_| Error::<T>::AlreadyApprovedRevocation)
+            })?;
+
+            Self::deposit_event(Event::RevocationApproved { who: sender, claim_hash });
+
+            Ok(())
+        }
+
+        /// 设置社交恢复方案：`threshold` 个监护人发起恢复并经过 `delay` 个区块后，
+        /// 调用者的全部存证可以被转到监护人指定的新账户
+        #[pallet::call_index(50)]
+        #[pallet::weight(T::WeightInfo::set_recovery_config())]
+        pub fn set_recovery_config(
+            origin: OriginFor<T>,
+            guardians: BoundedVec<T::AccountId, T::MaxGuardians>,
+            threshold: u32,
+            delay: BlockNumberFor<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            ensure!(!ActiveRecoveries::<T>::contains_key(&sender), Error::<T>::RecoveryInProgress);
+            ensure!(threshold > 0 && threshold as usize <= guardians.len(), Error::<T>::InvalidThreshold);
+            let mut unique = guardians.clone().into_inner();
+            unique.sort();
+            unique.dedup();
+            ensure!(unique.len() == guardians.len(), Error::<T>::InvalidThreshold);
+            ensure!(delay >= T::MinRecoveryDelay::get(), Error::<T>::RecoveryDelayTooShort);
+
+            RecoveryConfigs::<T>::insert(&sender, RecoveryConfig { guardians, threshold, delay });
+
+            Self::deposit_event(Event::RecoveryConfigSet { who: sender });
+
+            Ok(())
+        }
+
+        /// 监护人为 `lost` 发起恢复，把存证转到 `new_owner`；监护人数量达到门限时等待期开始
+        #[pallet::call_index(51)]
+        #[pallet::weight(T::WeightInfo::initiate_recovery())]
+        pub fn initiate_recovery(
+            origin: OriginFor<T>,
+            lost: T::AccountId,
+            new_owner: T::AccountId
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let config = RecoveryConfigs::<T>::get(&lost).ok_or(Error::<T>::RecoveryNotConfigured)?;
+            ensure!(config.guardians.contains(&sender), Error::<T>::NotGuardian);
+
+            let mut recovery = ActiveRecoveries::<T>::get(&lost).unwrap_or_else(|| ActiveRecovery {
+                new_owner: new_owner.clone(),
+                vouchers: Default::default(),
+                started_at: None,
+            });
+            ensure!(recovery.new_owner == new_owner, Error::<T>::RecoveryTargetMismatch);
+            ensure!(!recovery.vouchers.contains(&sender), Error::<T>::AlreadyVouched);
+            // 监护人不重复，发起数量不会超过监护人数量
+            recovery.vouchers.try_push(sender.clone()).map_err(|_| Error::<T>::AlreadyVouched)?;
+
+            Self::deposit_event(Event::RecoveryVouched {
+                who: sender,
+                lost: lost.clone(),
+                rescuer: new_owner.clone(),
+            });
+
+            if recovery.started_at.is_none() && recovery.vouchers.len() >= config.threshold as usize {
+                recovery.started_at = Some(frame_system::Pallet::<T>::block_number());
+                Self::deposit_event(Event::RecoveryStarted { lost: lost.clone(), rescuer: new_owner });
+            }
+            ActiveRecoveries::<T>::insert(&lost, recovery);
+
+            Ok(())
+        }
+
+        /// 所有者否决针对自己的恢复
+        #[pallet::call_index(52)]
+        #[pallet::weight(T::WeightInfo::veto_recovery())]
+        pub fn veto_recovery(origin: OriginFor<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            ensure!(ActiveRecoveries::<T>::take(&sender).is_some(), Error::<T>::RecoveryNotExist);
+
+            Self::deposit_event(Event::RecoveryVetoed { who: sender });
+
+            Ok(())
+        }
+
+        /// 等待期结束后任何人都可以完成恢复，把 `lost` 的存证全部转给新账户
+        ///
+        /// 冻结、争议中等无法转移的存证留在原账户，不影响其余存证；恢复方案随之清除。
+        #[pallet::call_index(53)]
+        #[pallet::weight(T::WeightInfo::complete_recovery(T::MaxClaimsPerAccount::get()))]
+        pub fn complete_recovery(origin: OriginFor<T>, lost: T::AccountId) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            ensure_signed(origin)?;
+
+            let recovery = ActiveRecoveries::<T>::get(&lost).ok_or(Error::<T>::RecoveryNotExist)?;
+            let config = RecoveryConfigs::<T>::get(&lost).ok_or(Error::<T>::RecoveryNotConfigured)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                recovery.started_at.map_or(false, |started_at| now >= started_at.saturating_add(config.delay)),
+                Error::<T>::RecoveryNotReady
+            );
+
+            let mut recovered = 0u32;
+            for claim_hash in Self::claims_of(&lost) {
+                let Some(details) = Proofs::<T>::get(&claim_hash).filter(|details| !details.is_expired(now)) else {
+                    continue;
+                };
+                if with_storage_layer(|| Self::do_forced_transfer(claim_hash, details, recovery.new_owner.clone())).is_ok() {
+                    recovered += 1;
+                }
+            }
+            ActiveRecoveries::<T>::remove(&lost);
+            RecoveryConfigs::<T>::remove(&lost);
+
+            Self::deposit_event(Event::RecoveryCompleted { lost, rescuer: recovery.new_owner, count: recovered });
+
+            Ok(())
+        }
+
+        /// 设置存证的继承人：所有者连续 `inactivity_blocks` 个区块没有调用 `ping` 后，
+        /// 任何人都可以调用 `claim_inheritance` 把存证转给 `heir`
+        ///
+        /// 设置本身也算一次活动。存证转移后继承设置失效。
+        #[pallet::call_index(54)]
+        #[pallet::weight(T::WeightInfo::set_inheritor())]
+        pub fn set_inheritor(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            heir: T::AccountId,
+            inactivity_blocks: BlockNumberFor<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(heir != sender, Error::<T>::InvalidHeir);
+            ensure!(inactivity_blocks >= T::MinInactivityPeriod::get(), Error::<T>::InactivityPeriodTooShort);
+
+            Inheritors::<T>::insert(&claim_hash, Inheritance { heir: heir.clone(), inactivity_blocks });
+            LastActivity::<T>::insert(&sender, frame_system::Pallet::<T>::block_number());
+
+            Self::deposit_event(Event::InheritorSet {
+                claim_hash,
+                inheritor: heir,
+                inactivity_period: inactivity_blocks,
+            });
+
+            Ok(())
+        }
+
+        /// 报告调用者仍在活动，推迟其全部存证的继承
+        #[pallet::call_index(55)]
+        #[pallet::weight(T::WeightInfo::ping())]
+        pub fn ping(origin: OriginFor<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            LastActivity::<T>::insert(&sender, frame_system::Pallet::<T>::block_number());
+
+            Self::deposit_event(Event::Heartbeat { who: sender });
+
+            Ok(())
+        }
+
+        /// 所有者超过不活动期后，任何人都可以把存证转给继承人
+        ///
+        /// 与管理员强制转移一样不需要共同所有者批准，但冻结或处于争议中的存证不能继承。
+        #[pallet::call_index(56)]
+        #[pallet::weight(T::WeightInfo::claim_inheritance())]
+        pub fn claim_inheritance(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            let inheritance = Inheritors::<T>::get(&claim_hash).ok_or(Error::<T>::NoInheritor)?;
+            let inactive_since = LastActivity::<T>::get(&details.owner);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= inactive_since.saturating_add(inheritance.inactivity_blocks),
+                Error::<T>::OwnerStillActive
+            );
+
+            let owner = details.owner.clone();
+            Self::do_transfer(claim_hash, details, inheritance.heir.clone())?;
+
+            Self::deposit_event(Event::InheritanceClaimed { owner, who: inheritance.heir, claim_hash });
+
+            Ok(())
+        }
+
+        /// 预约在 `at` 区块把存证转给 `target`，转移在该区块的 `on_initialize` 中执行
+        ///
+        /// 权限规则与 `transfer_claim` 相同；执行时所有权已变化、存证被冻结或共同所有者的批准不足时转移失败。
+        #[pallet::call_index(57)]
+        #[pallet::weight(T::WeightInfo::schedule_transfer())]
+        pub fn schedule_transfer(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            target: T::AccountId,
+            at: BlockNumberFor<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+            ensure!(at > frame_system::Pallet::<T>::block_number(), Error::<T>::InvalidScheduleBlock);
+            ensure!(!ScheduledTransfers::<T>::contains_key(&claim_hash), Error::<T>::TransferAlreadyScheduled);
+
+            TransferQueue::<T>::try_mutate(at, |queue| queue.try_push(claim_hash))
+                .map_err(|_| Error::<T>::TooManyScheduledTransfers)?;
+            ScheduledTransfers::<T>::insert(
+                &claim_hash,
+                ScheduledTransfer { from: details.owner.clone(), to: target.clone(), at },
+            );
+
+            Self::deposit_event(Event::TransferScheduled { claim_hash, who: details.owner, target, at });
+
+            Ok(())
+        }
+
+        /// 所有者或其操作员取消预约的转移
+        #[pallet::call_index(58)]
+        #[pallet::weight(T::WeightInfo::cancel_scheduled_transfer())]
+        pub fn cancel_scheduled_transfer(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+            Self::unschedule_transfer(&claim_hash).ok_or(Error::<T>::ScheduledTransferNotExist)?;
+
+            Self::deposit_event(Event::ScheduledTransferCancelled { claim_hash });
+
+            Ok(())
+        }
+
+        /// 两步撤销的第一步：标记存证等待撤销，`RevocationDelay` 个区块后才能完成
+        ///
+        /// 等待期内所有者可以 `cancel_revoke`；存证被转移后等待中的撤销失效。
+        #[pallet::call_index(59)]
+        #[pallet::weight(T::WeightInfo::begin_revoke())]
+        pub fn begin_revoke(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+            ensure!(!PendingRevocations::<T>::contains_key(&claim_hash), Error::<T>::RevocationAlreadyPending);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            PendingRevocations::<T>::insert(&claim_hash, now);
+
+            Self::deposit_event(Event::RevocationBegun {
+                claim_hash,
+                ready_at: now.saturating_add(T::RevocationDelay::get()),
+            });
+
+            Ok(())
+        }
+
+        /// 两步撤销的第二步：等待期结束后完成撤销，其余规则与 `revoke_claim` 相同
+        #[pallet::call_index(60)]
+        #[pallet::weight(T::WeightInfo::finalize_revoke())]
+        pub fn finalize_revoke(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+            let begun_at = PendingRevocations::<T>::get(&claim_hash).ok_or(Error::<T>::RevocationNotPending)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= begun_at.saturating_add(T::RevocationDelay::get()),
+                Error::<T>::RevocationDelayNotElapsed
+            );
+            Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Revoke)?;
+            Self::ensure_revocation_approved(&claim_hash)?;
+            ensure!(!Reports::<T>::contains_key(&claim_hash), Error::<T>::ClaimUnderReport);
+            Self::ensure_claim_movable(&claim_hash)?;
+
+            let removed = Self::do_revoke(claim_hash, details)?;
+            Ok(Some(Self::revoke_weight(T::WeightInfo::finalize_revoke(), 1, removed)).into())
+        }
+
+        /// 取消等待中的撤销
+        #[pallet::call_index(61)]
+        #[pallet::weight(T::WeightInfo::cancel_revoke())]
+        pub fn cancel_revoke(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+            ensure!(PendingRevocations::<T>::take(&claim_hash).is_some(), Error::<T>::RevocationNotPending);
+
+            Self::deposit_event(Event::RevocationCancelled { claim_hash });
+
+            Ok(())
+        }
+
+        /// 挂单出售存证，挂单期间存证不能通过其他方式转移；共同所有者需要事先批准出售
+        ///
+        /// 已有挂单时重新挂单会更新售价。
+        #[pallet::call_index(62)]
+        #[pallet::weight(T::WeightInfo::list_claim_for_sale())]
+        pub fn list_claim_for_sale(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            price: BalanceOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(!Auctions::<T>::contains_key(&claim_hash), Error::<T>::ClaimInAuction);
+            ensure!(!ClaimBundle::<T>::contains_key(&claim_hash), Error::<T>::ClaimInBundle);
+            // 托管中的存证可能被退款归还卖家，抵押和归属期内的存证不能转移，都不能挂单
+            Self::ensure_claim_movable(&claim_hash)?;
+            Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Sell)?;
+
+            Listings::<T>::insert(&claim_hash, Listing { seller: sender.clone(), price, decay: None });
+
+            Self::deposit_event(Event::ClaimListed { who: sender, claim_hash, price });
+
+            Ok(())
+        }
+
+        /// 按挂单的当前价格购买存证：货款扣除 `SaleFee` 和版税后转给卖家，费用销毁，存证转给买家
+        ///
+        /// `max_price` 是买家愿意支付的最高价，防止卖家在交易打包前抬高售价；荷兰式挂单按当前区块的价格成交。
+        #[pallet::call_index(63)]
+        #[pallet::weight(T::WeightInfo::buy_claim())]
+        pub fn buy_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            max_price: BalanceOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let buyer = ensure_signed(origin)?;
+
+            let listing = Listings::<T>::take(&claim_hash).ok_or(Error::<T>::NotListed)?;
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == listing.seller, Error::<T>::NotListed);
+            ensure!(buyer != listing.seller, Error::<T>::CannotBuyOwnClaim);
+            let price = listing.price_at(frame_system::Pallet::<T>::block_number());
+            ensure!(price <= max_price, Error::<T>::PriceTooHigh);
+
+            Self::pay_sale(&claim_hash, &buyer, &listing.seller, price)?;
+            Self::do_transfer(claim_hash, details, buyer.clone())?;
+
+            Self::deposit_event(Event::ClaimSold { seller: listing.seller, buyer, claim_hash, price });
+
+            Ok(())
+        }
+
+        /// 以托管方式按挂单的当前价格购买存证：存证立即转给买家，货款从买家保留 `EscrowPeriod` 个区块，
+        /// 期间买家可以 `dispute_escrow`，否则到期后按 `buy_claim` 的规则支付给卖家
+        ///
+        /// 放款之前存证不能再转移或撤销，存证过期删除时货款退还给买家。
+        #[pallet::call_index(92)]
+        #[pallet::weight(T::WeightInfo::buy_claim_escrowed())]
+        pub fn buy_claim_escrowed(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            max_price: BalanceOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let buyer = ensure_signed(origin)?;
+
+            let period = T::EscrowPeriod::get();
+            ensure!(!period.is_zero(), Error::<T>::EscrowDisabled);
+            let listing = Listings::<T>::take(&claim_hash).ok_or(Error::<T>::NotListed)?;
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == listing.seller, Error::<T>::NotListed);
+            ensure!(buyer != listing.seller, Error::<T>::CannotBuyOwnClaim);
+            let now = frame_system::Pallet::<T>::block_number();
+            let price = listing.price_at(now);
+            ensure!(price <= max_price, Error::<T>::PriceTooHigh);
+
+            T::Currency::reserve(&buyer, price)?;
+            Self::do_transfer(claim_hash, details, buyer.clone())?;
+            let release_at = now.saturating_add(period);
+            EscrowReleases::<T>::try_mutate(release_at, |releases| releases.try_push(claim_hash))
+                .map_err(|_| Error::<T>::TooManyEscrows)?;
+            Escrows::<T>::insert(
+                &claim_hash,
+                Escrow {
+                    seller: listing.seller.clone(),
+                    buyer: buyer.clone(),
+                    price,
+                    release_at,
+                    dispute: None,
+                },
+            );
+
+            Self::deposit_event(Event::ClaimSoldInEscrow {
+                seller: listing.seller,
+                buyer,
+                claim_hash,
+                price,
+                release_at,
+            });
+
+            Ok(())
+        }
+
+        /// 买家在争议期内对托管出售发起争议，保留 `DisputeBond` 作为保证金，放款暂停直到仲裁
+        #[pallet::call_index(93)]
+        #[pallet::weight(T::WeightInfo::dispute_escrow())]
+        pub fn dispute_escrow(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            evidence: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let mut escrow = Escrows::<T>::get(&claim_hash).ok_or(Error::<T>::EscrowNotExist)?;
+            ensure!(escrow.buyer == sender, Error::<T>::NotEscrowBuyer);
+            ensure!(frame_system::Pallet::<T>::block_number() < escrow.release_at, Error::<T>::EscrowPeriodEnded);
+            ensure!(escrow.dispute.is_none(), Error::<T>::EscrowAlreadyDisputed);
+
+            let bond = T::DisputeBond::get();
+            T::Currency::reserve(&sender, bond)?;
+            escrow.dispute = Some((evidence, bond));
+            Escrows::<T>::insert(&claim_hash, escrow);
+
+            Self::deposit_event(Event::EscrowDisputed { buyer: sender, claim_hash, evidence });
+
+            Ok(())
+        }
+
+        /// 仲裁托管出售：`refund` 为真时退还货款和保证金并把存证归还卖家，否则没收保证金（销毁）并向卖家放款
+        ///
+        /// 也用于处理争议期结束后放款失败的托管。
+        #[pallet::call_index(94)]
+        #[pallet::weight((T::WeightInfo::resolve_escrow(), DispatchClass::Operational))]
+        pub fn resolve_escrow(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            refund: bool
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            T::ArbitrationOrigin::ensure_origin(origin)?;
+
+            let escrow = Escrows::<T>::take(&claim_hash).ok_or(Error::<T>::EscrowNotExist)?;
+            let bond = escrow.dispute.map_or(Zero::zero(), |(_, bond)| bond);
+            if refund {
+                T::Currency::unreserve(&escrow.buyer, escrow.price.saturating_add(bond));
+                let details = Self::live_claim(&claim_hash)?;
+                if details.owner == escrow.buyer {
+                    // 归还卖家前取消买家的挂单和拍卖
+                    Self::withdraw_from_sale(&claim_hash);
+                    Self::do_forced_transfer(claim_hash, details, escrow.seller)?;
+                }
+                Self::deposit_event(Event::EscrowRefunded { buyer: escrow.buyer, claim_hash, price: escrow.price });
+            } else {
+                let _ = T::Currency::slash_reserved(&escrow.buyer, bond);
+                Self::release_escrow(&claim_hash, &escrow)?;
+            }
+
+            Ok(Pays::No.into())
+        }
+
+        /// 把多个存证打包挂单出售，买家以 `price` 一次性买下全部存证；共同所有者需要事先批准出售
+        ///
+        /// 打包期间存证不能单独挂单、拍卖或转移。
+        #[pallet::call_index(95)]
+        #[pallet::weight(T::WeightInfo::create_bundle(T::MaxBundleSize::get()))]
+        pub fn create_bundle(
+            origin: OriginFor<T>,
+            claims: BoundedVec<ClaimHashOf<T>, T::MaxBundleSize>,
+            price: BalanceOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            ensure!(!claims.is_empty(), Error::<T>::EmptyBundle);
+            let bundle_id = NextBundleId::<T>::get();
+            let next_id = bundle_id.checked_add(1).ok_or(Error::<T>::NoAvailableBundleId)?;
+            NextBundleId::<T>::put(next_id);
+
+            for claim_hash in claims.iter() {
+                let details = Self::live_claim(claim_hash)?;
+                ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+                ensure!(!Listings::<T>::contains_key(claim_hash), Error::<T>::ClaimListed);
+                ensure!(!Auctions::<T>::contains_key(claim_hash), Error::<T>::ClaimInAuction);
+                Self::ensure_claim_movable(claim_hash)?;
+                // 重复的存证在第二次出现时失败
+                ensure!(!ClaimBundle::<T>::contains_key(claim_hash), Error::<T>::ClaimInBundle);
+                Self::ensure_co_owners_approved(claim_hash, &CoOwnerAction::Sell)?;
+                ClaimBundle::<T>::insert(claim_hash, bundle_id);
+            }
+            BundleClaims::<T>::insert(bundle_id, claims);
+            Bundles::<T>::insert(bundle_id, Bundle { seller: sender.clone(), price });
+
+            Self::deposit_event(Event::BundleListed { who: sender, bundle_id, price });
+
+            Ok(())
+        }
+
+        /// 按总价购买打包中的全部存证，任意一个存证无法转移时整体回滚
+        ///
+        /// 总价按存证数量平均拆分（余数计入最后一个存证），每一份按 `buy_claim` 的规则扣除费用和版税后支付给卖家；
+        /// `max_price` 是买家愿意支付的最高总价。已过期但尚未清理的存证不出售，总价扣除它们的份额，
+        /// 存证留给过期清理删除；全部存证都已过期时返回 `EmptyBundle`。
+        #[pallet::call_index(96)]
+        #[pallet::weight(T::WeightInfo::buy_bundle(T::MaxBundleSize::get()))]
+        pub fn buy_bundle(origin: OriginFor<T>, bundle_id: BundleId, max_price: BalanceOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let buyer = ensure_signed(origin)?;
+
+            let bundle = Bundles::<T>::take(bundle_id).ok_or(Error::<T>::BundleNotExist)?;
+            ensure!(buyer != bundle.seller, Error::<T>::CannotBuyOwnClaim);
+            ensure!(bundle.price <= max_price, Error::<T>::PriceTooHigh);
+
+            let claims = BundleClaims::<T>::take(bundle_id);
+            let share = bundle.price / (claims.len() as u32).into();
+            let mut live = Vec::new();
+            for claim_hash in claims.iter() {
+                ClaimBundle::<T>::remove(claim_hash);
+                if let Ok(details) = Self::live_claim(claim_hash) {
+                    live.push((*claim_hash, details));
+                }
+            }
+            ensure!(!live.is_empty(), Error::<T>::EmptyBundle);
+
+            let price = if live.len() == claims.len() {
+                bundle.price
+            } else {
+                share.saturating_mul((live.len() as u32).into())
+            };
+            let mut remaining = price;
+            let count = live.len();
+            for (index, (claim_hash, details)) in live.into_iter().enumerate() {
+                ensure!(details.owner == bundle.seller, Error::<T>::NotClaimOwner);
+                let amount = if index + 1 == count { remaining } else { share };
+                remaining = remaining.saturating_sub(amount);
+                Self::pay_sale(&claim_hash, &buyer, &bundle.seller, amount)?;
+                Self::do_transfer(claim_hash, details, buyer.clone())?;
+            }
+
+            Self::deposit_event(Event::BundleSold { seller: bundle.seller, buyer, bundle_id, price });
+
+            Ok(())
+        }
+
+        /// 卖家把存证从打包出售中移出，`new_price` 可以同时更新总价；最后一个存证移出后打包出售取消
+        #[pallet::call_index(97)]
+        #[pallet::weight(T::WeightInfo::unbundle_claim())]
+        pub fn unbundle_claim(
+            origin: OriginFor<T>,
+            bundle_id: BundleId,
+            claim_hash: ClaimHashOf<T>,
+            new_price: Option<BalanceOf<T>>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let mut bundle = Bundles::<T>::get(bundle_id).ok_or(Error::<T>::BundleNotExist)?;
+            ensure!(bundle.seller == sender, Error::<T>::NotBundleSeller);
+            ensure!(ClaimBundle::<T>::get(&claim_hash) == Some(bundle_id), Error::<T>::ClaimNotInBundle);
+
+            Self::remove_from_bundle(bundle_id, &claim_hash);
+            if let Some(price) = new_price.filter(|_| Bundles::<T>::contains_key(bundle_id)) {
+                bundle.price = price;
+                Bundles::<T>::insert(bundle_id, bundle);
+                Self::deposit_event(Event::BundlePriceUpdated { bundle_id, price });
+            }
+
+            Ok(())
+        }
+
+        /// 关注存证，存证转移或撤销时会收到 `WatchedClaimChanged` 事件；任何账户都可以关注
+        #[pallet::call_index(98)]
+        #[pallet::weight(T::WeightInfo::watch_claim())]
+        pub fn watch_claim(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            Self::live_claim(&claim_hash)?;
+            Watchers::<T>::try_mutate(&claim_hash, |watchers| -> DispatchResult {
+                ensure!(!watchers.contains(&sender), Error::<T>::AlreadyWatching);
+                watchers.try_push(sender.clone()).map_err(|_| Error::<T>::TooManyWatchers)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ClaimWatched { who: sender, claim_hash });
+
+            Ok(())
+        }
+
+        /// 取消关注存证
+        #[pallet::call_index(99)]
+        #[pallet::weight(T::WeightInfo::unwatch_claim())]
+        pub fn unwatch_claim(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            Watchers::<T>::try_mutate_exists(&claim_hash, |maybe_watchers| -> DispatchResult {
+                let watchers = maybe_watchers.as_mut().ok_or(Error::<T>::NotWatching)?;
+                let index = watchers.iter().position(|who| who == &sender).ok_or(Error::<T>::NotWatching)?;
+                watchers.remove(index);
+                if watchers.is_empty() {
+                    *maybe_watchers = None;
+                }
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ClaimUnwatched { who: sender, claim_hash });
+
+            Ok(())
+        }
+
+        /// 为存证添加一条溯源注释，记录作者和区块号；所有者和操作员可以添加，
+        /// 开启 `AttestersCanAnnotate` 时背书人也可以添加
+        #[pallet::call_index(100)]
+        #[pallet::weight(T::WeightInfo::annotate_claim())]
+        pub fn annotate_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            text: AnnotationTextOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            let is_attester = T::AttestersCanAnnotate::get() && Attestations::<T>::contains_key(&claim_hash, &sender);
+            ensure!(Self::is_owner_or_operator(&details.owner, &sender) || is_attester, Error::<T>::NotAnnotator);
+            ensure!(!text.is_empty(), Error::<T>::EmptyAnnotation);
+
+            let block = frame_system::Pallet::<T>::block_number();
+            let index = Annotations::<T>::try_mutate(&claim_hash, |annotations| -> Result<u32, DispatchError> {
+                annotations
+                    .try_push(Annotation { author: sender.clone(), text, block })
+                    .map_err(|_| Error::<T>::TooManyAnnotations)?;
+                Ok(annotations.len() as u32 - 1)
+            })?;
+
+            Self::deposit_event(Event::ClaimAnnotated { who: sender, claim_hash, index });
+
+            Ok(())
+        }
+
+        /// 以归属方式转移存证：`target` 立即成为所有者并可以背书、注释，
+        /// `duration` 个区块后才能转移、出售或撤销存证；共同所有者需要事先批准转给 `target`
+        #[pallet::call_index(101)]
+        #[pallet::weight(T::WeightInfo::transfer_with_vesting())]
+        pub fn transfer_with_vesting(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            target: T::AccountId,
+            duration: BlockNumberFor<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+            ensure!(!duration.is_zero(), Error::<T>::InvalidVestingDuration);
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+            Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Transfer(target.clone()))?;
+
+            let from = details.owner.clone();
+            Self::do_transfer(claim_hash, details, target.clone())?;
+            let unlocks_at = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+            Vestings::<T>::insert(&claim_hash, Vesting { from: from.clone(), unlocks_at });
+
+            Self::deposit_event(Event::VestingStarted { who: from, target, claim_hash, unlocks_at });
+
+            Ok(())
+        }
+
+        /// 把自己的存证锁定为 `locker` 的抵押品，借贷 pallet 也可以通过 [`ClaimCollateral`] 锁定；
+        /// 锁定期间存证不能转移或撤销，直到 `locker` 调用 `unlock_collateral`
+        #[pallet::call_index(102)]
+        #[pallet::weight(T::WeightInfo::lock_as_collateral())]
+        pub fn lock_as_collateral(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            locker: T::AccountId
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_lock_collateral(&sender, claim_hash, locker)
+        }
+
+        /// 登记的锁定方解除存证的抵押锁定
+        #[pallet::call_index(103)]
+        #[pallet::weight(T::WeightInfo::unlock_collateral())]
+        pub fn unlock_collateral(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_unlock_collateral(&sender, claim_hash)
+        }
+
+        /// 卖家取消出售挂单
+        #[pallet::call_index(64)]
+        #[pallet::weight(T::WeightInfo::cancel_listing())]
+        pub fn cancel_listing(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let listing = Listings::<T>::get(&claim_hash).ok_or(Error::<T>::NotListed)?;
+            ensure!(listing.seller == sender, Error::<T>::NotClaimOwner);
+            Listings::<T>::remove(&claim_hash);
+
+            Self::deposit_event(Event::ListingCancelled { claim_hash });
+
+            Ok(())
+        }
+
+        /// 发起英式拍卖，`duration` 个区块后在 `on_initialize` 中结算；共同所有者需要事先批准出售
+        ///
+        /// 拍卖期间存证不能转移或挂单，已挂单的存证需要先取消挂单；托管、归属期内或被抵押的存证不能拍卖。
+        #[pallet::call_index(65)]
+        #[pallet::weight(T::WeightInfo::start_auction())]
+        pub fn start_auction(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            min_bid: BalanceOf<T>,
+            duration: BlockNumberFor<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(!duration.is_zero(), Error::<T>::InvalidAuctionDuration);
+            ensure!(!Listings::<T>::contains_key(&claim_hash), Error::<T>::ClaimListed);
+            ensure!(!Auctions::<T>::contains_key(&claim_hash), Error::<T>::ClaimInAuction);
+            ensure!(!ClaimBundle::<T>::contains_key(&claim_hash), Error::<T>::ClaimInBundle);
+            Self::ensure_claim_movable(&claim_hash)?;
+            Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Sell)?;
+
+            let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+            AuctionEnds::<T>::try_mutate(end, |ends| ends.try_push(claim_hash))
+                .map_err(|_| Error::<T>::TooManyAuctions)?;
+            Auctions::<T>::insert(&claim_hash, Auction { seller: sender.clone(), min_bid, end, best_bid: None });
+
+            Self::deposit_event(Event::AuctionStarted { who: sender, claim_hash, min_bid, ends_at: end });
+
+            Ok(())
+        }
+
+        /// 对拍卖出价，金额必须不低于最低出价且高于当前最高价
+        ///
+        /// 出价金额从出价人保留，被超过的出价立即退还。
+        #[pallet::call_index(66)]
+        #[pallet::weight(T::WeightInfo::bid())]
+        pub fn bid(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let bidder = ensure_signed(origin)?;
+
+            let mut auction = Auctions::<T>::get(&claim_hash).ok_or(Error::<T>::AuctionNotExist)?;
+            ensure!(frame_system::Pallet::<T>::block_number() < auction.end, Error::<T>::AuctionEnded);
+            ensure!(bidder != auction.seller, Error::<T>::CannotBidOnOwnAuction);
+            ensure!(amount >= auction.min_bid, Error::<T>::BidTooLow);
+            if let Some((previous, best)) = auction.best_bid.take() {
+                ensure!(amount > best, Error::<T>::BidTooLow);
+                T::Currency::unreserve(&previous, best);
+            }
+            T::Currency::reserve(&bidder, amount)?;
+            auction.best_bid = Some((bidder.clone(), amount));
+            Auctions::<T>::insert(&claim_hash, auction);
+
+            Self::deposit_event(Event::BidPlaced { who: bidder, claim_hash, amount });
+
+            Ok(())
+        }
+
+        /// 以荷兰式挂单出售存证：售价从 `start_price` 按区块线性下降，`duration` 个区块后降至 `floor_price`
+        ///
+        /// 第一个以当前价格 `buy_claim` 的买家成交；其他规则与 `list_claim_for_sale` 相同。
+        #[pallet::call_index(67)]
+        #[pallet::weight(T::WeightInfo::list_claim_dutch())]
+        pub fn list_claim_dutch(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            start_price: BalanceOf<T>,
+            floor_price: BalanceOf<T>,
+            duration: BlockNumberFor<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(floor_price <= start_price, Error::<T>::InvalidPriceRange);
+            ensure!(!duration.is_zero(), Error::<T>::InvalidAuctionDuration);
+            ensure!(!Auctions::<T>::contains_key(&claim_hash), Error::<T>::ClaimInAuction);
+            ensure!(!ClaimBundle::<T>::contains_key(&claim_hash), Error::<T>::ClaimInBundle);
+            Self::ensure_claim_movable(&claim_hash)?;
+            Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Sell)?;
+
+            let start = frame_system::Pallet::<T>::block_number();
+            let end = start.saturating_add(duration);
+            Listings::<T>::insert(
+                &claim_hash,
+                Listing {
+                    seller: sender.clone(),
+                    price: start_price,
+                    decay: Some(PriceDecay { floor: floor_price, start, end }),
+                },
+            );
+
+            Self::deposit_event(Event::DutchListingCreated {
+                who: sender,
+                claim_hash,
+                start_price,
+                floor_price,
+                floor_at: end,
+            });
+
+            Ok(())
+        }
+
+        /// 创建者设置存证的版税，之后每次出售或拍卖成交都按 `rate` 向 `beneficiary` 支付版税
+        ///
+        /// 只能在存证第一次转移之前设置，转移之后版税设置随存证保留且不能再修改。
+        #[pallet::call_index(68)]
+        #[pallet::weight(T::WeightInfo::set_royalty())]
+        pub fn set_royalty(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            beneficiary: T::AccountId,
+            rate: Perbill
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(OwnershipHistory::<T>::decode_len(&claim_hash).unwrap_or(0) == 0, Error::<T>::NotOriginalCreator);
+            ensure!(rate <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+
+            Royalties::<T>::insert(&claim_hash, Royalty { beneficiary: beneficiary.clone(), rate });
+
+            Self::deposit_event(Event::RoyaltySet { claim_hash, beneficiary, rate });
+
+            Ok(())
+        }
+
+        /// 管理员把 `who` 加入免费名单
+        #[pallet::call_index(69)]
+        #[pallet::weight((T::WeightInfo::add_fee_exempt(), DispatchClass::Operational))]
+        pub fn add_fee_exempt(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            ensure!(!FeeExempt::<T>::contains_key(&who), Error::<T>::AlreadyFeeExempt);
+            FeeExempt::<T>::insert(&who, ());
+
+            Self::deposit_event(Event::FeeExemptionGranted { who });
+
+            Ok(Pays::No.into())
+        }
+
+        /// 管理员把 `who` 移出免费名单，已创建的存证不受影响
+        #[pallet::call_index(70)]
+        #[pallet::weight((T::WeightInfo::remove_fee_exempt(), DispatchClass::Operational))]
+        pub fn remove_fee_exempt(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            ensure!(FeeExempt::<T>::take(&who).is_some(), Error::<T>::NotFeeExempt);
+
+            Self::deposit_event(Event::FeeExemptionRevoked { who });
+
+            Ok(Pays::No.into())
+        }
+
+        /// Root 在 `MaxClaimLength` 以内调整允许的最大存证长度，无需升级运行时；已有的存证不受影响
+        #[pallet::call_index(71)]
+        #[pallet::weight((T::WeightInfo::set_max_claim_length(), DispatchClass::Operational))]
+        pub fn set_max_claim_length(origin: OriginFor<T>, max_length: u32) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            ensure_root(origin)?;
+
+            ensure!(
+                max_length > 0 && max_length <= T::MaxClaimLength::get(),
+                Error::<T>::InvalidMaxClaimLength
+            );
+            CurrentMaxLength::<T>::put(max_length);
+
+            Self::deposit_event(Event::MaxClaimLengthSet { max_length });
+
+            Ok(Pays::No.into())
+        }
+
+        /// 由链下工作机提交的无签名存证，载荷的签名在 `validate_unsigned` 中校验
+        ///
+        /// 签名公钥对应的账户成为所有者，该账户必须在免费名单中，因此不需要持有资金。
+        #[pallet::call_index(72)]
+        #[pallet::weight(T::WeightInfo::submit_claim_unsigned())]
+        pub fn submit_claim_unsigned(
+            origin: OriginFor<T>,
+            payload: ClaimPayload<T::Public, ClaimHashOf<T>, BlockNumberFor<T>>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            ensure_none(origin)?;
+
+            let owner = payload.public.into_account();
+            ensure!(FeeExempt::<T>::contains_key(&owner), Error::<T>::NotFeeExempt);
+
+            Self::do_create_claim(owner.clone(), owner, payload.claim_hash, T::MaxClaimLength::get())
+        }
+
+        /// 公证人报告存证 `uri` 指向的内容是否可以访问，通常由其链下工作机定期提交
+        ///
+        /// 冻结的存证不接受报告；报告与当前状态相同时返回错误，避免重复提交。
+        #[pallet::call_index(73)]
+        #[pallet::weight(T::WeightInfo::report_availability())]
+        pub fn report_availability(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            available: bool,
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+            ensure!(Notaries::<T>::contains_key(&sender), Error::<T>::NotNotary);
+
+            let mut details = Self::live_claim(&claim_hash)?;
+            ensure!(details.status != ClaimStatus::Frozen, Error::<T>::ClaimFrozen);
+            let status = if available { ClaimStatus::Active } else { ClaimStatus::ContentUnavailable };
+            ensure!(details.status != status, Error::<T>::AvailabilityUnchanged);
+
+            details.status = status;
+            Proofs::<T>::insert(&claim_hash, details);
+
+            Self::deposit_event(Event::ContentAvailabilityReported { who: sender, claim_hash, available });
+
+            Ok(())
+        }
+
+        /// 公证人记录存证根在以太坊上的锚定交易，通常由其链下工作机在锚定后提交
+        ///
+        /// `root` 为 `from..=to` 区块内创建的 `count` 个存证按创建顺序计算的默克尔根。
+        #[pallet::call_index(74)]
+        #[pallet::weight(T::WeightInfo::record_anchor())]
+        pub fn record_anchor(
+            origin: OriginFor<T>,
+            root: ClaimHashOf<T>,
+            from: BlockNumberFor<T>,
+            to: BlockNumberFor<T>,
+            count: u32,
+            tx_hash: [u8; 32],
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+            ensure!(Notaries::<T>::contains_key(&sender), Error::<T>::NotNotary);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(from <= to && to <= now, Error::<T>::InvalidAnchorRange);
+            ensure!(!Anchors::<T>::contains_key(&root), Error::<T>::AnchorAlreadyRecorded);
+
+            let record = AnchorRecord { submitter: sender.clone(), from, to, count, tx_hash, recorded_at: now };
+            Anchors::<T>::insert(&root, record);
+
+            Self::deposit_event(Event::AnchorRecorded { who: sender, root, tx_hash });
+
+            Ok(())
+        }
+
+        /// 登记一批文档哈希的默克尔根，一笔交易即可为任意数量的文档存证
+        ///
+        /// 默克尔树按 [`Pallet::merkle_root`] 的规则由 `count` 个文档哈希依次构建，只保留一份基础押金；
+        /// 单个文档之后可以通过 `prove_membership` 上链成为普通存证。
+        #[pallet::call_index(75)]
+        #[pallet::weight(T::WeightInfo::create_batch_root())]
+        pub fn create_batch_root(origin: OriginFor<T>, root: ClaimHashOf<T>, count: u32) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+            Self::ensure_not_banned(&sender)?;
+
+            ensure!(count > 0, Error::<T>::InvalidBatchSize);
+            ensure!(!BatchRoots::<T>::contains_key(&root), Error::<T>::BatchRootAlreadyExists);
+
+            let deposit = Self::deposit_for(0);
+            T::Currency::reserve(&sender, deposit)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            BatchRoots::<T>::insert(&root, BatchRoot { owner: sender.clone(), count, created_at: now, deposit });
+
+            Self::deposit_event(Event::BatchRootCreated { who: sender, root, count });
+
+            Ok(())
+        }
+
+        /// 凭默克尔证明把批量存证中的 `leaf` 上链为普通存证，所有者为默克尔根的登记者
+        ///
+        /// 任何账户都可以提交证明，存证押金由提交者支付，撤销或过期时退还给提交者。
+        #[pallet::call_index(76)]
+        #[pallet::weight(T::WeightInfo::prove_membership(proof.siblings.len() as u32))]
+        pub fn prove_membership(
+            origin: OriginFor<T>,
+            root: ClaimHashOf<T>,
+            leaf: ClaimHashOf<T>,
+            proof: MerkleProof<ClaimHashOf<T>, T::MaxProofDepth>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+            Self::ensure_claim_available(&leaf)?;
+
+            let batch = BatchRoots::<T>::get(&root).ok_or(Error::<T>::BatchRootNotExist)?;
+            ensure!(Self::verify_merkle_proof(&root, leaf, batch.count, &proof), Error::<T>::InvalidMerkleProof);
+
+            // 无法得知原文长度，按最大长度收取押金
+            Self::do_create_claim(batch.owner, sender, leaf, T::MaxClaimLength::get())?;
+
+            Self::deposit_event(Event::BatchMembershipProven { root, claim_hash: leaf });
+
+            Ok(().into())
+        }
+
+        /// 所有者删除默克尔根并取回押金，已经上链的存证不受影响
+        #[pallet::call_index(77)]
+        #[pallet::weight(T::WeightInfo::remove_batch_root())]
+        pub fn remove_batch_root(origin: OriginFor<T>, root: ClaimHashOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let batch = BatchRoots::<T>::get(&root).ok_or(Error::<T>::BatchRootNotExist)?;
+            ensure!(batch.owner == sender, Error::<T>::NotClaimOwner);
+
+            BatchRoots::<T>::remove(&root);
+            T::Currency::unreserve(&sender, batch.deposit);
+
+            Self::deposit_event(Event::BatchRootRemoved { who: sender, root });
+
+            Ok(())
+        }
+
+        /// 创建附带作者签名的存证：`author` 对存证原始内容的签名验证通过后，作者公钥与存证一起保存
+        ///
+        /// 提交交易的账户成为所有者，作者只需离线签名，因此创作归属与保管可以分开且可验证。
+        #[pallet::call_index(78)]
+        #[pallet::weight(T::WeightInfo::create_signed_claim())]
+        pub fn create_signed_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            author: AuthorKey,
+            signature: AuthorSignature,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let claim_hash = Self::claim_hash(&claim);
+            Self::ensure_claim_available(&claim_hash)?;
+            Self::ensure_claim_length(&claim)?;
+            Self::ensure_content_id(&claim)?;
+            Self::ensure_namespace_allowed(&sender, &claim)?;
+            ensure!(author.verify(&claim, &signature), Error::<T>::InvalidAuthorSignature);
+
+            Self::do_create_claim(sender.clone(), sender.clone(), claim_hash, claim.len() as u32)?;
+            ClaimAuthors::<T>::insert(&claim_hash, author.clone());
+
+            Self::deposit_event(Event::SignedClaimCreated { who: sender.clone(), claim_hash, author });
+
+            // 免费名单中的账户不支付交易费
+            let pays_fee = if FeeExempt::<T>::contains_key(&sender) { Pays::No } else { Pays::Yes };
+            Ok(pays_fee.into())
+        }
+
+        /// 为已登记到预映像 pallet 的原文创建存证，链上只保存哈希，原文长度不受 `MaxClaimLength` 限制；
+        /// `retain` 为 `true` 时请求预映像 pallet 在存证存续期间保留原文，存证删除后取消请求
+        #[pallet::call_index(79)]
+        #[pallet::weight(T::WeightInfo::create_claim_from_preimage())]
+        pub fn create_claim_from_preimage(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            retain: bool,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+            Self::ensure_claim_available(&claim_hash)?;
+            let len = T::Preimages::len(&claim_hash).ok_or(Error::<T>::PreimageNotExist)?;
+
+            // 原文由预映像 pallet 收取押金保存，这里最多按 `MaxClaimLength` 收取押金
+            Self::do_create_claim(sender.clone(), sender.clone(), claim_hash, len.min(T::MaxClaimLength::get()))?;
+            if retain {
+                T::Preimages::request(&claim_hash);
+                RetainedPreimages::<T>::insert(&claim_hash, ());
+            }
+
+            Self::deposit_event(Event::PreimageClaimCreated { who: sender, claim_hash, len, retained: retain });
+            Ok(().into())
+        }
+
+        /// 镜像的 NFT 在外部被转移后，把存证转给 NFT 的当前持有者，任何人都可以调用；
+        /// 与普通转移一样，冻结、争议中、挂单或拍卖中的存证不能同步
+        #[pallet::call_index(80)]
+        #[pallet::weight(T::WeightInfo::sync_claim_owner())]
+        pub fn sync_claim_owner(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            ensure_signed(origin)?;
+            let details = Self::live_claim(&claim_hash)?;
+            let holder = T::NftMirror::owner_of(&claim_hash).ok_or(Error::<T>::NftNotMirrored)?;
+            ensure!(holder != details.owner, Error::<T>::NftOwnerUnchanged);
+
+            Self::do_forced_transfer(claim_hash, details, holder)
+        }
+
+        /// 管理员设置兄弟平行链是否可信，只有可信的链之间才能互相转移存证
+        #[pallet::call_index(81)]
+        #[pallet::weight((T::WeightInfo::set_trusted_para(), DispatchClass::Operational))]
+        pub fn set_trusted_para(origin: OriginFor<T>, para_id: u32, trusted: bool) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            if trusted {
+                TrustedParas::<T>::insert(para_id, ());
+            } else {
+                TrustedParas::<T>::remove(para_id);
+            }
+
+            Self::deposit_event(Event::TrustedParaSet { para_id, trusted });
+            Ok(Pays::No.into())
+        }
+
+        /// 把存证转给可信兄弟平行链上的 `beneficiary`：删除本链的记录、退还押金，然后向目标链发送 XCM 登记存证；
+        /// 与普通转移的限制相同，XCM 在目标链上执行失败时存证不会自动恢复
+        #[pallet::call_index(82)]
+        #[pallet::weight(T::WeightInfo::transfer_claim_to_para())]
+        pub fn transfer_claim_to_para(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            para_id: u32,
+            beneficiary: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+            let details = Self::live_claim(&claim_hash)?;
+
+            ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+            ensure!(TrustedParas::<T>::contains_key(para_id), Error::<T>::UntrustedPara);
+            Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Transfer(beneficiary.clone()))?;
+            ensure!(details.status != ClaimStatus::Frozen, Error::<T>::ClaimFrozen);
+            ensure!(!Disputes::<T>::contains_key(&claim_hash), Error::<T>::ClaimDisputed);
+            ensure!(!Reports::<T>::contains_key(&claim_hash), Error::<T>::ClaimUnderReport);
+            Self::ensure_claim_movable(&claim_hash)?;
+            ensure!(!Listings::<T>::contains_key(&claim_hash), Error::<T>::ClaimListed);
+            ensure!(!Auctions::<T>::contains_key(&claim_hash), Error::<T>::ClaimInAuction);
+
+            let owner = details.owner.clone();
+            let removed = Self::do_revoke(claim_hash, details)?;
+
+            // 消息发出后无法撤回，发送放在最后，之前的任何失败都不会让存证同时留在两条链上；
+            // 目标链以相同的 pallet 索引部署本 pallet，直接编码本链的调用
+            let call: T::OverarchingCall =
+                Call::<T>::force_register_remote_claim { claim_hash, owner: beneficiary.clone() }.into();
+            let message = Xcm(vec![
+                Instruction::UnpaidExecution { weight_limit: WeightLimit::Unlimited, check_origin: None },
+                Instruction::Transact {
+                    origin_kind: OriginKind::Xcm,
+                    require_weight_at_most: T::WeightInfo::force_register_remote_claim(),
+                    call: call.encode().into(),
+                },
+            ]);
+            send_xcm::<T::XcmSender>(Location::new(1, [Junction::Parachain(para_id)]), message)
+                .map_err(|_| Error::<T>::XcmSendFailed)?;
+
+            Self::deposit_event(Event::ClaimSentToPara { who: owner, claim_hash, para_id, target: beneficiary });
+            Ok(Some(Self::revoke_weight(T::WeightInfo::transfer_claim_to_para(), 1, removed)).into())
+        }
+
+        /// 登记从可信兄弟平行链转入的存证，只能由 `RemoteOrigin` 调用；
+        /// 押金已在来源链上退还，转入的存证不保留押金
+        #[pallet::call_index(83)]
+        #[pallet::weight(T::WeightInfo::force_register_remote_claim())]
+        pub fn force_register_remote_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let location = T::RemoteOrigin::ensure_origin(origin)?;
+            let para_id = remote::sibling_para(&location)
+                .filter(|para_id| TrustedParas::<T>::contains_key(para_id))
+                .ok_or(Error::<T>::UntrustedPara)?;
+            Self::ensure_claim_available(&claim_hash)?;
+
+            Self::insert_claim(owner.clone(), owner.clone(), claim_hash, None)?;
+
+            Self::deposit_event(Event::RemoteClaimRegistered { para_id, who: owner, claim_hash });
+            Ok(().into())
+        }
+
+        /// 其他链上的应用通过 XCM 为内容哈希创建存证，所有者为 `XcmOrigin` 转换得到的本链账户，
+        /// 押金和创建费用从该账户支付，规则与 `create_claim_by_hash` 相同
+        #[pallet::call_index(84)]
+        #[pallet::weight(T::WeightInfo::create_claim_by_hash())]
+        pub fn create_claim_via_xcm(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            let owner = T::XcmOrigin::ensure_origin(origin)?;
+            Self::ensure_claim_available(&claim_hash)?;
+
+            Self::do_create_claim(owner.clone(), owner, claim_hash, T::MaxClaimLength::get())?;
+            Ok(().into())
+        }
+
+        /// 公证人提交原生代币以法币计价的单价，通常由其链下工作机在每个押金周期开始时提交
+        ///
+        /// 下一个周期开始时按最新的喂价重新计算基础押金。
+        #[pallet::call_index(85)]
+        #[pallet::weight(T::WeightInfo::report_price())]
+        pub fn report_price(origin: OriginFor<T>, price: FixedU128) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+            ensure!(Notaries::<T>::contains_key(&sender), Error::<T>::NotNotary);
+            ensure!(!price.is_zero(), Error::<T>::InvalidPrice);
+
+            let reported_at = frame_system::Pallet::<T>::block_number();
+            NativePrice::<T>::put(PriceReport { price, reporter: sender.clone(), reported_at });
+
+            Self::deposit_event(Event::PriceReported { who: sender, price });
+
+            Ok(())
+        }
+
+        /// 举报他人的虚假存证，保留 `ReportBond` 作为保证金，`evidence` 为链下证据的哈希
+        #[pallet::call_index(86)]
+        #[pallet::weight(T::WeightInfo::report_claim())]
+        pub fn report_claim(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            evidence: ClaimHashOf<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(details.owner != sender, Error::<T>::CannotReportOwnClaim);
+            ensure!(!Reports::<T>::contains_key(&claim_hash), Error::<T>::ReportAlreadyExist);
+
+            let bond = T::ReportBond::get();
+            T::Currency::reserve(&sender, bond)?;
+            Reports::<T>::insert(
+                &claim_hash,
+                FraudReport {
+                    reporter: sender.clone(),
+                    evidence,
+                    bond,
+                    reported_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::ClaimReported { who: sender, claim_hash, evidence });
+
+            Ok(())
+        }
+
+        /// 仲裁举报：成立时退还举报人的保证金，罚没存证押金并按 `ReporterReward` 分给举报人和 `OnSlash`，
+        /// 然后撤销存证；驳回时罚没举报人的保证金交给 `OnSlash`
+        ///
+        /// 存证已过期但尚未清理时，举报成立只退还举报人的保证金，存证留给过期清理删除。
+        #[pallet::call_index(87)]
+        #[pallet::weight((T::WeightInfo::settle_report(), DispatchClass::Operational))]
+        pub fn settle_report(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            uphold: bool
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_paused()?;
+            T::ArbitrationOrigin::ensure_origin(origin)?;
+
+            let report = Reports::<T>::take(&claim_hash).ok_or(Error::<T>::ReportNotExist)?;
+            if !uphold {
+                let (bond, _) = T::Currency::slash_reserved(&report.reporter, report.bond);
+                let amount = bond.peek();
+                T::OnSlash::on_unbalanced(bond);
+
+                Self::deposit_event(Event::ReportRejected { claim_hash, reporter: report.reporter, bond: amount });
+                return Ok(Pays::No.into());
+            }
+
+            T::Currency::unreserve(&report.reporter, report.bond);
+            let Ok(mut details) = Self::live_claim(&claim_hash) else {
+                Self::deposit_event(Event::ReportUpheld {
+                    claim_hash,
+                    reporter: report.reporter,
+                    slashed: Zero::zero(),
+                    reward: Zero::zero(),
+                });
+                return Ok(Pays::No.into());
+            };
+            let (slashed, _) = T::Currency::slash_reserved(&details.depositor, details.deposit);
+            let slashed_amount = slashed.peek();
+            let (reward, rest) = slashed.split(T::ReporterReward::get() * slashed_amount);
+            let reward_amount = reward.peek();
+            T::Currency::resolve_creating(&report.reporter, reward);
+            T::OnSlash::on_unbalanced(rest);
+
+            // 押金已被罚没，撤销时不再退还
+            details.deposit = Zero::zero();
+            let penalty = i32::try_from(T::FraudReputationPenalty::get()).unwrap_or(i32::MAX);
+            Self::adjust_reputation(&details.owner, -penalty);
+            Self::do_revoke(claim_hash, details)?;
+
+            Self::deposit_event(Event::ReportUpheld {
+                claim_hash,
+                reporter: report.reporter,
+                slashed: slashed_amount,
+                reward: reward_amount,
+            });
+
+            Ok(Pays::No.into())
+        }
+
+        /// 所有者向 `licensee` 授予存证的使用许可，`terms_hash` 为链下许可条款的哈希，许可在 `expiry` 区块到期；
+        /// 已有许可时更新条款和到期区块
+        #[pallet::call_index(88)]
+        #[pallet::weight(T::WeightInfo::grant_license())]
+        pub fn grant_license(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            licensee: T::AccountId,
+            terms_hash: ClaimHashOf<T>,
+            expiry: BlockNumberFor<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(expiry > now, Error::<T>::InvalidLicenseExpiry);
+
+            if !Licenses::<T>::contains_key(&claim_hash, &licensee) {
+                LicenseCount::<T>::try_mutate(&claim_hash, |count| -> DispatchResult {
+                    ensure!(*count < T::MaxLicenses::get(), Error::<T>::TooManyLicenses);
+                    *count += 1;
+                    Ok(())
+                })?;
+            }
+            Licenses::<T>::insert(&claim_hash, &licensee, License { terms_hash, expiry, granted_at: now });
+
+            Self::deposit_event(Event::LicenseGranted { claim_hash, licensee, terms_hash, expiry });
+
+            Ok(())
+        }
+
+        /// 所有者撤销 `licensee` 的使用许可，已到期的许可也可以撤销以腾出名额
+        #[pallet::call_index(89)]
+        #[pallet::weight(T::WeightInfo::revoke_license())]
+        pub fn revoke_license(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            licensee: T::AccountId
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+            ensure!(Licenses::<T>::contains_key(&claim_hash, &licensee), Error::<T>::LicenseNotExist);
+            Self::remove_license(&claim_hash, &licensee);
+
+            Self::deposit_event(Event::LicenseRevoked { claim_hash, licensee });
+
+            Ok(())
+        }
+
+        /// 所有者为已授予的许可设置付款计划：每 `period` 个区块支付 `amount`，替换原有的计划。
+        /// 被许可人调用 `settle_license` 支付第一期即接受计划，此前许可不生效
+        #[pallet::call_index(90)]
+        #[pallet::weight(T::WeightInfo::set_license_fee())]
+        pub fn set_license_fee(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            licensee: T::AccountId,
+            amount: BalanceOf<T>,
+            period: BlockNumberFor<T>
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(Self::is_owner_or_operator(&details.owner, &sender), Error::<T>::NotClaimOwner);
+            ensure!(Licenses::<T>::contains_key(&claim_hash, &licensee), Error::<T>::LicenseNotExist);
+            ensure!(!amount.is_zero() && !period.is_zero(), Error::<T>::InvalidLicenseFee);
+            LicenseFees::<T>::insert(&claim_hash, &licensee, LicenseFee { amount, period, paid_until: None });
+
+            Self::deposit_event(Event::LicenseFeeSet { claim_hash, licensee, amount, period });
+
+            Ok(())
+        }
+
+        /// 结算到期的许可费，被许可人或所有者都可以调用；付款从被许可人转给当前所有者，
+        /// 余额不足时许可被撤销。被许可人第一次结算即接受付款计划，之后每期到期时也会自动结算
+        #[pallet::call_index(91)]
+        #[pallet::weight(T::WeightInfo::settle_license())]
+        pub fn settle_license(
+            origin: OriginFor<T>,
+            claim_hash: ClaimHashOf<T>,
+            licensee: T::AccountId
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let sender = ensure_signed(origin)?;
+
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(
+                sender == licensee || Self::is_owner_or_operator(&details.owner, &sender),
+                Error::<T>::NotLicenseParty
+            );
+            let mut fee = LicenseFees::<T>::get(&claim_hash, &licensee).ok_or(Error::<T>::LicenseFeeNotExist)?;
+            if fee.paid_until.is_none() {
+                ensure!(sender == licensee, Error::<T>::LicenseFeeNotAccepted);
+                fee.paid_until = Some(frame_system::Pallet::<T>::block_number());
+            }
+            Self::do_settle_license(&claim_hash, &licensee, &details.owner, fee);
+
+            Ok(())
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        /// 只接受签名有效、签名账户在免费名单中且存证尚不存在的载荷
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let Call::submit_claim_unsigned { payload, signature } = call else {
+                return InvalidTransaction::Call.into();
+            };
+            if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+                return InvalidTransaction::BadProof.into();
+            }
+            if !FeeExempt::<T>::contains_key(payload.public.clone().into_account()) {
+                return InvalidTransaction::BadSigner.into();
+            }
+            let now = frame_system::Pallet::<T>::block_number();
+            if payload.block_number > now {
+                return InvalidTransaction::Future.into();
+            }
+            if Proofs::<T>::get(&payload.claim_hash).map_or(false, |details| !details.is_expired(now)) {
+                return InvalidTransaction::Stale.into();
+            }
+
+            // 与 `DeduplicateClaims` 使用不同的前缀，签名和无签名的登记互不影响
+            ValidTransaction::with_tag_prefix("PoeOffchain")
+                .priority(T::UnsignedPriority::get())
+                .and_provides(payload.claim_hash)
+                .longevity(5)
+                .propagate(true)
+                .build()
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// 计算存证内容的哈希，链下工具对原始内容做同样的哈希即可得到存证的键
+        pub fn claim_hash(claim: &[u8]) -> ClaimHashOf<T> {
+            T::ClaimHasher::hash(claim)
+        }
+
+        /// 提交-揭示流程使用的承诺：`hash(存证内容 ++ 盐)`
+        pub fn commitment_of(claim: &[u8], salt: &[u8; 32]) -> ClaimHashOf<T> {
+            T::ClaimHasher::hash(&[claim, &salt[..]].concat())
+        }
+
+        /// 列出某个账户当前拥有的全部存证哈希
+        pub fn claims_of(owner: &T::AccountId) -> Vec<ClaimHashOf<T>> {
+            OwnerClaims::<T>::iter_key_prefix(owner).collect()
+        }
+
+        /// 发出事件，并以事件涉及的存证哈希作为索引主题，轻客户端可以通过 `System::EventTopics`
+        /// 只订阅单个存证的生命周期
+        pub(super) fn deposit_event(event: Event<T>) {
+            let topics = event.topics();
+            let event = <T as Config>::RuntimeEvent::from(event);
+            frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+        }
+
+        /// `licensee` 是否持有存证未到期的使用许可，付费许可还要求当前区块已付款
+        pub fn has_license(claim_hash: &ClaimHashOf<T>, licensee: &T::AccountId) -> bool {
+            let now = frame_system::Pallet::<T>::block_number();
+            let licensed = Licenses::<T>::get(claim_hash, licensee).map_or(false, |license| license.expiry > now);
+            let paid = LicenseFees::<T>::get(claim_hash, licensee)
+                .map_or(true, |fee| fee.paid_until.map_or(false, |paid_until| paid_until > now));
+            licensed && paid && Self::live_claim(claim_hash).is_ok()
+        }
+
+        /// 存证是否存在、未过期且已度过挑战期
+        pub fn is_confirmed(claim_hash: &ClaimHashOf<T>) -> bool {
+            Self::live_claim(claim_hash).is_ok() && !ProvisionalClaims::<T>::contains_key(claim_hash)
+        }
+
+        /// 未过期存证的所有者和创建区块号，供 Runtime API 使用
+        pub fn claim_owner(claim_hash: &ClaimHashOf<T>) -> Option<(T::AccountId, BlockNumberFor<T>)> {
+            Self::live_claim(claim_hash).ok().map(|details| (details.owner, details.created_at))
+        }
+
+        /// 存证的历史所有者及其转出时的区块号，供 Runtime API 使用
+        pub fn ownership_history(claim_hash: &ClaimHashOf<T>) -> Vec<(T::AccountId, BlockNumberFor<T>)> {
+            OwnershipHistory::<T>::get(claim_hash).into_inner()
+        }
+
+        /// 存证在区块 `block` 结束时的所有者，供 Runtime API 使用
+        ///
+        /// 历史记录中 `(账户, 区块号)` 表示该账户持有存证直到在该区块转出。区块早于存证创建、
+        /// 存证在该区块已过期，或者查询的区块早于仍保留的历史记录（最早的记录已被丢弃）时返回 `None`
+        pub fn owner_at(claim_hash: &ClaimHashOf<T>, block: BlockNumberFor<T>) -> Option<T::AccountId> {
+            let details = Proofs::<T>::get(claim_hash)?;
+            if block < details.created_at || details.is_expired(block) {
+                return None;
+            }
+            let history = OwnershipHistory::<T>::get(claim_hash);
+            match history.iter().position(|(_, transferred_at)| *transferred_at > block) {
+                // 历史已满时最早的记录可能被丢弃，无法确定第一条记录的账户从何时开始持有
+                Some(0) if history.is_full() => None,
+                Some(index) => Some(history[index].0.clone()),
+                None => Some(details.owner),
+            }
+        }
+
+        /// 获取未过期的存证，不存在或已过期都返回 `ClaimNotExist`
+        pub fn live_claim(claim_hash: &ClaimHashOf<T>) -> Result<ClaimDetails<T>, Error<T>> {
+            let now = frame_system::Pallet::<T>::block_number();
+            Proofs::<T>::get(claim_hash)
+                .filter(|details| !details.is_expired(now))
+                .ok_or(Error::<T>::ClaimNotExist)
+        }
+
+        /// pallet 暂停时返回 `PalletPaused`
+        fn ensure_not_paused() -> DispatchResult {
+            ensure!(!Paused::<T>::get(), Error::<T>::PalletPaused);
+            Ok(())
+        }
+
+        /// 存证内容以他人未失效的命名空间为前缀时返回 `NamespaceReserved`
+        ///
+        /// 只按内容检查，`create_claim_by_hash` 等只提供哈希的方式不受命名空间限制。
+        pub fn ensure_namespace_allowed(who: &T::AccountId, claim: &[u8]) -> DispatchResult {
+            let now = frame_system::Pallet::<T>::block_number();
+            let max_len = claim.len().min(T::MaxNamespaceLength::get() as usize);
+            for len in 1..=max_len {
+                let prefix = NamespaceOf::<T>::truncate_from(claim[..len].to_vec());
+                if let Some(namespace) = Namespaces::<T>::get(&prefix) {
+                    ensure!(namespace.owner == *who || namespace.is_expired(now), Error::<T>::NamespaceReserved);
+                }
+            }
+            Ok(())
+        }
+
+        /// 存证长度超过 `CurrentMaxLength` 时返回 `ClaimLengthTooLarge`
+        fn ensure_claim_length(claim: &[u8]) -> DispatchResult {
+            ensure!(claim.len() <= CurrentMaxLength::<T>::get() as usize, Error::<T>::ClaimLengthTooLarge);
+            Ok(())
+        }
+
+        /// 开启 `ValidateContentIds` 且存证内容不是合法的 CID 时返回 `InvalidContentId`
+        fn ensure_content_id(claim: &[u8]) -> DispatchResult {
+            ensure!(!T::ValidateContentIds::get() || cid::is_valid(claim), Error::<T>::InvalidContentId);
+            Ok(())
+        }
+
+        /// 账户被禁止时返回 `AccountBanned`
+        fn ensure_not_banned(who: &T::AccountId) -> DispatchResult {
+            ensure!(!BannedAccounts::<T>::contains_key(who), Error::<T>::AccountBanned);
+            Ok(())
+        }
+
+        /// 存证处于托管、归属期或被抵押时返回相应的错误，这些存证不能转移、撤销或挂单
+        fn ensure_claim_movable(claim_hash: &ClaimHashOf<T>) -> DispatchResult {
+            ensure!(!Escrows::<T>::contains_key(claim_hash), Error::<T>::ClaimInEscrow);
+            Self::ensure_vested(claim_hash)?;
+            ensure!(!CollateralLocks::<T>::contains_key(claim_hash), Error::<T>::ClaimLocked);
+            Ok(())
+        }
+
+        /// `who` 是否可以代表 `owner` 管理存证：本人或被授权的操作员
+        pub fn is_owner_or_operator(owner: &T::AccountId, who: &T::AccountId) -> bool {
+            owner == who || Operators::<T>::contains_key(owner, who)
+        }
+
+        /// 不受转移冷却期和归属期限制的转移，用于管理员、仲裁、账户恢复和 NFT 镜像同步，其他检查与 `do_transfer` 相同
+        fn do_forced_transfer(
+            claim_hash: ClaimHashOf<T>,
+            mut details: ClaimDetails<T>,
+            target: T::AccountId,
+        ) -> DispatchResult {
+            details.last_transfer = None;
+            Vestings::<T>::remove(&claim_hash);
+            Self::do_transfer(claim_hash, details, target)
+        }
+
+        /// 把存证转给 `target`：押金随之转移并保持保留状态，同时更新反向索引、清除转移邀请
+        ///
+        /// 调用者负责检查权限，创建区块号保持不变；冻结、处于争议或举报中、归属期内、作为抵押品锁定、挂单出售或拍卖中的存证不能转移，
+        /// 也不能转给被禁止的账户；距上次转移不足 `TransferCooldown` 个区块时不能再次转移。
+        pub(crate) fn do_transfer(
+            claim_hash: ClaimHashOf<T>,
+            mut details: ClaimDetails<T>,
+            target: T::AccountId,
+        ) -> DispatchResult {
+            ensure!(details.status != ClaimStatus::Frozen, Error::<T>::ClaimFrozen);
+            ensure!(!Disputes::<T>::contains_key(&claim_hash), Error::<T>::ClaimDisputed);
+            ensure!(!Reports::<T>::contains_key(&claim_hash), Error::<T>::ClaimUnderReport);
+            Self::ensure_claim_movable(&claim_hash)?;
+            Self::ensure_not_banned(&target)?;
+            ensure!(!Listings::<T>::contains_key(&claim_hash), Error::<T>::ClaimListed);
+            ensure!(!Auctions::<T>::contains_key(&claim_hash), Error::<T>::ClaimInAuction);
+            ensure!(!ClaimBundle::<T>::contains_key(&claim_hash), Error::<T>::ClaimInBundle);
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(last_transfer) = details.last_transfer {
+                ensure!(now >= last_transfer.saturating_add(T::TransferCooldown::get()), Error::<T>::CooldownActive);
+            }
+            details.last_transfer = Some(now);
+
+            let from = details.owner.clone();
+            if from != target {
+                Self::inc_claim_count(&target)?;
+                ClaimCountOf::<T>::mutate(&from, |count| *count = count.saturating_sub(1));
+            }
+            // 所有者自己支付的押金随存证转给新的所有者；代付的押金仍由代付方保留，存证删除时退还给代付方
+            if details.depositor == from {
+                T::Currency::repatriate_reserved(&from, &target, details.deposit, BalanceStatus::Reserved)?;
+                details.depositor = target.clone();
+            }
+
+            // 单独转出的存证离开原集合；整体转移集合时集合所有者已先行更新，成员关系保持不变
+            if let Some(collection_id) = ClaimCollection::<T>::get(&claim_hash) {
+                if Collections::<T>::get(collection_id).map_or(true, |collection| collection.owner != target) {
+                    Self::remove_from_collection(collection_id, &claim_hash);
+                }
+            }
+
+            OwnershipHistory::<T>::mutate(&claim_hash, |history| {
+                if history.is_full() && !history.is_empty() {
+                    history.remove(0);
+                }
+                let _ = history.try_push((from.clone(), now));
+            });
+
+            // 共同所有关系、继承设置、预约的转移、等待中的撤销和已结束的归属期只对转移前的所有者有效
+            Self::clear_co_owners(&claim_hash);
+            Inheritors::<T>::remove(&claim_hash);
+            Vestings::<T>::remove(&claim_hash);
+            Self::unschedule_transfer(&claim_hash);
+            PendingRevocations::<T>::remove(&claim_hash);
+
+            details.owner = target.clone();
+            Proofs::<T>::insert(&claim_hash, details);
+            OwnerClaims::<T>::remove(&from, &claim_hash);
+            OwnerClaims::<T>::insert(&target, &claim_hash, ());
+            PendingTransfers::<T>::remove(&claim_hash);
+            Approvals::<T>::remove(&claim_hash);
+            T::NftMirror::transfer(&claim_hash, &target)?;
+
+            BlockStats::<T>::mutate(|stats| stats.transferred = stats.transferred.saturating_add(1));
+            Self::deposit_event(Event::ClaimTransfered {
+                who: from,
+                target: target.clone(),
+                claim_hash,
+                block: now,
+            });
+            Self::notify_watchers(&claim_hash, Some(target));
+
+            Ok(())
+        }
+
+        /// 存证有关注者时发出 `WatchedClaimChanged` 事件，`owner` 为变更后的所有者
+        fn notify_watchers(claim_hash: &ClaimHashOf<T>, owner: Option<T::AccountId>) {
+            let watchers = Watchers::<T>::get(claim_hash);
+            if !watchers.is_empty() {
+                Self::deposit_event(Event::WatchedClaimChanged { claim_hash: *claim_hash, owner, watchers });
+            }
+        }
+
+        /// 账户拥有的存证数量加一，超过 `MaxClaimsPerAccount` 时返回 `TooManyClaims`
+        fn inc_claim_count(who: &T::AccountId) -> DispatchResult {
+            ClaimCountOf::<T>::try_mutate(who, |count| {
+                ensure!(*count < T::MaxClaimsPerAccount::get(), Error::<T>::TooManyClaims);
+                *count += 1;
+                Ok(())
+            })
+        }
+
+        /// 存证总数加一，超过 `MaxTotalClaims` 时返回 `TooManyClaims`
+        fn inc_total_claims() -> DispatchResult {
+            TotalClaims::<T>::try_mutate(|total| {
+                ensure!(*total < T::MaxTotalClaims::get(), Error::<T>::TooManyClaims);
+                *total += 1;
+                Ok(())
+            })
+        }
+
+        /// 删除存证的全部记录并退还押金，返回清理的背书、许可、子存证链接和共同所有者的数量
+        fn remove_claim(claim_hash: &ClaimHashOf<T>, details: &ClaimDetails<T>) -> u32 {
+            Proofs::<T>::remove(claim_hash);
+            OwnerClaims::<T>::remove(&details.owner, claim_hash);
+            PendingTransfers::<T>::remove(claim_hash);
+            Approvals::<T>::remove(claim_hash);
+            ClaimCountOf::<T>::mutate(&details.owner, |count| *count = count.saturating_sub(1));
+            TotalClaims::<T>::mutate(|total| *total = total.saturating_sub(1));
+            T::Currency::unreserve(&details.depositor, details.deposit);
+            // 存证已不存在，争议随之结束，保证金退还给发起方
+            if let Some(dispute) = Disputes::<T>::take(claim_hash) {
+                T::Currency::unreserve(&dispute.challenger, dispute.bond);
+            }
+            // 举报同理，例如存证在仲裁前过期
+            if let Some(report) = Reports::<T>::take(claim_hash) {
+                T::Currency::unreserve(&report.reporter, report.bond);
+            }
+            // 尚未放款的托管出售取消，货款和争议保证金退还给买家
+            if let Some(escrow) = Escrows::<T>::take(claim_hash) {
+                let bond = escrow.dispute.map_or(Zero::zero(), |(_, bond)| bond);
+                T::Currency::unreserve(&escrow.buyer, escrow.price.saturating_add(bond));
+            }
+            ProvisionalClaims::<T>::remove(claim_hash);
+            // 确认时获得的声誉和确认数量随存证删除扣回，即使存证已转给其他账户；已颁发的徽章保留
+            if let Some(credited) = ConfirmationCredits::<T>::take(claim_hash) {
+                Self::adjust_reputation(&credited, -1);
+                ConfirmedClaimCount::<T>::mutate(&credited, |count| *count = count.saturating_sub(1));
+            }
+            Notarizations::<T>::remove(claim_hash);
+            OwnershipHistory::<T>::remove(claim_hash);
+            // 断开版本链中指向该存证的链接，双方都可以重新建立版本关系
+            if let Some(previous) = Supersedes::<T>::take(claim_hash) {
+                SupersededBy::<T>::remove(previous);
+            }
+            if let Some(next) = SupersededBy::<T>::take(claim_hash) {
+                Supersedes::<T>::remove(next);
+            }
+            // 从父存证的子存证列表中移除；作为父存证被删除时，子存证成为独立的存证
+            if let Some(parent) = ParentOf::<T>::take(claim_hash) {
+                Children::<T>::mutate(parent, |children| children.retain(|child| child != claim_hash));
+            }
+            let children = Children::<T>::take(claim_hash);
+            let mut removed = children.len() as u32;
+            for child in children {
+                ParentOf::<T>::remove(child);
+            }
+            if let Some(collection_id) = ClaimCollection::<T>::get(claim_hash) {
+                Self::remove_from_collection(collection_id, claim_hash);
+            }
+            removed.saturating_accrue(Self::clear_co_owners(claim_hash));
+            RevocationGuards::<T>::remove(claim_hash);
+            RevocationApprovals::<T>::remove(claim_hash);
+            Inheritors::<T>::remove(claim_hash);
+            Self::unschedule_transfer(claim_hash);
+            PendingRevocations::<T>::remove(claim_hash);
+            Self::withdraw_from_sale(claim_hash);
+            Royalties::<T>::remove(claim_hash);
+            ClaimAuthors::<T>::remove(claim_hash);
+            Self::notify_watchers(claim_hash, None);
+            Watchers::<T>::remove(claim_hash);
+            Annotations::<T>::remove(claim_hash);
+            Vestings::<T>::remove(claim_hash);
+            CollateralLocks::<T>::remove(claim_hash);
+            Self::unindex_expiry(claim_hash, details.expires_at);
+            T::NftMirror::burn(claim_hash);
+            if RetainedPreimages::<T>::take(claim_hash).is_some() {
+                T::Preimages::unrequest(claim_hash);
+            }
+            // 背书针对的是这一次登记，存证删除后一并清除
+            let attestations = AttestationCount::<T>::take(claim_hash);
+            if attestations > 0 {
+                let _ = Attestations::<T>::clear_prefix(claim_hash, T::MaxAttestations::get(), None);
+            }
+            // 许可同理，所有权登记不存在后许可失去依据
+            // 许可和付款计划都不超过 `MaxLicenses` 条，自动结算队列中残留的条目在执行时跳过
+            let licenses = LicenseCount::<T>::take(claim_hash);
+            if licenses > 0 {
+                let _ = Licenses::<T>::clear_prefix(claim_hash, T::MaxLicenses::get(), None);
+                let _ = LicenseFees::<T>::clear_prefix(claim_hash, T::MaxLicenses::get(), None);
+            }
+            removed.saturating_add(attestations).saturating_add(licenses.saturating_mul(2))
+        }
+
+        /// 存证有共同所有者时，要求每个共同所有者都已批准 `action`
+        ///
+        /// 只用于所有者发起的撤销和转移；管理员强制操作、仲裁和提交-揭示的抢先撤销不受限制。
+        fn ensure_co_owners_approved(claim_hash: &ClaimHashOf<T>, action: &CoOwnerAction<T::AccountId>) -> DispatchResult {
+            for co_owner in CoOwners::<T>::get(claim_hash) {
+                ensure!(
+                    CoOwnerApprovals::<T>::get(claim_hash, &co_owner).as_ref() == Some(action),
+                    Error::<T>::CoOwnerApprovalMissing
+                );
+            }
+            Ok(())
+        }
+
+        /// 存证设置了撤销门限时，要求批准数量达到门限
+        fn ensure_revocation_approved(claim_hash: &ClaimHashOf<T>) -> DispatchResult {
+            if let Some(guard) = RevocationGuards::<T>::get(claim_hash) {
+                ensure!(
+                    RevocationApprovals::<T>::decode_len(claim_hash).unwrap_or(0) >= guard.threshold as usize,
+                    Error::<T>::RevocationNotApproved
+                );
+            }
+            Ok(())
+        }
+
+        /// 取消存证预约的转移，返回被取消的预约
+        fn unschedule_transfer(
+            claim_hash: &ClaimHashOf<T>,
+        ) -> Option<ScheduledTransfer<T::AccountId, BlockNumberFor<T>>> {
+            let scheduled = ScheduledTransfers::<T>::take(claim_hash)?;
+            TransferQueue::<T>::mutate(scheduled.at, |queue| queue.retain(|queued| queued != claim_hash));
+            Some(scheduled)
+        }
+
+        /// 执行预约在 `now` 的存证转移，返回处理的预约数量
+        ///
+        /// 每个转移单独回滚：失败的转移触发 `ScheduledTransferFailed`，不影响同一区块的其他转移。
+        pub(crate) fn execute_scheduled_transfers(now: BlockNumberFor<T>) -> u32 {
+            let queue = TransferQueue::<T>::take(now);
+            let count = queue.len() as u32;
+            for claim_hash in queue {
+                let Some(scheduled) = ScheduledTransfers::<T>::take(&claim_hash) else {
+                    continue;
+                };
+                let result = with_storage_layer(|| {
+                    let details = Self::live_claim(&claim_hash)?;
+                    ensure!(details.owner == scheduled.from, Error::<T>::NotClaimOwner);
+                    Self::ensure_co_owners_approved(&claim_hash, &CoOwnerAction::Transfer(scheduled.to.clone()))?;
+                    Self::do_transfer(claim_hash, details, scheduled.to.clone())
+                });
+                if let Err(error) = result {
+                    Self::deposit_event(Event::ScheduledTransferFailed { claim_hash, error });
+                }
+            }
+            count
+        }
+
+        /// 确认本区块挑战期结束的存证，返回队列中的存证数量
+        ///
+        /// 仍有未仲裁争议的存证保持待确认状态，由 `resolve_dispute` 在仲裁后确认；已删除的存证，
+        /// 以及过期后重新创建、挑战期重新计算的存证直接跳过。
+        pub(crate) fn confirm_provisional_claims(now: BlockNumberFor<T>) -> u32 {
+            let queue = ConfirmationQueue::<T>::take(now);
+            let count = queue.len() as u32;
+            for claim_hash in queue {
+                if ProvisionalClaims::<T>::get(&claim_hash) != Some(now) || Disputes::<T>::contains_key(&claim_hash) {
+                    continue;
+                }
+                Self::confirm_claim(&claim_hash);
+            }
+            count
+        }
+
+        /// 存证处于归属期内时返回 `ClaimVesting`
+        fn ensure_vested(claim_hash: &ClaimHashOf<T>) -> DispatchResult {
+            let now = frame_system::Pallet::<T>::block_number();
+            let vested = Vestings::<T>::get(claim_hash).map_or(true, |vesting| now >= vesting.unlocks_at);
+            ensure!(vested, Error::<T>::ClaimVesting);
+            Ok(())
+        }
+
+        /// 由所有者或其操作员 `who` 把存证锁定为 `locker` 的抵押品，归属期内的存证不能锁定
+        fn do_lock_collateral(who: &T::AccountId, claim_hash: ClaimHashOf<T>, locker: T::AccountId) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let details = Self::live_claim(&claim_hash)?;
+            ensure!(Self::is_owner_or_operator(&details.owner, who), Error::<T>::NotClaimOwner);
+            ensure!(!CollateralLocks::<T>::contains_key(&claim_hash), Error::<T>::ClaimLocked);
+            Self::ensure_vested(&claim_hash)?;
+
+            CollateralLocks::<T>::insert(&claim_hash, &locker);
+            Self::deposit_event(Event::CollateralLocked { who: details.owner, claim_hash, locker });
+            Ok(())
+        }
+
+        /// 登记的锁定方 `locker` 解除抵押锁定
+        fn do_unlock_collateral(locker: &T::AccountId, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let registered = CollateralLocks::<T>::get(&claim_hash).ok_or(Error::<T>::NotLocked)?;
+            ensure!(&registered == locker, Error::<T>::NotLocker);
+
+            CollateralLocks::<T>::remove(&claim_hash);
+            Self::deposit_event(Event::CollateralUnlocked { claim_hash, locker: registered });
+            Ok(())
+        }
+
+        /// 把存证记入 `expires_at` 区块的过期索引
+        fn index_expiry(claim_hash: &ClaimHashOf<T>, expires_at: BlockNumberFor<T>) -> DispatchResult {
+            ExpiringAt::<T>::try_mutate(expires_at, |claims| claims.try_push(*claim_hash))
+                .map_err(|_| Error::<T>::TooManyExpiring.into())
+        }
+
+        /// 把存证从 `expires_at` 区块的过期索引中移除
+        fn unindex_expiry(claim_hash: &ClaimHashOf<T>, expires_at: BlockNumberFor<T>) {
+            ExpiringAt::<T>::mutate_exists(expires_at, |maybe_claims| {
+                if let Some(claims) = maybe_claims {
+                    claims.retain(|claim| claim != claim_hash);
+                    if claims.is_empty() {
+                        *maybe_claims = None;
+                    }
+                }
+            });
+        }
+
+        /// 本区块 `on_finalize` 中需要检查的存证数量，即 `WarningPeriod` 个区块后过期的索引条目数
+        fn expiry_warnings_due(now: BlockNumberFor<T>) -> u32 {
+            let period = T::WarningPeriod::get();
+            if period.is_zero() {
+                return 0;
+            }
+            ExpiringAt::<T>::decode_len(now.saturating_add(period)).unwrap_or(0) as u32
+        }
+
+        /// 为 `WarningPeriod` 个区块后过期的存证发出 `ClaimExpiringSoon` 事件
+        ///
+        /// 每个区块只读取一个索引条目，数量不超过 `MaxExpiringPerBlock`；已删除或续期的存证跳过。
+        fn warn_expiring_claims(now: BlockNumberFor<T>) {
+            let period = T::WarningPeriod::get();
+            if period.is_zero() {
+                return;
+            }
+            let expires_at = now.saturating_add(period);
+            for claim_hash in ExpiringAt::<T>::get(expires_at) {
+                let Some(details) = Proofs::<T>::get(&claim_hash).filter(|details| details.expires_at == expires_at) else {
+                    continue;
+                };
+                Self::deposit_event(Event::ClaimExpiringSoon { who: details.owner, claim_hash, expires_at });
+            }
+        }
+
+        /// 提醒 `count` 个存证消耗的权重：`on_initialize` 中读取条目数量，`on_finalize` 中读取索引和每个存证
+        fn expiry_warnings_weight(count: u32) -> Weight {
+            if T::WarningPeriod::get().is_zero() {
+                return Weight::zero();
+            }
+            T::DbWeight::get().reads(2 + count as u64)
+        }
+
+        /// 确认 `count` 个存证消耗的权重：队列读写各一次，每个存证读取挑战期、争议和存证，删除挑战期，
+        /// 更新所有者的声誉和确认数量，并可能颁发徽章
+        fn confirmations_weight(count: u32) -> Weight {
+            T::DbWeight::get()
+                .reads_writes(1, 1)
+                .saturating_add(T::DbWeight::get().reads_writes(6, 5).saturating_mul(count as u64))
+        }
+
+        /// 删除许可及其付款计划
+        fn remove_license(claim_hash: &ClaimHashOf<T>, licensee: &T::AccountId) {
+            Licenses::<T>::remove(claim_hash, licensee);
+            LicenseFees::<T>::remove(claim_hash, licensee);
+            LicenseCount::<T>::mutate(claim_hash, |count| *count = count.saturating_sub(1));
+        }
+
+        /// 一次性支付 `fee.paid_until` 至当前区块之间到期的各期许可费，付款不超过许可的到期区块。
+        /// 付款成功后在新的截止区块排队自动结算，队列已满时等待任意一方手动结算；付款失败时撤销许可
+        fn do_settle_license(
+            claim_hash: &ClaimHashOf<T>,
+            licensee: &T::AccountId,
+            owner: &T::AccountId,
+            mut fee: LicenseFee<BalanceOf<T>, BlockNumberFor<T>>,
+        ) {
+            let now = frame_system::Pallet::<T>::block_number();
+            let (Some(paid_until), Some(license)) = (fee.paid_until, Licenses::<T>::get(claim_hash, licensee)) else {
+                return;
+            };
+            if paid_until > now || paid_until >= license.expiry {
+                LicenseFees::<T>::insert(claim_hash, licensee, fee);
+                return;
+            }
+
+            // 覆盖当前区块所需的期数：截止区块本身已到期，因此至少一期；最后一期覆盖到期区块时不再多收
+            let remaining = license.expiry.saturating_sub(paid_until).saturating_add(fee.period) - One::one();
+            let periods = (now.saturating_sub(paid_until) / fee.period + One::one()).min(remaining / fee.period);
+            let new_paid_until = paid_until.saturating_add(fee.period.saturating_mul(periods));
+            let due = fee.amount.saturating_mul(periods.saturated_into::<u32>().into());
+            if T::Currency::transfer(licensee, owner, due, ExistenceRequirement::KeepAlive).is_err() {
+                Self::remove_license(claim_hash, licensee);
+                Self::deposit_event(Event::LicenseFeeMissed {
+                    claim_hash: *claim_hash,
+                    licensee: licensee.clone(),
+                    due,
+                });
+                return;
+            }
+
+            fee.paid_until = Some(new_paid_until);
+            LicenseFees::<T>::insert(claim_hash, licensee, fee);
+            if new_paid_until < license.expiry {
+                let _ = LicenseFeeQueue::<T>::try_mutate(new_paid_until, |queue| {
+                    queue.try_push((*claim_hash, licensee.clone()))
+                });
+            }
+            Self::deposit_event(Event::LicenseFeePaid {
+                claim_hash: *claim_hash,
+                licensee: licensee.clone(),
+                amount: due,
+                paid_until: new_paid_until,
+            });
+        }
+
+        /// 自动结算在 `now` 到期的许可费，返回队列中的许可数量
+        ///
+        /// 许可已撤销、付款计划被替换或已经手动结算过的条目直接跳过。
+        pub(crate) fn settle_license_fees(now: BlockNumberFor<T>) -> u32 {
+            let queue = LicenseFeeQueue::<T>::take(now);
+            let count = queue.len() as u32;
+            for (claim_hash, licensee) in queue {
+                let Some(fee) = LicenseFees::<T>::get(&claim_hash, &licensee) else {
+                    continue;
+                };
+                let Ok(details) = Self::live_claim(&claim_hash) else {
+                    continue;
+                };
+                if fee.paid_until == Some(now) {
+                    Self::do_settle_license(&claim_hash, &licensee, &details.owner, fee);
+                }
+            }
+            count
+        }
+
+        /// 自动结算 `count` 个许可费消耗的权重：队列读写各一次，每个许可读取付款计划、许可和存证，
+        /// 转账读写双方账户，并更新付款计划和下一期的队列（或撤销许可时删除许可、付款计划并更新许可计数）
+        fn license_fees_weight(count: u32) -> Weight {
+            T::DbWeight::get()
+                .reads_writes(1, 1)
+                .saturating_add(T::DbWeight::get().reads_writes(7, 5).saturating_mul(count as u64))
+        }
+
+        /// 挑战期已经结束的待确认存证立即确认
+        fn confirm_if_due(claim_hash: &ClaimHashOf<T>) {
+            let now = frame_system::Pallet::<T>::block_number();
+            if ProvisionalClaims::<T>::get(claim_hash).map_or(false, |confirm_at| confirm_at <= now) {
+                Self::confirm_claim(claim_hash);
+            }
+        }
+
+        fn confirm_claim(claim_hash: &ClaimHashOf<T>) {
+            ProvisionalClaims::<T>::remove(claim_hash);
+            if let Some(details) = Proofs::<T>::get(claim_hash) {
+                Self::note_confirmed(claim_hash, &details.owner);
+                Self::deposit_event(Event::ClaimConfirmed { who: details.owner, claim_hash: *claim_hash });
+            }
+        }
+
+        /// 存证经过挑战期确认后所有者的声誉加 1 并累计确认数量，达到 `BadgeThreshold` 且还没有徽章时颁发徽章
+        fn note_confirmed(claim_hash: &ClaimHashOf<T>, owner: &T::AccountId) {
+            Self::adjust_reputation(owner, 1);
+            ConfirmationCredits::<T>::insert(claim_hash, owner);
+            let confirmed = ConfirmedClaimCount::<T>::mutate(owner, |count| {
+                *count = count.saturating_add(1);
+                *count
+            });
+            let threshold = T::BadgeThreshold::get();
+            if threshold == 0 || confirmed < threshold || Badges::<T>::contains_key(owner) {
+                return;
+            }
+            let minted_at = frame_system::Pallet::<T>::block_number();
+            Badges::<T>::insert(owner, Badge { minted_at });
+            Self::deposit_event(Event::BadgeMinted { who: owner.clone(), confirmed });
+        }
+
+        /// 调整账户的声誉，结果饱和在 `i32` 的范围内
+        fn adjust_reputation(who: &T::AccountId, delta: i32) {
+            Reputation::<T>::mutate(who, |reputation| *reputation = reputation.saturating_add(delta));
+        }
+
+        /// 执行 `executed` 个预约转移消耗的权重：队列读写各一次，每个转移按 `do_transfer` 的最坏情况计算
+        fn scheduled_transfers_weight(executed: u32) -> Weight {
+            let per_transfer = T::DbWeight::get()
+                .reads_writes(18 + T::MaxCoOwners::get() as u64, 19 + T::MaxCoOwners::get() as u64);
+            T::DbWeight::get()
+                .reads_writes(1, 1)
+                .saturating_add(per_transfer.saturating_mul(executed as u64))
+        }
+
+        /// 支付存证的成交价：`SaleFee` 部分销毁，版税支付给受益人，其余支付给卖家
+        ///
+        /// 版税最多为扣除费用后的全部货款，买家支付的总额始终等于 `price`。
+        fn pay_sale(
+            claim_hash: &ClaimHashOf<T>,
+            buyer: &T::AccountId,
+            seller: &T::AccountId,
+            price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let fee = T::SaleFee::get() * price;
+            let mut proceeds = price.saturating_sub(fee);
+            if let Some(royalty) = Royalties::<T>::get(claim_hash) {
+                let amount = (royalty.rate * price).min(proceeds);
+                if !amount.is_zero() {
+                    T::Currency::transfer(buyer, &royalty.beneficiary, amount, ExistenceRequirement::KeepAlive)?;
+                    proceeds = proceeds.saturating_sub(amount);
+                    Self::deposit_event(Event::RoyaltyPaid {
+                        claim_hash: *claim_hash,
+                        beneficiary: royalty.beneficiary,
+                        amount,
+                    });
+                }
+            }
+            T::Currency::transfer(buyer, seller, proceeds, ExistenceRequirement::KeepAlive)?;
+            if !fee.is_zero() {
+                let _ = T::Currency::withdraw(buyer, fee, WithdrawReasons::FEE, ExistenceRequirement::KeepAlive)?;
+            }
+            Ok(())
+        }
+
+        /// 取消存证的拍卖并退还当前最高出价
+        fn cancel_auction(claim_hash: &ClaimHashOf<T>) {
+            if let Some(auction) = Auctions::<T>::take(claim_hash) {
+                AuctionEnds::<T>::mutate(auction.end, |ends| ends.retain(|ending| ending != claim_hash));
+                if let Some((bidder, amount)) = auction.best_bid {
+                    T::Currency::unreserve(&bidder, amount);
+                }
+            }
+        }
+
+        /// 取消存证的出售挂单、拍卖和打包出售
+        fn withdraw_from_sale(claim_hash: &ClaimHashOf<T>) {
+            Listings::<T>::remove(claim_hash);
+            Self::cancel_auction(claim_hash);
+            if let Some(bundle_id) = ClaimBundle::<T>::get(claim_hash) {
+                Self::remove_from_bundle(bundle_id, claim_hash);
+            }
+        }
+
+        /// 结算在 `now` 结束的拍卖，返回处理的拍卖数量
+        ///
+        /// 成交价按 `pay_sale` 分配；每个拍卖单独回滚，结算失败时出价退还给出价人。
+        pub(crate) fn settle_auctions(now: BlockNumberFor<T>) -> u32 {
+            let ends = AuctionEnds::<T>::take(now);
+            let count = ends.len() as u32;
+            for claim_hash in ends {
+                let Some(auction) = Auctions::<T>::take(&claim_hash) else {
+                    continue;
+                };
+                let Some((bidder, amount)) = auction.best_bid else {
+                    Self::deposit_event(Event::AuctionEndedWithoutBids { claim_hash });
+                    continue;
+                };
+                let result = with_storage_layer(|| {
+                    let details = Self::live_claim(&claim_hash)?;
+                    ensure!(details.owner == auction.seller, Error::<T>::NotClaimOwner);
+                    T::Currency::unreserve(&bidder, amount);
+                    Self::pay_sale(&claim_hash, &bidder, &auction.seller, amount)?;
+                    Self::do_transfer(claim_hash, details, bidder.clone())
+                });
+                match result {
+                    Ok(()) => Self::deposit_event(Event::AuctionSettled {
+                        seller: auction.seller,
+                        buyer: bidder,
+                        claim_hash,
+                        price: amount,
+                    }),
+                    Err(error) => {
+                        T::Currency::unreserve(&bidder, amount);
+                        Self::deposit_event(Event::AuctionSettlementFailed { claim_hash, error });
+                    }
+                }
+            }
+            count
+        }
+
+        /// 结算 `settled` 个拍卖消耗的权重：队列读写各一次，每个拍卖按成交并 `do_transfer` 的最坏情况计算
+        fn auctions_weight(settled: u32) -> Weight {
+            let per_auction = T::DbWeight::get()
+                .reads_writes(23 + T::MaxCoOwners::get() as u64, 22 + T::MaxCoOwners::get() as u64);
+            T::DbWeight::get()
+                .reads_writes(1, 1)
+                .saturating_add(per_auction.saturating_mul(settled as u64))
+        }
+
+        /// 把托管的货款按 `pay_sale` 的规则支付给卖家
+        fn release_escrow(
+            claim_hash: &ClaimHashOf<T>,
+            escrow: &Escrow<T::AccountId, ClaimHashOf<T>, BlockNumberFor<T>, BalanceOf<T>>,
+        ) -> DispatchResult {
+            T::Currency::unreserve(&escrow.buyer, escrow.price);
+            Self::pay_sale(claim_hash, &escrow.buyer, &escrow.seller, escrow.price)?;
+            Self::deposit_event(Event::EscrowReleased {
+                seller: escrow.seller.clone(),
+                claim_hash: *claim_hash,
+                price: escrow.price,
+            });
+            Ok(())
+        }
+
+        /// 向卖家支付争议期在 `now` 结束的托管货款，返回队列中的托管数量
+        ///
+        /// 有争议的托管等待仲裁；每个放款单独回滚，失败的托管保留并触发 `EscrowReleaseFailed`，由仲裁处理。
+        pub(crate) fn release_escrows(now: BlockNumberFor<T>) -> u32 {
+            let releases = EscrowReleases::<T>::take(now);
+            let count = releases.len() as u32;
+            for claim_hash in releases {
+                let Some(escrow) = Escrows::<T>::get(&claim_hash) else {
+                    continue;
+                };
+                if escrow.release_at != now || escrow.dispute.is_some() {
+                    continue;
+                }
+                let result = with_storage_layer(|| {
+                    Escrows::<T>::remove(&claim_hash);
+                    Self::release_escrow(&claim_hash, &escrow)
+                });
+                if let Err(error) = result {
+                    Self::deposit_event(Event::EscrowReleaseFailed { claim_hash, error });
+                }
+            }
+            count
+        }
+
+        /// 放款 `count` 个托管消耗的权重：队列读写各一次，每个托管读写托管记录，读取版税，
+        /// 并读写买家、卖家、版税受益人的账户和总发行量
+        fn escrows_weight(count: u32) -> Weight {
+            T::DbWeight::get()
+                .reads_writes(1, 1)
+                .saturating_add(T::DbWeight::get().reads_writes(6, 5).saturating_mul(count as u64))
+        }
+
+        /// 清除存证的全部共同所有者及其批准，返回清除的共同所有者数量
+        fn clear_co_owners(claim_hash: &ClaimHashOf<T>) -> u32 {
+            let co_owners = CoOwners::<T>::take(claim_hash).len() as u32;
+            if co_owners > 0 {
+                let _ = CoOwnerApprovals::<T>::clear_prefix(claim_hash, T::MaxCoOwners::get(), None);
+            }
+            co_owners
+        }
+
+        /// 把存证从集合中移出
+        fn remove_from_collection(collection_id: CollectionId, claim_hash: &ClaimHashOf<T>) {
+            ClaimCollection::<T>::remove(claim_hash);
+            CollectionClaims::<T>::mutate(collection_id, |claims| claims.retain(|claim| claim != claim_hash));
+        }
+
+        /// 把存证移出打包出售，打包中已没有存证时取消打包
+        fn remove_from_bundle(bundle_id: BundleId, claim_hash: &ClaimHashOf<T>) {
+            ClaimBundle::<T>::remove(claim_hash);
+            let claims = BundleClaims::<T>::mutate(bundle_id, |claims| {
+                claims.retain(|claim| claim != claim_hash);
+                claims.len()
+            });
+            Self::deposit_event(Event::ClaimUnbundled { bundle_id, claim_hash: *claim_hash });
+            if claims == 0 {
+                BundleClaims::<T>::remove(bundle_id);
+                Bundles::<T>::remove(bundle_id);
+                Self::deposit_event(Event::BundleCancelled { bundle_id });
+            }
+        }
+
+        /// 把存证活动写入链下索引，键为 [`activity_key`]，每个账户的活动按顺序从 0 编号
+        ///
+        /// 节点需要以 `--enable-offchain-indexing true` 启动才会保存索引。索引写入不随存储回滚，
+        /// 调用失败时计数回滚，写入的记录会被同一账户的下一条活动覆盖。
+        fn index_activity(owner: &T::AccountId, claim_hash: ClaimHashOf<T>, kind: ActivityKind) {
+            let index = ActivityCount::<T>::mutate(owner, |count| {
+                let index = *count;
+                *count = count.saturating_add(1);
+                index
+            });
+            let record = ActivityRecord { claim_hash, block_number: frame_system::Pallet::<T>::block_number(), kind };
+            sp_io::offchain_index::set(&activity_key(owner, index), &record.encode());
+        }
+
+        /// 撤销存证并通知 `OnClaimRevoked`，调用者负责检查权限
+        ///
+        /// 返回随存证清理的关联记录数量，见 `remove_claim`。
+        pub(crate) fn do_revoke(claim_hash: ClaimHashOf<T>, details: ClaimDetails<T>) -> Result<u32, DispatchError> {
+            let removed = Self::remove_claim(&claim_hash, &details);
+            T::OnClaimRevoked::on_claim_revoked(&details.owner, &claim_hash)?;
+            Self::index_activity(&details.owner, claim_hash, ActivityKind::Revoked);
+
+            BlockStats::<T>::mutate(|stats| stats.revoked = stats.revoked.saturating_add(1));
+            Self::deposit_event(Event::ClaimRevoked {
+                who: details.owner,
+                claim_hash,
+                block: frame_system::Pallet::<T>::block_number(),
+            });
+
+            Ok(removed)
+        }
+
+        /// 撤销 `revoked` 个存证的实际权重：按实际清理的关联记录数量退还最坏情况中未使用的写入
+        ///
+        /// 撤销的权重按每个存证的背书、许可、子存证和共同所有者都达到上限计算，例如没有背书的存证会退还大部分权重。
+        fn revoke_weight(worst_case: Weight, revoked: u32, removed: u32) -> Weight {
+            let max_per_claim = T::MaxAttestations::get()
+                .saturating_add(T::MaxLicenses::get().saturating_mul(2))
+                .saturating_add(T::MaxChildren::get())
+                .saturating_add(T::MaxCoOwners::get());
+            let unused = max_per_claim.saturating_mul(revoked).saturating_sub(removed);
+            worst_case.saturating_sub(T::DbWeight::get().writes(unused as u64))
+        }
+
+        /// 转移 `transferred` 个存证的实际权重：按实际清除的共同所有者批准数量退还最坏情况中未使用的读写
+        fn transfer_weight(worst_case: Weight, transferred: u32, co_owners: u32) -> Weight {
+            let unused = T::MaxCoOwners::get().saturating_mul(transferred).saturating_sub(co_owners);
+            worst_case.saturating_sub(T::DbWeight::get().reads_writes(unused as u64, unused as u64))
+        }
+
+        /// 创建 `created` 个存证的实际权重：最坏情况按每个存证都覆盖一个过期存证计算，没有覆盖时退还清理的权重
+        fn create_weight(worst_case: Weight, created: u32, overwritten: u32) -> Weight {
+            let unused = created.saturating_sub(overwritten);
+            worst_case.saturating_sub(Self::expired_claim_weight().saturating_mul(unused as u64))
+        }
+
+        /// 存证当前的共同所有者数量
+        fn co_owner_count(claim_hash: &ClaimHashOf<T>) -> u32 {
+            CoOwners::<T>::decode_len(claim_hash).unwrap_or(0) as u32
+        }
+
+        /// 相同内容的存证已存在且未过期时返回 `ProofAlreadyExist`，只收取暂停状态和存证两次读取的权重
+        fn ensure_claim_available(claim_hash: &ClaimHashOf<T>) -> Result<(), DispatchErrorWithPostInfo> {
+            let now = frame_system::Pallet::<T>::block_number();
+            if Proofs::<T>::get(claim_hash).map_or(false, |details| !details.is_expired(now)) {
+                return Err(Error::<T>::ProofAlreadyExist.with_weight(T::DbWeight::get().reads(2)));
+            }
+            Ok(())
+        }
+
+        /// 链下工作机：取出 `PENDING_CLAIMS_KEY` 中的存证哈希逐个提交，提交失败的留待下一个区块重试
+        fn submit_pending_claims(now: BlockNumberFor<T>) {
+            let signer = Signer::<T, T::AuthorityId>::any_account();
+            if !signer.can_sign() {
+                return;
+            }
+            let mut storage = StorageValueRef::persistent(PENDING_CLAIMS_KEY);
+            let pending = match storage.get::<Vec<ClaimHashOf<T>>>() {
+                Ok(Some(pending)) if !pending.is_empty() => pending,
+                _ => return,
+            };
+
+            let remaining: Vec<_> = pending
+                .into_iter()
+                .filter(|claim_hash| {
+                    let result = signer.send_unsigned_transaction(
+                        |account| ClaimPayload { public: account.public.clone(), claim_hash: *claim_hash, block_number: now },
+                        |payload, signature| Call::submit_claim_unsigned { payload, signature },
+                    );
+                    !matches!(result, Some((_, Ok(()))))
+                })
+                .collect();
+
+            if remaining.is_empty() {
+                storage.clear();
+            } else {
+                storage.set(&remaining);
+            }
+        }
+
+        /// 链下工作机：每 `AvailabilityCheckInterval` 个区块检查一批带有 HTTP(S) 地址的存证，
+        /// 可用性与链上状态不一致时提交签名的 `report_availability` 交易
+        ///
+        /// 检查进度保存在链下本地存储的游标中，遍历完所有存证后从头开始。
+        fn check_availability(now: BlockNumberFor<T>) {
+            let interval = T::AvailabilityCheckInterval::get();
+            if interval.is_zero() || !(now % interval).is_zero() {
+                return;
+            }
+            let signer = Signer::<T, T::AuthorityId>::any_account();
+            if !signer.can_sign() {
+                return;
+            }
+
+            let mut cursor = StorageValueRef::persistent(AVAILABILITY_CURSOR_KEY);
+            let mut iter = match cursor.get::<Vec<u8>>() {
+                Ok(Some(last_key)) => Proofs::<T>::iter_from(last_key),
+                _ => Proofs::<T>::iter(),
+            };
+
+            let mut checked = 0;
+            let mut finished = true;
+            while checked < T::MaxAvailabilityChecks::get() {
+                let Some((claim_hash, details)) = iter.next() else {
+                    break;
+                };
+                checked += 1;
+                finished = checked < T::MaxAvailabilityChecks::get();
+
+                if details.is_expired(now) || details.status == ClaimStatus::Frozen {
+                    continue;
+                }
+                let Some(available) = details.uri.as_ref().and_then(|uri| Self::fetch_available(uri)) else {
+                    continue;
+                };
+                if available == (details.status == ClaimStatus::ContentUnavailable) {
+                    let _ = signer.send_signed_transaction(|_| Call::report_availability { claim_hash, available });
+                }
+            }
+
+            if finished {
+                cursor.clear();
+            } else {
+                cursor.set(&iter.last_raw_key().to_vec());
+            }
+        }
+
+        /// 请求 `uri` 并根据响应码判断内容是否可以访问，非 HTTP(S) 地址返回 `None`
+        fn fetch_available(uri: &[u8]) -> Option<bool> {
+            let url = sp_std::str::from_utf8(uri).ok()?;
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return None;
+            }
+
+            let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MS));
+            let Ok(pending) = http::Request::get(url).deadline(deadline).send() else {
+                return Some(false);
+            };
+            let available = match pending.try_wait(deadline) {
+                Ok(Ok(response)) => (200..400).contains(&response.code),
+                _ => false,
+            };
+            Some(available)
+        }
+
+        /// 计算存证哈希的默克尔根：相邻两个节点拼接后哈希，奇数个节点时最后一个直接进入上一层；
+        /// 空列表返回默认哈希
+        pub fn merkle_root(leaves: &[ClaimHashOf<T>]) -> ClaimHashOf<T> {
+            let mut level = leaves.to_vec();
+            while level.len() > 1 {
+                level = level
+                    .chunks(2)
+                    .map(|pair| match pair {
+                        [left, right] => Self::merkle_node(left, right),
+                        _ => pair[0],
+                    })
+                    .collect();
+            }
+            level.first().copied().unwrap_or_default()
+        }
+
+        /// 验证 `leaf` 是按 [`Pallet::merkle_root`] 构建、包含 `count` 个叶子的默克尔树中的第 `leaf_index` 个叶子
+        ///
+        /// 树的形状由 `count` 决定，证明必须恰好包含从叶子到根路径上的全部兄弟节点。
+        pub fn verify_merkle_proof(
+            root: &ClaimHashOf<T>,
+            leaf: ClaimHashOf<T>,
+            count: u32,
+            proof: &MerkleProof<ClaimHashOf<T>, T::MaxProofDepth>,
+        ) -> bool {
+            if proof.leaf_index >= count {
+                return false;
+            }
+            let mut siblings = proof.siblings.iter();
+            let (mut hash, mut index, mut width) = (leaf, proof.leaf_index, count);
+            while width > 1 {
+                if index % 2 == 1 {
+                    let Some(left) = siblings.next() else { return false };
+                    hash = Self::merkle_node(left, &hash);
+                } else if index + 1 < width {
+                    let Some(right) = siblings.next() else { return false };
+                    hash = Self::merkle_node(&hash, right);
+                }
+                // 否则是奇数层的最后一个节点，直接进入上一层
+                index /= 2;
+                width = (width + 1) / 2;
+            }
+            siblings.next().is_none() && hash == *root
+        }
+
+        /// 默克尔树的内部节点：左右子节点拼接后的哈希
+        pub(crate) fn merkle_node(left: &ClaimHashOf<T>, right: &ClaimHashOf<T>) -> ClaimHashOf<T> {
+            T::ClaimHasher::hash(&[left.as_ref(), right.as_ref()].concat())
+        }
+
+        /// 链下工作机：每 `AnchorInterval` 个区块把这段时间内创建的存证的默克尔根发送到以太坊，
+        /// 再以签名的 `record_anchor` 交易把以太坊交易哈希记录上链
+        ///
+        /// 需要在链下本地存储中设置 `ANCHOR_ENDPOINT_KEY` 和 `ANCHOR_ACCOUNT_KEY`。
+        fn anchor_recent_claims(now: BlockNumberFor<T>) {
+            let interval = T::AnchorInterval::get();
+            if interval.is_zero() || !(now % interval).is_zero() {
+                return;
+            }
+            let local = |key| sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, key);
+            let (Some(endpoint), Some(account)) = (local(ANCHOR_ENDPOINT_KEY), local(ANCHOR_ACCOUNT_KEY)) else {
+                return;
+            };
+            let signer = Signer::<T, T::AuthorityId>::any_account();
+            if !signer.can_sign() {
+                return;
+            }
+
+            let from = now.saturating_sub(interval).saturating_add(One::one());
+            let mut created: Vec<_> = Proofs::<T>::iter()
+                .filter(|(_, details)| details.created_at >= from && details.created_at <= now)
+                .map(|(claim_hash, details)| (details.created_at, claim_hash))
+                .collect();
+            if created.is_empty() {
+                return;
+            }
+            created.sort();
+            let leaves: Vec<_> = created.into_iter().map(|(_, claim_hash)| claim_hash).collect();
+            let root = Self::merkle_root(&leaves);
+            if Anchors::<T>::contains_key(&root) {
+                return;
+            }
+
+            let Some(tx_hash) = Self::send_anchor(&endpoint, &account, root.as_ref()) else {
+                return;
+            };
+            let count = leaves.len() as u32;
+            let _ = signer.send_signed_transaction(|_| Call::record_anchor { root, from, to: now, count, tx_hash });
+        }
+
+        /// 通过 JSON-RPC 发送锚定交易，返回以太坊交易哈希
+        fn send_anchor(endpoint: &[u8], account: &[u8], root: &[u8]) -> Option<[u8; 32]> {
+            let url = sp_std::str::from_utf8(endpoint).ok()?;
+            let body = ethereum::send_transaction_body(account, root);
+
+            let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MS));
+            let pending = http::Request::post(url, sp_std::vec![body])
+                .add_header("Content-Type", "application/json")
+                .deadline(deadline)
+                .send()
+                .ok()?;
+            let response = pending.try_wait(deadline).ok()?.ok()?;
+            if response.code != 200 {
+                return None;
+            }
+            ethereum::parse_tx_hash(&response.body().collect::<Vec<u8>>())
+        }
+
+        /// 在押金周期开始时从价格接口读取原生代币价格并以签名交易提交
+        fn report_native_price(now: BlockNumberFor<T>) {
+            let era = T::DepositEra::get();
+            if era.is_zero() || !(now % era).is_zero() {
+                return;
+            }
+            let Some(endpoint) = sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, PRICE_ENDPOINT_KEY) else {
+                return;
+            };
+            let signer = Signer::<T, T::AuthorityId>::any_account();
+            if !signer.can_sign() {
+                return;
+            }
+
+            let Some(price) = Self::fetch_price(&endpoint) else {
+                return;
+            };
+            let _ = signer.send_signed_transaction(|_| Call::report_price { price });
+        }
+
+        /// 请求价格接口并取出响应中的价格
+        fn fetch_price(endpoint: &[u8]) -> Option<FixedU128> {
+            let url = sp_std::str::from_utf8(endpoint).ok()?;
+
+            let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MS));
+            let pending = http::Request::get(url).deadline(deadline).send().ok()?;
+            let response = pending.try_wait(deadline).ok()?.ok()?;
+            if response.code != 200 {
+                return None;
+            }
+            price::parse_price(&response.body().collect::<Vec<u8>>())
+        }
+
+        /// 在押金周期开始时按未过期的喂价重新计算基础押金，使押金保持 `FiatDeposit` 的法币价值
+        fn update_deposit_base(now: BlockNumberFor<T>) -> Weight {
+            let era = T::DepositEra::get();
+            if era.is_zero() || !(now % era).is_zero() {
+                return Weight::zero();
+            }
+            let Some(report) = NativePrice::<T>::get() else {
+                return T::DbWeight::get().reads(1);
+            };
+            if now.saturating_sub(report.reported_at) > T::MaxPriceAge::get() {
+                return T::DbWeight::get().reads(1);
+            }
+
+            // 押金（最小单位）= 法币价值 / 单价 * 每个代币的最小单位数
+            let Some(tokens) = T::FiatDeposit::get().checked_div(&report.price) else {
+                return T::DbWeight::get().reads(1);
+            };
+            let deposit = tokens.saturating_mul_int(T::NativeUnit::get());
+            EraDepositBase::<T>::put(deposit);
+
+            Self::deposit_event(Event::DepositBaseUpdated { deposit, price: report.price });
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+
+        /// 删除一个已过期的存证并触发 `ClaimExpired` 事件
+        fn remove_expired_claim(claim_hash: &ClaimHashOf<T>, details: ClaimDetails<T>) {
+            Self::remove_claim(claim_hash, &details);
+
+            Self::deposit_event(Event::ClaimExpired {
+                who: details.owner,
+                claim_hash: *claim_hash,
+                block: frame_system::Pallet::<T>::block_number(),
+            });
+        }
+
+        /// 从最早未处理完的区块起按过期索引删除到期的存证，最多检查 `limit` 个索引条目和存证，返回 `(检查数, 清理数)`
+        ///
+        /// 达到上限时未检查的存证留在索引中，`ExpiryBacklog` 记录处理到的区块，之后的区块从这里继续。
+        pub(crate) fn expire_indexed_claims(now: BlockNumberFor<T>, limit: u32) -> (u32, u32) {
+            let mut block = ExpiryBacklog::<T>::get().unwrap_or(now);
+            let (mut checked, mut removed) = (0u32, 0u32);
+            while block <= now {
+                if checked >= limit {
+                    ExpiryBacklog::<T>::put(block);
+                    return (checked, removed);
+                }
+                checked += 1;
+
+                let mut claims = ExpiringAt::<T>::take(block).into_inner();
+                let rest = claims.split_off(claims.len().min((limit - checked) as usize));
+                for claim_hash in claims {
+                    checked += 1;
+                    // 续期或删除后残留的条目按存证当前的过期区块跳过
+                    let Some(details) = Proofs::<T>::get(&claim_hash).filter(|details| details.expires_at == block) else {
+                        continue;
+                    };
+                    Self::remove_expired_claim(&claim_hash, details);
+                    removed += 1;
+                }
+                if !rest.is_empty() {
+                    ExpiringAt::<T>::insert(block, BoundedVec::<_, T::MaxExpiringPerBlock>::truncate_from(rest));
+                    ExpiryBacklog::<T>::put(block);
+                    return (checked, removed);
+                }
+                if block == now {
+                    break;
+                }
+                block = block.saturating_add(One::one());
+            }
+            ExpiryBacklog::<T>::kill();
+            (checked, removed)
+        }
+
+        /// 按过期索引清理消耗的权重：`ExpiryBacklog` 读写各一次，每个检查的索引条目或存证读写各一次，
+        /// 每个清理的存证按 `remove_claim` 的最坏情况计算
+        fn expiry_index_weight(checked: u32, removed: u32) -> Weight {
+            T::DbWeight::get()
+                .reads_writes(1 + checked as u64, 1 + checked as u64)
+                .saturating_add(Self::expired_claim_weight().saturating_mul(removed as u64))
+        }
+
+        /// 从游标处继续检查最多 `limit` 个存证并清理其中已过期的，返回 `(检查数, 清理数)`
+        ///
+        /// 遍历到 `Proofs` 末尾后游标被清空，下次从头开始。
+        pub(crate) fn sweep_expired_claims(now: BlockNumberFor<T>, limit: u32) -> (u32, u32) {
+            if limit == 0 {
+                return (0, 0);
+            }
+
+            let mut iter = match ExpirySweepCursor::<T>::get() {
+                Some(last) => Proofs::<T>::iter_from(Proofs::<T>::hashed_key_for(last)),
+                None => Proofs::<T>::iter(),
+            };
+
+            // 遍历过程中不能修改 map，先收集过期的存证再统一删除
+            let mut checked = 0u32;
+            let mut cursor = None;
+            let mut expired = Vec::new();
+            while checked < limit {
+                match iter.next() {
+                    Some((claim_hash, details)) => {
+                        checked += 1;
+                        cursor = Some(claim_hash);
+                        if details.is_expired(now) {
+                            expired.push((claim_hash, details));
+                        }
+                    },
+                    None => {
+                        cursor = None;
+                        break;
+                    },
+                }
+            }
+
+            match cursor {
+                Some(claim_hash) => ExpirySweepCursor::<T>::put(claim_hash),
+                None => ExpirySweepCursor::<T>::kill(),
+            }
+
+            let removed = expired.len() as u32;
+            for (claim_hash, details) in expired {
+                Self::remove_expired_claim(&claim_hash, details);
+            }
+
+            (checked, removed)
+        }
+
+        /// 遍历注册表清理过期存证消耗的权重：游标读写各一次，每个检查的存证读一次，
+        /// 每个清理的存证按 `remove_claim` 的最坏情况计算
+        fn sweep_weight(checked: u32, removed: u32) -> Weight {
+            T::DbWeight::get()
+                .reads_writes(1 + checked as u64, 1)
+                .saturating_add(Self::expired_claim_weight().saturating_mul(removed as u64))
+        }
+
+        /// 删除一个过期存证的最坏情况权重（包括清除全部背书、许可、子存证链接和共同所有者批准）
+        fn expired_claim_weight() -> Weight {
+            T::DbWeight::get().reads_writes(
+                29,
+                51 + T::MaxAttestations::get() as u64 +
+                    2 * T::MaxLicenses::get() as u64 +
+                    T::MaxChildren::get() as u64 +
+                    T::MaxCoOwners::get() as u64,
+            )
+        }
+
+        /// 长度为 `len` 的存证需要保留的押金，基础部分为本周期按喂价计算的押金
+        pub fn deposit_for(len: u32) -> BalanceOf<T> {
+            let base = EraDepositBase::<T>::get().unwrap_or_else(T::DepositBase::get);
+            base.saturating_add(T::DepositPerByte::get().saturating_mul(len.into()))
+        }
+
+        /// 为 `owner` 创建存证，押金从 `depositor` 保留，数量上限按 `owner` 计算
+        fn do_create_claim(
+            owner: T::AccountId,
+            depositor: T::AccountId,
+            claim_hash: ClaimHashOf<T>,
+            len: u32,
+        ) -> DispatchResult {
+            Self::insert_claim(owner, depositor, claim_hash, Some(len))
+        }
+
+        /// 与 `do_create_claim` 相同，`len` 为 `None` 时不保留押金、也不收取创建费用，
+        /// 用于已在其他链上缴纳过押金的转入存证
+        fn insert_claim(
+            owner: T::AccountId,
+            depositor: T::AccountId,
+            claim_hash: ClaimHashOf<T>,
+            len: Option<u32>,
+        ) -> DispatchResult {
+            ensure!(!Banned::<T>::contains_key(&claim_hash), Error::<T>::ClaimBanned);
+            Self::ensure_not_banned(&owner)?;
+            if depositor != owner {
+                Self::ensure_not_banned(&depositor)?;
+            }
+            T::ClaimPolicy::can_create(&owner, &claim_hash)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+
+            // 已过期的存证可以被重新创建，先把旧记录清理掉
+            if let Some(details) = Proofs::<T>::get(&claim_hash) {
+                ensure!(details.is_expired(now), Error::<T>::ProofAlreadyExist);
+                Self::remove_expired_claim(&claim_hash, details);
+            }
+
+            ClaimsThisBlock::<T>::try_mutate(|count| {
+                ensure!(*count < T::MaxClaimsPerBlock::get(), Error::<T>::BlockLimitReached);
+                *count += 1;
+                Ok::<_, DispatchError>(())
+            })?;
+            Self::inc_claim_count(&owner)?;
+            Self::inc_total_claims()?;
+
+            // 免费名单中的账户不保留押金，也不支付创建费用
+            let exempt = FeeExempt::<T>::contains_key(&depositor);
+            let deposit = match len {
+                Some(len) if !exempt => Self::deposit_for(len),
+                _ => Zero::zero(),
+            };
+            T::Currency::reserve(&depositor, deposit)?;
+            // 创建费用与押金由同一账户支付
+            let fee = T::CreationFee::get();
+            if !exempt && len.is_some() && !fee.is_zero() {
+                let imbalance = T::Currency::withdraw(&depositor, fee, WithdrawReasons::FEE, ExistenceRequirement::KeepAlive)?;
+                T::OnCreationFee::on_unbalanced(imbalance);
+            }
+
+            let expires_at = now.saturating_add(T::ClaimLifetime::get());
+            Self::index_expiry(&claim_hash, expires_at)?;
+            let mut details = ClaimDetails::new(owner.clone(), now, expires_at, deposit);
+            details.depositor = depositor;
+            Proofs::<T>::insert(&claim_hash, details);
+            OwnerClaims::<T>::insert(&owner, &claim_hash, ());
+            let window = T::ChallengeWindow::get();
+            if !window.is_zero() {
+                // 每个区块创建的存证不超过 `MaxClaimsPerBlock`，队列在达到上限前不会溢出
+                let confirm_at = now.saturating_add(window);
+                ConfirmationQueue::<T>::try_mutate(confirm_at, |queue| queue.try_push(claim_hash))
+                    .map_err(|_| Error::<T>::BlockLimitReached)?;
+                ProvisionalClaims::<T>::insert(&claim_hash, confirm_at);
+            }
+            T::OnClaimCreated::on_claim_created(&owner, &claim_hash)?;
+            T::NftMirror::mint(&owner, &claim_hash)?;
+            Self::index_activity(&owner, claim_hash, ActivityKind::Created);
+
+            BlockStats::<T>::mutate(|stats| stats.created = stats.created.saturating_add(1));
+            Self::deposit_event(Event::ClaimCreated {
+                who: owner,
+                claim_hash,
+                block: frame_system::Pallet::<T>::block_number(),
+            });
+
+            Ok(())
+        }
+
+        /// 校验存储的不变量：
+        /// - `Proofs` 与 `OwnerClaims` 一一对应，所有者一致
+        /// - 每个账户的 `ClaimCountOf` 等于其 `OwnerClaims` 条目数，`TotalClaims` 等于 `Proofs` 条目数
+        /// - 当前生效的 `CurrentMaxLength` 不超过 `MaxClaimLength`
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            let mut total = 0u32;
+            for (claim_hash, details) in Proofs::<T>::iter() {
+                ensure!(
+                    OwnerClaims::<T>::contains_key(&details.owner, claim_hash),
+                    "claim is missing from OwnerClaims of its owner"
+                );
+                total = total.saturating_add(1);
+            }
+            ensure!(TotalClaims::<T>::get() == total, "TotalClaims does not match the number of Proofs");
+
+            let mut counts = sp_std::collections::btree_map::BTreeMap::<T::AccountId, u32>::new();
+            for (owner, claim_hash, ()) in OwnerClaims::<T>::iter() {
+                ensure!(
+                    Proofs::<T>::get(claim_hash).map_or(false, |details| details.owner == owner),
+                    "OwnerClaims entry has no matching Proofs entry"
+                );
+                let count = counts.entry(owner).or_default();
+                *count = count.saturating_add(1);
+            }
+            for (owner, count) in ClaimCountOf::<T>::iter() {
+                ensure!(
+                    counts.remove(&owner).unwrap_or_default() == count,
+                    "ClaimCountOf does not match the number of OwnerClaims"
+                );
+            }
+            ensure!(counts.is_empty(), "account owns claims but has no ClaimCountOf");
+
+            ensure!(
+                CurrentMaxLength::<T>::get() <= T::MaxClaimLength::get(),
+                "CurrentMaxLength exceeds MaxClaimLength"
+            );
+            Ok(())
+        }
+    }
+
+    impl<T: Config> ProofProvider<T::AccountId> for Pallet<T> {
+        type Hash = ClaimHashOf<T>;
+
+        fn owner_of(claim_hash: &Self::Hash) -> Option<T::AccountId> {
+            Self::live_claim(claim_hash).ok().map(|details| details.owner)
+        }
+
+        fn create_for(owner: &T::AccountId, claim_hash: Self::Hash) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            Self::do_create_claim(owner.clone(), owner.clone(), claim_hash, T::MaxClaimLength::get())
+        }
+
+        fn reputation_of(who: &T::AccountId) -> i32 {
+            Reputation::<T>::get(who)
+        }
+    }
+
+    impl<T: Config> ClaimCollateral<T::AccountId> for Pallet<T> {
+        type Hash = ClaimHashOf<T>;
+
+        fn lock_as_collateral(owner: &T::AccountId, claim_hash: Self::Hash, locker: &T::AccountId) -> DispatchResult {
+            Self::do_lock_collateral(owner, claim_hash, locker.clone())
+        }
+
+        fn unlock_collateral(locker: &T::AccountId, claim_hash: Self::Hash) -> DispatchResult {
+            Self::do_unlock_collateral(locker, claim_hash)
+        }
+
+        fn collateral_locker(claim_hash: &Self::Hash) -> Option<T::AccountId> {
+            CollateralLocks::<T>::get(claim_hash)
+        }
+    }
+}
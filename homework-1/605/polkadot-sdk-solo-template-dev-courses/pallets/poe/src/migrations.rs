@@ -0,0 +1,198 @@
+//! PoE pallet 的存储迁移
+
+use super::*;
+use frame_support::{
+    migrations::{MigrationId, SteppedMigration, SteppedMigrationError, VersionedMigration},
+    pallet_prelude::*,
+    storage_alias,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    weights::WeightMeter,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{One, Saturating, Zero};
+use sp_std::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// 多区块迁移的标识前缀
+const PALLET_MIGRATIONS_ID: &[u8; 10] = b"pallet-poe";
+
+/// 把迁移的存证写入 `expires_at` 区块的过期索引，该区块已满时顺延到下一个区块，`expires_at` 更新为实际使用的区块
+///
+/// 迁移的存证从同一个区块起算有效期，逐块顺延保证每个区块的索引不超过 `MaxExpiringPerBlock`。
+fn index_migrated_claim<T: Config>(claim_hash: ClaimHashOf<T>, expires_at: &mut BlockNumberFor<T>) {
+    while ExpiringAt::<T>::try_mutate(*expires_at, |claims| claims.try_push(claim_hash)).is_err() {
+        *expires_at = expires_at.saturating_add(One::one());
+    }
+}
+
+/// v0 版本的存储布局：键为存证原文，值为 `(所有者, 区块号)`
+pub mod v0 {
+    use super::*;
+
+    #[storage_alias]
+    pub type Proofs<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        (<T as frame_system::Config>::AccountId, BlockNumberFor<T>),
+    >;
+}
+
+/// v0 -> v1：`Proofs` 的键由存证原文改为存证内容的哈希，同时建立 `OwnerClaims` 索引
+pub mod v1 {
+    use super::*;
+
+    /// v1 版本的存储布局：键为存证哈希，值仍为 `(所有者, 区块号)`
+    #[storage_alias]
+    pub type Proofs<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        ClaimHashOf<T>,
+        (<T as frame_system::Config>::AccountId, BlockNumberFor<T>),
+    >;
+
+    /// 只在链上存储版本为 0 时执行，执行后版本升为 1
+    pub type MigrateToV1<T> =
+        VersionedMigration<0, 1, UncheckedMigrateToV1<T>, Pallet<T>, <T as frame_system::Config>::DbWeight>;
+
+    /// 不检查存储版本的迁移逻辑，应通过 [`MigrateToV1`] 使用
+    pub struct UncheckedMigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            // 先把旧数据全部取出，避免在同一个存储前缀下边遍历边写入
+            let old: Vec<_> = v0::Proofs::<T>::drain().collect();
+            let count = old.len() as u64;
+            for (claim, value) in old {
+                let claim_hash = Pallet::<T>::claim_hash(&claim);
+                OwnerClaims::<T>::insert(&value.0, &claim_hash, ());
+                // 迁移前的存证不受数量上限限制，只记录实际数量
+                ClaimCountOf::<T>::mutate(&value.0, |count| *count = count.saturating_add(1));
+                Proofs::<T>::insert(claim_hash, value);
+            }
+
+            TotalClaims::<T>::put(count as u32);
+
+            T::DbWeight::get().reads_writes(count * 2, count * 4 + 1)
+        }
+
+        /// 记录迁移前的存证数量
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((v0::Proofs::<T>::iter_keys().count() as u32).encode())
+        }
+
+        /// 旧存证全部按哈希重新写入，索引和总数与迁移前的数量一致
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "MigrateToV1: invalid pre-upgrade state")?;
+            ensure!(v0::Proofs::<T>::iter_keys().next().is_none(), "MigrateToV1: v0 claims left behind");
+            ensure!(Proofs::<T>::iter_keys().count() as u32 == count, "MigrateToV1: claim count changed");
+            ensure!(OwnerClaims::<T>::iter_keys().count() as u32 == count, "MigrateToV1: OwnerClaims incomplete");
+            ensure!(TotalClaims::<T>::get() == count, "MigrateToV1: TotalClaims mismatch");
+            Ok(())
+        }
+    }
+}
+
+/// v1 -> v2：`(所有者, 区块号)` 元组替换为 [`ClaimDetails`]
+pub mod v2 {
+    use super::*;
+
+    /// 只在链上存储版本为 1 时执行，执行后版本升为 2
+    pub type MigrateToV2<T> =
+        VersionedMigration<1, 2, UncheckedMigrateToV2<T>, Pallet<T>, <T as frame_system::Config>::DbWeight>;
+
+    /// 不检查存储版本的迁移逻辑，应通过 [`MigrateToV2`] 使用
+    pub struct UncheckedMigrateToV2<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            // 旧存证没有过期时间，从升级所在区块起给予完整的有效期，避免升级后大量存证立即过期
+            let mut expires_at = frame_system::Pallet::<T>::block_number().saturating_add(T::ClaimLifetime::get());
+            let mut count = 0u64;
+            crate::Proofs::<T>::translate::<(T::AccountId, BlockNumberFor<T>), _>(
+                |claim_hash, (owner, created_at)| {
+                    count += 1;
+                    index_migrated_claim::<T>(claim_hash, &mut expires_at);
+                    // v1 的存证创建时没有保留押金
+                    Some(ClaimDetails::new(owner, created_at, expires_at, Zero::zero()))
+                },
+            );
+
+            T::DbWeight::get().reads_writes(2 * count + 1, 2 * count)
+        }
+
+        /// 记录迁移前的存证数量
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((v1::Proofs::<T>::iter_keys().count() as u32).encode())
+        }
+
+        /// 每个存证都能按 [`ClaimDetails`] 解码且写入了过期索引，数量没有变化
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "MigrateToV2: invalid pre-upgrade state")?;
+            ensure!(Proofs::<T>::iter_values().count() as u32 == count, "MigrateToV2: claims failed to decode");
+            ensure!(
+                Proofs::<T>::iter()
+                    .all(|(claim_hash, details)| ExpiringAt::<T>::get(details.expires_at).contains(&claim_hash)),
+                "MigrateToV2: claims missing from ExpiringAt"
+            );
+            Ok(())
+        }
+    }
+
+    /// 与 [`MigrateToV2`] 相同的转换，但跨多个区块逐个处理存证，供 `pallet_migrations` 使用，
+    /// 存证数量巨大、无法在一个区块内完成迁移的链应使用它代替 [`MigrateToV2`]。
+    /// 游标为最后转换的存证哈希及其过期区块，由 `pallet_migrations` 在区块之间保存；全部转换完成后存储版本升为 2
+    ///
+    /// 迁移期间尚未转换的存证无法按 [`ClaimDetails`] 解码，过期清理会跳过它们；
+    /// 有效期从转换所在的区块起算，之后的步骤从游标记录的过期区块继续，不再检查之前已满的区块
+    pub struct LazyMigrateToV2<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for LazyMigrateToV2<T> {
+        type Cursor = (ClaimHashOf<T>, BlockNumberFor<T>);
+        type Identifier = MigrationId<10>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 1, version_to: 2 }
+        }
+
+        fn step(
+            mut cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+                return Ok(None);
+            }
+
+            let required = T::WeightInfo::migrate_v2_step();
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            let mut expires_at = frame_system::Pallet::<T>::block_number().saturating_add(T::ClaimLifetime::get());
+            if let Some((_, last_expires_at)) = cursor {
+                expires_at = expires_at.max(last_expires_at);
+            }
+            while meter.try_consume(required).is_ok() {
+                // 游标之前的存证都已转换，从游标之后继续按旧布局读取
+                let mut iter = match cursor {
+                    Some((last, _)) => v1::Proofs::<T>::iter_from(v1::Proofs::<T>::hashed_key_for(last)),
+                    None => v1::Proofs::<T>::iter(),
+                };
+                let Some((claim_hash, (owner, created_at))) = iter.next() else {
+                    meter.consume(T::DbWeight::get().writes(1));
+                    StorageVersion::new(2).put::<Pallet<T>>();
+                    return Ok(None);
+                };
+                index_migrated_claim::<T>(claim_hash, &mut expires_at);
+                Proofs::<T>::insert(claim_hash, ClaimDetails::new(owner, created_at, expires_at, Zero::zero()));
+                cursor = Some((claim_hash, expires_at));
+            }
+            Ok(cursor)
+        }
+    }
+}
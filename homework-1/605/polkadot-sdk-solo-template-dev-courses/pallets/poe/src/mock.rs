@@ -1,13 +1,21 @@
 use crate as pallet_poe;
+use codec::Encode;
 use frame_support::{
-    derive_impl,
-    traits::{ConstU16, ConstU64},
+    derive_impl, ensure, parameter_types,
+    traits::{
+        ConstU16, ConstU64, Currency, EnsureOrigin, FetchResult, MapSuccess, Morph, OnUnbalanced, QueryPreimage,
+        StorePreimage,
+    },
+    weights::constants::RocksDbWeight,
 };
 use sp_core::{ConstU32, H256};
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    testing::{TestSignature, TestXt, UintAuthorityId},
+    traits::{BlakeTwo256, Extrinsic as ExtrinsicT, Hash, IdentityLookup, Keccak256},
+    BuildStorage, DispatchError, DispatchResult, FixedU128, Perbill,
 };
+use std::{borrow::Cow, cell::RefCell, collections::BTreeMap};
+use xcm::latest::{Assets, Junction, Location, SendError, SendResult, SendXcm, Xcm, XcmHash};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -16,6 +24,7 @@ frame_support::construct_runtime!(
     pub enum Test
     {
         System: frame_system,
+        Balances: pallet_balances,
         PoeModule: pallet_poe,
     }
 );
@@ -25,7 +34,7 @@ impl frame_system::Config for Test {
     type BaseCallFilter = frame_support::traits::Everything;
     type BlockWeights = ();
     type BlockLength = ();
-    type DbWeight = ();
+    type DbWeight = RocksDbWeight;
     type RuntimeOrigin = RuntimeOrigin;
     type RuntimeCall = RuntimeCall;
     type Nonce = u64;
@@ -38,7 +47,7 @@ impl frame_system::Config for Test {
     type BlockHashCount = ConstU64<250>;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -47,15 +56,357 @@ impl frame_system::Config for Test {
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+}
+
 impl pallet_poe::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxClaimLength = ConstU32<4>;
+    type MaxTitleLength = ConstU32<16>;
+    type MaxUriLength = ConstU32<32>;
+    type MaxAnnotationLength = ConstU32<16>;
+    type ClaimLifetime = ConstU64<100>;
+    type MaxClaimsPerBlock = MaxClaimsPerBlock;
+    type MaxExpirationsPerBlock = ConstU32<2>;
+    type MaxExpiringPerBlock = ConstU32<64>;
+    type WarningPeriod = WarningPeriod;
+    type Currency = Balances;
+    type RenewalFee = ConstU64<10>;
+    type CreationFee = CreationFee;
+    type OnCreationFee = ToTreasury;
+    type DepositBase = ConstU64<50>;
+    type DepositPerByte = ConstU64<10>;
+    type FiatDeposit = FiatDeposit;
+    type NativeUnit = ConstU64<100>;
+    type DepositEra = ConstU64<50>;
+    type MaxPriceAge = ConstU64<100>;
+    type OfferLifetime = ConstU64<10>;
+    type MaxClaimsPerAccount = ConstU32<3>;
+    type MaxTotalClaims = ConstU32<8>;
+    type MaxBatchSize = ConstU32<3>;
+    type MinRevealDelay = ConstU64<2>;
+    type DisputeBond = ConstU64<100>;
+    type ChallengeWindow = ChallengeWindow;
+    type ArbitrationOrigin = frame_system::EnsureRoot<u64>;
+    type ReportBond = ConstU64<100>;
+    type ReporterReward = ReporterReward;
+    type OnSlash = ToTreasury;
+    type FraudReputationPenalty = ConstU32<5>;
+    type BadgeThreshold = BadgeThreshold;
+    type MaxLicenses = ConstU32<2>;
+    type MaxWatchers = ConstU32<2>;
+    type MaxAnnotations = ConstU32<2>;
+    type AttestersCanAnnotate = AttestersCanAnnotate;
+    type MaxAttestations = ConstU32<2>;
+    type NotaryStake = ConstU64<200>;
+    type MaxHistoryLen = ConstU32<2>;
+    type MaxChildren = ConstU32<2>;
+    type MaxCollectionMetadataLength = ConstU32<16>;
+    type MaxCollectionSize = ConstU32<2>;
+    type MaxBundleSize = ConstU32<3>;
+    type MaxNamespaceLength = ConstU32<4>;
+    type NamespaceDeposit = ConstU64<50>;
+    type NamespaceLifetime = ConstU64<20>;
+    type MaxCoOwners = ConstU32<2>;
+    type MaxSignatories = ConstU32<3>;
+    type MaxGuardians = ConstU32<3>;
+    type MinRecoveryDelay = ConstU64<5>;
+    type MinInactivityPeriod = ConstU64<10>;
+    type MaxScheduledPerBlock = ConstU32<2>;
+    type RevocationDelay = RevocationDelay;
+    type TransferCooldown = TransferCooldown;
+    type SaleFee = SaleFee;
+    type MaxRoyalty = MaxRoyalty;
+    type MaxAuctionsPerBlock = ConstU32<2>;
+    type EscrowPeriod = ConstU64<10>;
+    type AuthorityId = TestAuthId;
+    type UnsignedPriority = ConstU64<100>;
+    type AvailabilityCheckInterval = ConstU64<10>;
+    type MaxAvailabilityChecks = ConstU32<4>;
+    type AnchorInterval = ConstU64<20>;
+    type MaxProofDepth = ConstU32<8>;
+    type ClaimHasher = Keccak256;
+    type ValidateContentIds = ValidateContentIds;
+    type Preimages = TestPreimages;
+    type ClaimPolicy = ClaimHooks;
+    type OnClaimCreated = ClaimHooks;
+    type OnClaimRevoked = ClaimHooks;
+    type NftMirror = TestNftMirror;
+    type XcmSender = TestXcmSender;
+    type RemoteOrigin = TestRemoteOrigin;
+    type XcmOrigin = MapSuccess<TestRemoteOrigin, SiblingAccount>;
+    type ForceOrigin = frame_system::EnsureRoot<u64>;
+    type WeightInfo = ();
+}
+
+/// 测试中链下工作机的签名方案，`UintAuthorityId(n)` 对应账户 `n`
+pub struct TestAuthId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+    type GenericPublic = UintAuthorityId;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = TestXt<RuntimeCall, ()>;
+}
+
+impl<C> frame_system::offchain::CreateSignedTransaction<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    fn create_transaction<A: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        _public: UintAuthorityId,
+        _account: u64,
+        nonce: u64,
+    ) -> Option<(RuntimeCall, <TestXt<RuntimeCall, ()> as ExtrinsicT>::SignaturePayload)> {
+        Some((call, (nonce, ())))
+    }
+}
+
+parameter_types! {
+    /// 默认为 0，保持 `revoke_claim` 立即撤销；测试两步撤销时通过 `RevocationDelay::set` 修改
+    pub static RevocationDelay: u64 = 0;
+    pub const SaleFee: Perbill = Perbill::from_percent(10);
+    pub const MaxRoyalty: Perbill = Perbill::from_percent(20);
+    pub const FiatDeposit: FixedU128 = FixedU128::from_u32(1);
+    pub const ReporterReward: Perbill = Perbill::from_percent(40);
+    /// 默认为 0，避免影响其他测试中的余额；测试创建费用时通过 `CreationFee::set` 修改
+    pub static CreationFee: u64 = 0;
+    /// 默认不限制，避免影响其他测试；测试区块上限时通过 `MaxClaimsPerBlock::set` 修改
+    pub static MaxClaimsPerBlock: u32 = u32::MAX;
+    /// 默认不校验，其他测试可以使用任意内容；测试 CID 校验时通过 `ValidateContentIds::set` 修改
+    pub static ValidateContentIds: bool = false;
+    /// 默认为 0，存证创建后立即确认；测试挑战期时通过 `ChallengeWindow::set` 修改
+    pub static ChallengeWindow: u64 = 0;
+    /// 默认为 0，存证可以连续转移；测试转移冷却期时通过 `TransferCooldown::set` 修改
+    pub static TransferCooldown: u64 = 0;
+    /// 默认为 0，不发出过期提醒，避免影响其他测试中的事件；测试过期提醒时通过 `WarningPeriod::set` 修改
+    pub static WarningPeriod: u64 = 0;
+    /// 默认为 0，不颁发徽章，避免影响其他测试中的事件；测试徽章时通过 `BadgeThreshold::set` 修改
+    pub static BadgeThreshold: u32 = 0;
+    /// 默认允许背书人添加注释；测试只允许所有者注释时通过 `AttestersCanAnnotate::set` 修改
+    pub static AttestersCanAnnotate: bool = true;
+}
+
+/// 接收创建费用的国库账户
+pub const TREASURY: u64 = 99;
+
+/// 把创建费用和罚没的资金存入 `TREASURY` 的测试实现
+pub struct ToTreasury;
+
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Test>> for ToTreasury {
+    fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Test>) {
+        Balances::resolve_creating(&TREASURY, amount);
+    }
 }
 
 // Build genesis storage according to the mock runtime.
+thread_local! {
+    /// `ClaimHooks` 收到的回调，`true` 表示创建，`false` 表示撤销
+    pub static CLAIM_HOOK_CALLS: RefCell<Vec<(bool, u64, H256)>> = RefCell::new(Vec::new());
+}
+
+/// 记录存证回调的测试实现，账户 3 被撤销存证时回调返回错误，账户 4 不允许拥有存证
+pub struct ClaimHooks;
+
+impl pallet_poe::ClaimPolicy<u64, H256> for ClaimHooks {
+    fn can_create(owner: &u64, _claim_hash: &H256) -> DispatchResult {
+        ensure!(*owner != 4, DispatchError::Other("creation rejected by policy"));
+        Ok(())
+    }
+}
+
+impl pallet_poe::OnClaimCreated<u64, H256> for ClaimHooks {
+    fn on_claim_created(owner: &u64, claim_hash: &H256) -> DispatchResult {
+        CLAIM_HOOK_CALLS.with(|calls| calls.borrow_mut().push((true, *owner, *claim_hash)));
+        Ok(())
+    }
+}
+
+impl pallet_poe::OnClaimRevoked<u64, H256> for ClaimHooks {
+    fn on_claim_revoked(owner: &u64, claim_hash: &H256) -> DispatchResult {
+        ensure!(*owner != 3, DispatchError::Other("revoke rejected by hook"));
+        CLAIM_HOOK_CALLS.with(|calls| calls.borrow_mut().push((false, *owner, *claim_hash)));
+        Ok(())
+    }
+}
+
+thread_local! {
+    /// `TestPreimages` 中登记的原文
+    pub static PREIMAGES: RefCell<BTreeMap<H256, Vec<u8>>> = RefCell::new(BTreeMap::new());
+    /// `TestPreimages` 中每个哈希被请求保留的次数
+    pub static PREIMAGE_REQUESTS: RefCell<BTreeMap<H256, u32>> = RefCell::new(BTreeMap::new());
+}
+
+/// 预映像的测试实现，与 `ClaimHasher` 一样使用 Keccak-256，只记录原文和保留请求次数
+pub struct TestPreimages;
+
+impl QueryPreimage for TestPreimages {
+    type H = Keccak256;
+
+    fn len(hash: &H256) -> Option<u32> {
+        PREIMAGES.with(|preimages| preimages.borrow().get(hash).map(|bytes| bytes.len() as u32))
+    }
+
+    fn fetch(hash: &H256, _len: Option<u32>) -> FetchResult {
+        PREIMAGES.with(|preimages| {
+            preimages.borrow().get(hash).map(|bytes| Cow::Owned(bytes.clone())).ok_or(DispatchError::Unavailable)
+        })
+    }
+
+    fn is_requested(hash: &H256) -> bool {
+        PREIMAGE_REQUESTS.with(|requests| requests.borrow().contains_key(hash))
+    }
+
+    fn request(hash: &H256) {
+        PREIMAGE_REQUESTS.with(|requests| *requests.borrow_mut().entry(*hash).or_default() += 1);
+    }
+
+    fn unrequest(hash: &H256) {
+        PREIMAGE_REQUESTS.with(|requests| {
+            let mut requests = requests.borrow_mut();
+            if let Some(count) = requests.get_mut(hash) {
+                *count -= 1;
+                if *count == 0 {
+                    requests.remove(hash);
+                }
+            }
+        });
+    }
+}
+
+impl StorePreimage for TestPreimages {
+    const MAX_LENGTH: usize = usize::MAX;
+
+    fn note(bytes: Cow<[u8]>) -> Result<H256, DispatchError> {
+        let hash = Keccak256::hash(&bytes);
+        PREIMAGES.with(|preimages| preimages.borrow_mut().insert(hash, bytes.into_owned()));
+        Ok(hash)
+    }
+
+    fn unnote(hash: &H256) {
+        PREIMAGES.with(|preimages| preimages.borrow_mut().remove(hash));
+    }
+}
+
+thread_local! {
+    /// `TestNftMirror` 中每个存证对应 NFT 的持有者
+    pub static NFT_OWNERS: RefCell<BTreeMap<H256, u64>> = RefCell::new(BTreeMap::new());
+}
+
+/// 只记录 NFT 持有者的镜像测试实现，测试中直接修改 `NFT_OWNERS` 模拟 NFT 在外部被转移
+pub struct TestNftMirror;
+
+impl pallet_poe::ClaimNftMirror<u64, H256> for TestNftMirror {
+    fn mint(owner: &u64, claim_hash: &H256) -> DispatchResult {
+        NFT_OWNERS.with(|owners| owners.borrow_mut().insert(*claim_hash, *owner));
+        Ok(())
+    }
+
+    fn transfer(claim_hash: &H256, to: &u64) -> DispatchResult {
+        NFT_OWNERS.with(|owners| owners.borrow_mut().insert(*claim_hash, *to));
+        Ok(())
+    }
+
+    fn burn(claim_hash: &H256) {
+        NFT_OWNERS.with(|owners| owners.borrow_mut().remove(claim_hash));
+    }
+
+    fn owner_of(claim_hash: &H256) -> Option<u64> {
+        NFT_OWNERS.with(|owners| owners.borrow().get(claim_hash).copied())
+    }
+}
+
+thread_local! {
+    /// `TestXcmSender` 发送的 XCM 消息
+    pub static SENT_XCM: RefCell<Vec<(Location, Xcm<()>)>> = RefCell::new(Vec::new());
+}
+
+/// 只记录消息的 XCM 路由，可以发送到任意目标
+pub struct TestXcmSender;
+
+impl SendXcm for TestXcmSender {
+    type Ticket = (Location, Xcm<()>);
+
+    fn validate(dest: &mut Option<Location>, message: &mut Option<Xcm<()>>) -> SendResult<Self::Ticket> {
+        let ticket = (dest.take().ok_or(SendError::MissingArgument)?, message.take().ok_or(SendError::MissingArgument)?);
+        Ok((ticket, Assets::new()))
+    }
+
+    fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+        let hash = ticket.1.using_encoded(sp_io::hashing::blake2_256);
+        SENT_XCM.with(|sent| sent.borrow_mut().push(ticket));
+        Ok(hash)
+    }
+}
+
+/// 测试中的 XCM 来源：编号不小于 `1000` 的账户代表同编号的兄弟平行链
+pub struct TestRemoteOrigin;
+
+impl EnsureOrigin<RuntimeOrigin> for TestRemoteOrigin {
+    type Success = Location;
+
+    fn try_origin(origin: RuntimeOrigin) -> Result<Location, RuntimeOrigin> {
+        let raw: Result<frame_system::RawOrigin<u64>, RuntimeOrigin> = origin.into();
+        match raw {
+            Ok(frame_system::RawOrigin::Signed(who)) if who >= 1000 => {
+                Ok(Location::new(1, [Junction::Parachain(who as u32)]))
+            }
+            Ok(raw) => Err(raw.into()),
+            Err(origin) => Err(origin),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::signed(2000))
+    }
+}
+
+/// 测试中兄弟平行链在本链的账户与平行链编号相同
+pub struct SiblingAccount;
+
+impl Morph<Location> for SiblingAccount {
+    type Outcome = u64;
+
+    fn morph(location: Location) -> u64 {
+        pallet_poe::remote::sibling_para(&location).map_or(0, u64::from)
+    }
+}
+
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default()
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
-        .unwrap()
-        .into()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 1_000), (2, 1_000), (3, 1_000)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    t.into()
 }
\ No newline at end of file
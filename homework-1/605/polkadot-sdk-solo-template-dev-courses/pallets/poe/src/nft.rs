@@ -0,0 +1,45 @@
+//! 通过 `nonfungibles_v2` 接口把存证镜像为 `pallet_nfts` 中的 NFT
+//!
+//! 每个存证对应预先创建的集合中的一个物品，物品编号就是存证哈希。集合应只允许本 pallet 铸造，
+//! 否则他人抢先铸造同一编号的物品会使对应的存证无法创建。
+
+use crate::traits::ClaimNftMirror;
+use frame_support::traits::{
+    tokens::nonfungibles_v2::{Inspect, Mutate, Transfer},
+    Get,
+};
+use sp_runtime::DispatchResult;
+use sp_std::marker::PhantomData;
+
+/// 在 `Collection` 集合中为每个存证铸造物品编号为存证哈希的 NFT，物品配置使用 `ItemConfig::default()`，
+/// 铸造押金由存证所有者支付
+pub struct NonfungiblesMirror<Nfts, Collection, ItemConfig>(PhantomData<(Nfts, Collection, ItemConfig)>);
+
+impl<AccountId, Hash, Nfts, Collection, ItemConfig> ClaimNftMirror<AccountId, Hash>
+    for NonfungiblesMirror<Nfts, Collection, ItemConfig>
+where
+    AccountId: PartialEq,
+    Nfts: Inspect<AccountId, ItemId = Hash> + Mutate<AccountId, ItemConfig> + Transfer<AccountId>,
+    Collection: Get<Nfts::CollectionId>,
+    ItemConfig: Default,
+{
+    fn mint(owner: &AccountId, claim_hash: &Hash) -> DispatchResult {
+        Nfts::mint_into(&Collection::get(), claim_hash, owner, &ItemConfig::default(), false)
+    }
+
+    fn transfer(claim_hash: &Hash, to: &AccountId) -> DispatchResult {
+        let collection = Collection::get();
+        if Nfts::owner(&collection, claim_hash).as_ref() == Some(to) {
+            return Ok(());
+        }
+        Nfts::transfer(&collection, claim_hash, to)
+    }
+
+    fn burn(claim_hash: &Hash) {
+        let _ = Nfts::burn(&Collection::get(), claim_hash, None);
+    }
+
+    fn owner_of(claim_hash: &Hash) -> Option<AccountId> {
+        Nfts::owner(&Collection::get(), claim_hash)
+    }
+}
@@ -0,0 +1,33 @@
+//! 链下工作机喂价时使用的价格解析
+//!
+//! 运行时环境中没有 JSON 库，价格接口只需返回一个数字或只包含一个数字的 JSON，
+//! 例如 `{"USD":0.52}`，这里取出响应中的第一个十进制数作为一个完整原生代币的法币单价。
+
+use sp_runtime::{FixedPointNumber, FixedU128};
+
+/// 取出响应中的第一个十进制数，最多保留 18 位小数；没有数字、数值为 0 或溢出时返回 `None`
+pub(crate) fn parse_price(body: &[u8]) -> Option<FixedU128> {
+    let start = body.iter().position(u8::is_ascii_digit)?;
+    let rest = &body[start..];
+
+    let int_len = rest.iter().position(|byte| !byte.is_ascii_digit()).unwrap_or(rest.len());
+    let integer = digits_value(&rest[..int_len])?;
+
+    let fraction = match rest[int_len..].strip_prefix(b".") {
+        Some(frac) => {
+            let frac_len = frac.iter().position(|byte| !byte.is_ascii_digit()).unwrap_or(frac.len());
+            let kept = &frac[..frac_len.min(18)];
+            digits_value(kept)?.checked_mul(10u128.pow(18 - kept.len() as u32))?
+        },
+        None => 0,
+    };
+
+    let inner = integer.checked_mul(FixedU128::DIV)?.checked_add(fraction)?;
+    (inner > 0).then(|| FixedU128::from_inner(inner))
+}
+
+fn digits_value(digits: &[u8]) -> Option<u128> {
+    digits
+        .iter()
+        .try_fold(0u128, |value, digit| value.checked_mul(10)?.checked_add((digit - b'0') as u128))
+}
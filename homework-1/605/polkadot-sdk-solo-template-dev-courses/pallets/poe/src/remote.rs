@@ -0,0 +1,31 @@
+//! 通过 XCM 在兄弟平行链之间转移存证
+//!
+//! 源链的 `transfer_claim_to_para` 删除本地存证，并向目标链发送 `UnpaidExecution` + `Transact`，
+//! 以 `OriginKind::Xcm` 调用目标链本 pallet 的 `force_register_remote_claim`。两条链需要以相同的
+//! pallet 索引部署本 pallet，并在 `TrustedParas` 中互相登记。
+//!
+//! 目标链的 XCM 执行器配置中，屏障和来源转换都可以使用 [`IsTrustedSibling`] 过滤来源：
+//! `AllowExplicitUnpaidExecutionFrom<IsTrustedSibling<Runtime>>` 放行可信链的免费执行，
+//! `RemoteOrigin` 设为 `pallet_xcm::EnsureXcm<IsTrustedSibling<Runtime>>`。
+
+use crate::{Config, TrustedParas};
+use frame_support::traits::Contains;
+use sp_std::marker::PhantomData;
+use xcm::latest::{Junction, Location};
+
+/// 位置为兄弟平行链 `../Parachain(id)` 时返回平行链编号
+pub fn sibling_para(location: &Location) -> Option<u32> {
+    match location.unpack() {
+        (1, [Junction::Parachain(id)]) => Some(*id),
+        _ => None,
+    }
+}
+
+/// 只接受 `TrustedParas` 中登记的兄弟平行链
+pub struct IsTrustedSibling<T>(PhantomData<T>);
+
+impl<T: Config> Contains<Location> for IsTrustedSibling<T> {
+    fn contains(location: &Location) -> bool {
+        sibling_para(location).map_or(false, TrustedParas::<T>::contains_key)
+    }
+}
@@ -1,8 +1,44 @@
-
-use crate::{mock::*, Error, Proofs};
-use frame_support::{assert_noop, assert_ok};
-use sp_core::ConstU32;
-use sp_runtime::BoundedVec;
+use crate::{
+    cid, migrations, mock::*, activity_key, ANCHOR_ACCOUNT_KEY, ANCHOR_ENDPOINT_KEY,
+    ActiveRecoveries, ActivityCount, ActivityKind, ActivityRecord, AnchorRecord, Anchors,
+    Annotation, Annotations, Approvals, AttestationCount, Attestations, AuctionEnds, Auctions,
+    AuthorKey, AuthorSignature, Badge, Badges, Banned, BundleClaims, Bundles, BannedAccounts,
+    BatchRoot, BatchRoots, Children, ClaimAuthors, ClaimBundle, ClaimCollateral, ClaimCollection,
+    ClaimCountOf, ClaimDetails, ClaimNftMirror, ClaimPayload, ClaimStats, ClaimStatus,
+    ClaimsThisBlock, ConfirmationCredits, ConfirmationQueue, ConfirmedClaimCount, CoOwnerAction,
+    CoOwnerApprovals, CoOwners, CollateralLocks, CollectionClaims, Collections, CurrentMaxLength,
+    DeduplicateClaims, DisputeResolution, Disputes, EraDepositBase, Error, Escrows, ExpiringAt,
+    ExpiryBacklog, FeeExempt, FraudReport, Inheritors, License, LicenseCount, LicenseFees, Licenses,
+    Listing, Listings, MerkleProof, Namespaces, NativePrice, Notaries, Notarizations, Operators,
+    OwnerClaims, PENDING_CLAIMS_KEY, PRICE_ENDPOINT_KEY, ParentOf, PendingRevocations,
+    PendingTransfers, PriceReport, ProofProvider, Proofs, ProvisionalClaims, RecoveryConfigs,
+    Reports, Reputation, RetainedPreimages, RevocationApprovals, RevocationGuards, Royalties,
+    ScheduledTransfers, SupersededBy, Supersedes, TransferQueue, Vesting, Vestings, Watchers,
+    WeightInfo,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{DispatchClass, GetDispatchInfo, Pays, WithPostDispatchInfo},
+    migrations::SteppedMigration,
+    traits::{
+        tokens::nonfungibles_v2::{Inspect, Transfer},
+        Currency, GetStorageVersion, Hooks, OnRuntimeUpgrade, QueryPreimage, StorageVersion, StorePreimage,
+    },
+    weights::{constants::RocksDbWeight, Weight, WeightMeter},
+};
+use sp_core::{
+    ed25519,
+    offchain::{testing, OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt},
+    sr25519, ConstU32, Pair, H256,
+};
+use sp_runtime::{
+    testing::{TestXt, UintAuthorityId},
+    traits::{Hash, SignedExtension, ValidateUnsigned},
+    transaction_validity::{InvalidTransaction, TransactionSource},
+    BoundedVec, BuildStorage, DispatchError, FixedU128, Perbill, RuntimeAppPublic,
+};
+use xcm::latest::{Instruction, Junction, Location, OriginKind};
 
 /// 测试成功创建存证
 #[test]
@@ -16,14 +52,91 @@ fn test_create_claim_success() {
         claim.try_push(255_u8).expect("Failed to push");
         claim.try_push(255_u8).expect("Failed to push");
         claim.try_push(255_u8).expect("Failed to push");
-        println!("claim: {:?}", claim);
-        println!("cliam.len: {:?}", claim.len());
-        
+
         assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
 
-        let (accout_id, block_number) = Proofs::<Test>::get(claim).unwrap();
-        println!("accout_id: {}, block_number: {}", accout_id, block_number);
-        assert_eq!(accout_id, 1);
+        let details = Proofs::<Test>::get(PoeModule::claim_hash(&claim)).unwrap();
+        assert_eq!(details.owner, 1);
+    });
+}
+
+/// 测试存证生命周期事件带有具名字段和所在区块号
+#[test]
+fn test_claim_lifecycle_events() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(3);
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        System::assert_last_event(crate::Event::ClaimCreated { who: 1, claim_hash, block: 3 }.into());
+
+        System::set_block_number(5);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash));
+        System::assert_last_event(crate::Event::ClaimTransfered { who: 1, target: 2, claim_hash, block: 5 }.into());
+
+        System::set_block_number(8);
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim_hash));
+        System::assert_last_event(crate::Event::ClaimRevoked { who: 2, claim_hash, block: 8 }.into());
+    });
+}
+
+/// 测试存证事件以存证哈希为索引主题，可以按主题查到单个存证的全部事件
+#[test]
+fn test_claim_event_topics() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(3);
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        let other: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![3, 4]).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), other));
+        System::set_block_number(5);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash));
+        assert_eq!(System::events().last().unwrap().topics, vec![claim_hash]);
+
+        let blocks: Vec<u64> = frame_system::EventTopics::<Test>::get(claim_hash)
+            .into_iter()
+            .map(|(block, _)| block)
+            .collect();
+        assert_eq!(blocks, vec![3, 5]);
+
+        // 与存证无关的事件不带主题
+        assert_ok!(PoeModule::pause(RuntimeOrigin::root()));
+        assert!(System::events().last().unwrap().topics.is_empty());
+    });
+}
+
+/// 测试存证统计：调用更新本区块的统计，区块结束时保存快照并计入累计值
+#[test]
+fn test_claim_stats() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let claim_a: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1]).unwrap();
+        let claim_b: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![2]).unwrap();
+        let hash_a = PoeModule::claim_hash(&claim_a);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim_a));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim_b));
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, hash_a));
+        assert_eq!(PoeModule::cumulative_stats(), ClaimStats::default());
+
+        PoeModule::on_finalize(1);
+        let first = ClaimStats { created: 2, revoked: 0, transferred: 1 };
+        assert_eq!(PoeModule::last_block_stats(), first);
+        assert_eq!(PoeModule::cumulative_stats(), first);
+
+        System::set_block_number(2);
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), hash_a));
+        PoeModule::on_finalize(2);
+        assert_eq!(PoeModule::last_block_stats(), ClaimStats { created: 0, revoked: 1, transferred: 0 });
+        assert_eq!(PoeModule::cumulative_stats(), ClaimStats { created: 2, revoked: 1, transferred: 1 });
+
+        // 没有活动的区块快照为空，累计值不变
+        System::set_block_number(3);
+        PoeModule::on_finalize(3);
+        assert_eq!(PoeModule::last_block_stats(), ClaimStats::default());
+        assert_eq!(PoeModule::cumulative_stats(), ClaimStats { created: 2, revoked: 1, transferred: 1 });
     });
 }
 
@@ -44,7 +157,7 @@ fn test_create_claim_failure() {
         // 再次创建相同的存证，应该失败
         assert_noop!(
             PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()),
-            Error::<Test>::ProofAlreadyExist
+            Error::<Test>::ProofAlreadyExist.with_weight(RocksDbWeight::get().reads(2))
         );
     });
 }
@@ -64,7 +177,8 @@ fn test_revoke_claim_success() {
         // 创建存证
         assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
         // 撤销存证
-        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()));
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), PoeModule::claim_hash(&claim)));
+        assert!(!Proofs::<Test>::contains_key(PoeModule::claim_hash(&claim)));
     });
 }
 
@@ -84,7 +198,7 @@ fn test_revoke_claim_failure() {
 
         // 尝试撤销其他账户的存证，应该失败
         assert_noop!(
-            PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim.clone()),
+            PoeModule::revoke_claim(RuntimeOrigin::signed(2), PoeModule::claim_hash(&claim)),
             Error::<Test>::NotClaimOwner
         );
 
@@ -96,7 +210,7 @@ fn test_revoke_claim_failure() {
         claim2.try_push(4_u8).expect("Failed to push");
 
         assert_noop!(
-            PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim2.clone()),
+            PoeModule::revoke_claim(RuntimeOrigin::signed(1), PoeModule::claim_hash(&claim2)),
             Error::<Test>::ClaimNotExist
         );
     });
@@ -116,10 +230,10 @@ fn test_transfer_claim_success() {
 
         assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
 
-        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim.clone()));
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, PoeModule::claim_hash(&claim)));
 
-        let (accout_id, _block_number) = Proofs::<Test>::get(claim).unwrap();
-        assert_eq!(accout_id, 2);
+        let details = Proofs::<Test>::get(PoeModule::claim_hash(&claim)).unwrap();
+        assert_eq!(details.owner, 2);
     });
 }
 
@@ -139,7 +253,7 @@ fn test_transfer_claim_failure() {
 
         // 尝试转移其他账户的存证，应该失败
         assert_noop!(
-            PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, claim.clone()),
+            PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, PoeModule::claim_hash(&claim)),
             Error::<Test>::NotClaimOwner
         );
 
@@ -151,8 +265,4046 @@ fn test_transfer_claim_failure() {
         claim2.try_push(4_u8).expect("Failed to push");
 
         assert_noop!(
-            PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, claim2.clone()),
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, PoeModule::claim_hash(&claim2)),
+            Error::<Test>::ClaimNotExist
+        );
+    });
+}
+
+/// 测试使用预先计算的哈希创建存证
+#[test]
+fn test_create_claim_by_hash() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // 哈希不受 MaxClaimLength 限制，对应的原文可以任意长
+        let claim_hash = PoeModule::claim_hash(&[7_u8; 1024]);
+
+        assert_ok!(PoeModule::create_claim_by_hash(RuntimeOrigin::signed(1), claim_hash));
+        assert_eq!(Proofs::<Test>::get(claim_hash), Some(ClaimDetails::new(1, 1, 101, 90)));
+
+        assert_noop!(
+            PoeModule::create_claim_by_hash(RuntimeOrigin::signed(2), claim_hash),
+            Error::<Test>::ProofAlreadyExist.with_weight(RocksDbWeight::get().reads(2))
+        );
+    });
+}
+
+/// 测试 v0 -> v1 -> v2 迁移：存证原文的键被替换为哈希，元组被替换为结构体
+#[test]
+fn test_migrations() {
+    new_test_ext().execute_with(|| {
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        migrations::v0::Proofs::<Test>::insert(&claim, (1, 5));
+        StorageVersion::new(0).put::<PoeModule>();
+
+        migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(migrations::v1::Proofs::<Test>::get(PoeModule::claim_hash(&claim)), Some((1, 5)));
+        assert_eq!(PoeModule::claims_of(&1), vec![PoeModule::claim_hash(&claim)]);
+        assert_eq!(ClaimCountOf::<Test>::get(1), 1);
+        assert_eq!(PoeModule::total_claims(), 1);
+        assert!(!migrations::v0::Proofs::<Test>::contains_key(&claim));
+        assert_eq!(PoeModule::on_chain_storage_version(), 1);
+
+        // 继续执行 v1 -> v2，元组被替换为 ClaimDetails
+        migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Proofs::<Test>::get(PoeModule::claim_hash(&claim)), Some(ClaimDetails::new(1, 5, 100, 0)));
+        assert_eq!(ExpiringAt::<Test>::get(100).into_inner(), vec![PoeModule::claim_hash(&claim)]);
+        assert_eq!(PoeModule::on_chain_storage_version(), 2);
+    });
+}
+
+/// 测试 v2 迁移写入过期索引，区块的索引已满时顺延到下一个区块
+#[test]
+fn test_migration_spreads_expiry_index() {
+    new_test_ext().execute_with(|| {
+        for i in 0..3u8 {
+            migrations::v1::Proofs::<Test>::insert(PoeModule::claim_hash(&[i]), (1, 5));
+        }
+        let existing: Vec<_> = (0..63u32).map(|i| PoeModule::claim_hash(&i.to_le_bytes())).collect();
+        ExpiringAt::<Test>::insert(100, BoundedVec::try_from(existing).unwrap());
+        StorageVersion::new(1).put::<PoeModule>();
+
+        migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(ExpiringAt::<Test>::get(100).len(), 64);
+        assert_eq!(ExpiringAt::<Test>::get(101).len(), 2);
+        for (claim_hash, details) in Proofs::<Test>::iter() {
+            assert!(ExpiringAt::<Test>::get(details.expires_at).contains(&claim_hash));
+        }
+    });
+}
+
+/// 测试迁移只在存储版本匹配时执行，重复执行不会改动存储
+#[test]
+fn test_migrations_check_storage_version() {
+    new_test_ext().execute_with(|| {
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        migrations::v0::Proofs::<Test>::insert(&claim, (1, 5));
+        StorageVersion::new(2).put::<PoeModule>();
+
+        // 版本不是 0，v0 数据保持原样
+        assert_eq!(migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade(), RocksDbWeight::get().reads(1));
+        assert!(migrations::v0::Proofs::<Test>::contains_key(&claim));
+        assert_eq!(PoeModule::total_claims(), 0);
+
+        StorageVersion::new(0).put::<PoeModule>();
+        migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+        migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+        let details = Proofs::<Test>::get(PoeModule::claim_hash(&claim)).unwrap();
+
+        // 再次执行两个迁移都被跳过
+        migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+        migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+        assert_eq!(Proofs::<Test>::get(PoeModule::claim_hash(&claim)), Some(details));
+        assert_eq!(PoeModule::total_claims(), 1);
+        assert_eq!(PoeModule::on_chain_storage_version(), 2);
+    });
+}
+
+/// 测试多区块迁移：每一步只转换权重允许的存证数量，游标跨步延续，全部完成后更新存储版本
+#[test]
+fn test_lazy_migration() {
+    new_test_ext().execute_with(|| {
+        let mut hashes = Vec::new();
+        for i in 0..5u8 {
+            let claim_hash = PoeModule::claim_hash(&[i]);
+            migrations::v1::Proofs::<Test>::insert(claim_hash, (1, 5));
+            hashes.push(claim_hash);
+        }
+        StorageVersion::new(1).put::<PoeModule>();
+
+        // 权重不足一步时报错
+        let step = <Test as crate::Config>::WeightInfo::migrate_v2_step();
+        let mut meter = WeightMeter::with_limit(Weight::zero());
+        assert!(migrations::v2::LazyMigrateToV2::<Test>::step(None, &mut meter).is_err());
+
+        // 每步转换两个存证
+        let limit = step.saturating_mul(2).saturating_add(RocksDbWeight::get().writes(1));
+        let mut cursor = None;
+        let mut steps = 0;
+        loop {
+            let mut meter = WeightMeter::with_limit(limit);
+            cursor = migrations::v2::LazyMigrateToV2::<Test>::step(cursor, &mut meter).unwrap();
+            steps += 1;
+            let converted = hashes.iter().filter(|hash| Proofs::<Test>::get(hash).is_some()).count();
+            match cursor {
+                Some(_) => {
+                    assert_eq!(converted, 2 * steps);
+                    assert_eq!(PoeModule::on_chain_storage_version(), 1);
+                }
+                None => break,
+            }
+        }
+        assert_eq!(steps, 3);
+        for hash in hashes {
+            assert_eq!(Proofs::<Test>::get(hash), Some(ClaimDetails::new(1, 5, 100, 0)));
+            assert!(ExpiringAt::<Test>::get(100).contains(&hash));
+        }
+        assert_eq!(PoeModule::on_chain_storage_version(), 2);
+
+        // 版本已更新，再次执行直接结束
+        let mut meter = WeightMeter::new();
+        assert!(matches!(migrations::v2::LazyMigrateToV2::<Test>::step(None, &mut meter), Ok(None)));
+    });
+}
+
+/// 测试迁移的 pre_upgrade / post_upgrade 检查
+#[cfg(feature = "try-runtime")]
+#[test]
+fn test_migrations_try_runtime_checks() {
+    new_test_ext().execute_with(|| {
+        for i in 0..3u8 {
+            migrations::v0::Proofs::<Test>::insert(BoundedVec::try_from(vec![i]).unwrap(), (1, 5));
+        }
+        StorageVersion::new(0).put::<PoeModule>();
+
+        assert_ok!(migrations::v1::MigrateToV1::<Test>::try_on_runtime_upgrade(true));
+        assert_ok!(migrations::v2::MigrateToV2::<Test>::try_on_runtime_upgrade(true));
+        assert_eq!(PoeModule::on_chain_storage_version(), 2);
+        assert_ok!(PoeModule::do_try_state());
+    });
+}
+
+/// 测试 OwnerClaims 反向索引随创建、转移、撤销同步更新
+#[test]
+fn test_owner_claims_index() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        assert_eq!(PoeModule::claims_of(&1), vec![claim_hash]);
+
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash));
+        assert!(PoeModule::claims_of(&1).is_empty());
+        assert!(OwnerClaims::<Test>::contains_key(2, claim_hash));
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim_hash));
+        assert!(PoeModule::claims_of(&2).is_empty());
+    });
+}
+
+/// 测试设置存证元数据
+#[test]
+fn test_set_claim_metadata() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        let title: BoundedVec<u8, ConstU32<16>> = BoundedVec::try_from(b"paper".to_vec()).unwrap();
+        let uri: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(b"ipfs://x".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+
+        // 只有所有者可以设置元数据
+        assert_noop!(
+            PoeModule::set_claim_metadata(RuntimeOrigin::signed(2), claim_hash, Some(title.clone()), None),
+            Error::<Test>::NotClaimOwner
+        );
+
+        assert_ok!(PoeModule::set_claim_metadata(
+            RuntimeOrigin::signed(1),
+            claim_hash,
+            Some(title.clone()),
+            Some(uri.clone())
+        ));
+
+        let details = Proofs::<Test>::get(claim_hash).unwrap();
+        assert_eq!(details.title, Some(title));
+        assert_eq!(details.uri, Some(uri));
+        assert_eq!(details.created_at, 1);
+    });
+}
+
+/// 测试存证过期后视为不存在，并且可以被重新创建
+#[test]
+fn test_claim_expiration() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().expires_at, 101);
+
+        // 到达过期区块后，撤销和转移都视为存证不存在
+        System::set_block_number(101);
+        assert_noop!(
+            PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash),
+            Error::<Test>::ClaimNotExist
+        );
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash),
             Error::<Test>::ClaimNotExist
         );
+
+        // 其他账户可以重新创建该存证，旧记录被清理
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), claim));
+        System::assert_has_event(
+            crate::Event::ClaimExpired { who: 1, claim_hash, block: System::block_number() }.into(),
+        );
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 2);
+        assert!(PoeModule::claims_of(&1).is_empty());
+    });
+}
+
+/// 测试 on_initialize 按过期索引分批清理到期的存证，未处理完的索引留到之后的区块
+#[test]
+fn test_on_initialize_sweeps_expired_claims() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for i in 0..3_u8 {
+            let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![i]).unwrap();
+            assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        }
+        // 第 50 块创建的存证在第 101 块时仍然有效，不会被检查
+        System::set_block_number(50);
+        let live: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![9]).unwrap();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), live.clone()));
+        assert_eq!(ExpiringAt::<Test>::get(101).len(), 3);
+
+        // 每个区块最多检查 MaxExpirationsPerBlock(2) 个索引条目和存证，第 101 块的索引需要三个区块处理完
+        for now in 101..=103 {
+            System::set_block_number(now);
+            PoeModule::on_initialize(now);
+            assert_eq!(Proofs::<Test>::iter().count() as u64, 4 - (now - 100));
+        }
+        assert!(!ExpiringAt::<Test>::contains_key(101));
+        assert_eq!(ExpiryBacklog::<Test>::get(), Some(102));
+
+        // 追上积压的空索引后清空积压
+        System::set_block_number(104);
+        PoeModule::on_initialize(104);
+        System::set_block_number(105);
+        PoeModule::on_initialize(105);
+        assert_eq!(ExpiryBacklog::<Test>::get(), None);
+
+        assert_eq!(Proofs::<Test>::iter().count(), 1);
+        assert!(Proofs::<Test>::contains_key(PoeModule::claim_hash(&live)));
+        assert!(PoeModule::claims_of(&1).is_empty());
+        assert_eq!(
+            System::events()
+                .iter()
+                .filter(|r| matches!(r.event, RuntimeEvent::PoeModule(crate::Event::ClaimExpired { .. })))
+                .count(),
+            3
+        );
+    });
+}
+
+/// 测试续期和撤销时更新过期索引
+#[test]
+fn test_expiry_index_maintenance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_a = PoeModule::claim_hash(&[1]);
+        let claim_b = PoeModule::claim_hash(&[2]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![2]).unwrap()));
+        assert_eq!(ExpiringAt::<Test>::get(101).into_inner(), vec![claim_a, claim_b]);
+
+        assert_ok!(PoeModule::renew_claim(RuntimeOrigin::signed(1), claim_a));
+        assert_eq!(ExpiringAt::<Test>::get(101).into_inner(), vec![claim_b]);
+        assert_eq!(ExpiringAt::<Test>::get(201).into_inner(), vec![claim_a]);
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_b));
+        assert!(!ExpiringAt::<Test>::contains_key(101));
+
+        // 续期后的存证在新的过期区块被清理
+        System::set_block_number(101);
+        PoeModule::on_initialize(101);
+        assert!(Proofs::<Test>::contains_key(claim_a));
+        System::set_block_number(201);
+        PoeModule::on_initialize(201);
+        assert!(!Proofs::<Test>::contains_key(claim_a));
+        assert!(!ExpiringAt::<Test>::contains_key(201));
+        assert_eq!(ExpiryBacklog::<Test>::get(), None);
+    });
+}
+
+/// 测试 on_idle 按剩余权重清理过期存证，且不超过给定的权重
+#[test]
+fn test_on_idle_respects_weight_budget() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for i in 0..3_u8 {
+            let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![i]).unwrap();
+            assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        }
+
+        System::set_block_number(101);
+
+        // 权重不足以支付游标的固定开销时什么都不做
+        assert_eq!(PoeModule::on_idle(101, Weight::zero()), Weight::zero());
+        assert_eq!(Proofs::<Test>::iter().count(), 3);
+
+        // 固定开销 + 一个存证的清理开销
+        let budget = RocksDbWeight::get().reads_writes(31, 62);
+        let used = PoeModule::on_idle(101, budget);
+        assert!(used.all_lte(budget));
+        assert_eq!(Proofs::<Test>::iter().count(), 2);
+
+        // 从游标处继续，清理掉剩余的存证
+        PoeModule::on_idle(101, budget.saturating_mul(2));
+        assert_eq!(Proofs::<Test>::iter().count(), 0);
+        assert!(PoeModule::claims_of(&1).is_empty());
+    });
+}
+
+/// 测试续期存证：过期时间延长，并收取续期费用
+#[test]
+fn test_renew_claim() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+
+        assert_noop!(
+            PoeModule::renew_claim(RuntimeOrigin::signed(2), claim_hash),
+            Error::<Test>::NotClaimOwner
+        );
+
+        System::set_block_number(90);
+        assert_ok!(PoeModule::renew_claim(RuntimeOrigin::signed(1), claim_hash));
+
+        let details = Proofs::<Test>::get(claim_hash).unwrap();
+        assert_eq!(details.expires_at, 201);
+        assert_eq!(details.created_at, 1);
+        // 1000 - 押金 90 - 续期费 10
+        assert_eq!(Balances::free_balance(1), 900);
+        System::assert_last_event(crate::Event::ClaimRenewed { who: 1, claim_hash, expires_at: 201 }.into());
+    });
+}
+
+/// 测试押金：创建时保留，转移时转给新所有者，撤销时退还
+#[test]
+fn test_claim_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+
+        // 50 + 10 * 4
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        assert_eq!(Balances::reserved_balance(1), 90);
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().deposit, 90);
+
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 910);
+        assert_eq!(Balances::reserved_balance(2), 90);
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim_hash));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(2), 1_090);
+    });
+}
+
+/// 测试押金按存证长度计算
+#[test]
+fn test_claim_deposit_scales_with_length() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let short: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1]).unwrap();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), short.clone()));
+        assert_eq!(Proofs::<Test>::get(PoeModule::claim_hash(&short)).unwrap().deposit, 60);
+        assert_eq!(Balances::reserved_balance(1), 60);
+    });
+}
+
+/// 测试余额不足以支付押金时无法创建存证
+#[test]
+fn test_create_claim_without_deposit_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+
+        // 账户 4 没有余额
+        assert!(PoeModule::create_claim(RuntimeOrigin::signed(4), claim.clone()).is_err());
+        assert!(!Proofs::<Test>::contains_key(PoeModule::claim_hash(&claim)));
+    });
+}
+
+/// 测试两阶段转移：接收方接受后所有权才转移
+#[test]
+fn test_offer_and_accept_claim() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+
+        assert_noop!(
+            PoeModule::offer_claim(RuntimeOrigin::signed(2), claim_hash, 3),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim_hash, 2));
+        // 发出邀请后所有权不变
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 1);
+
+        assert_noop!(
+            PoeModule::accept_claim(RuntimeOrigin::signed(3), claim_hash),
+            Error::<Test>::NotOfferTarget
+        );
+        assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(2), claim_hash));
+
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 2);
+        assert_eq!(Balances::reserved_balance(2), 90);
+        assert!(!PendingTransfers::<Test>::contains_key(claim_hash));
+        assert_noop!(
+            PoeModule::accept_claim(RuntimeOrigin::signed(2), claim_hash),
+            Error::<Test>::OfferNotExist
+        );
+    });
+}
+
+/// 测试转移邀请的过期和取消
+#[test]
+fn test_offer_expiry_and_cancel() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim_hash, 2));
+
+        System::set_block_number(11);
+        assert_noop!(
+            PoeModule::accept_claim(RuntimeOrigin::signed(2), claim_hash),
+            Error::<Test>::OfferExpired
+        );
+
+        assert_noop!(
+            PoeModule::cancel_offer(RuntimeOrigin::signed(2), claim_hash),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(PoeModule::cancel_offer(RuntimeOrigin::signed(1), claim_hash));
+        assert!(!PendingTransfers::<Test>::contains_key(claim_hash));
+    });
+}
+
+/// 测试被授权账户代为转移存证，转移后授权被清除
+#[test]
+fn test_approve_and_transfer_claim_from() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+
+        assert_noop!(
+            PoeModule::transfer_claim_from(RuntimeOrigin::signed(2), claim_hash, 3),
+            Error::<Test>::NotApproved
+        );
+        assert_noop!(
+            PoeModule::approve_transfer(RuntimeOrigin::signed(2), claim_hash, 2),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(PoeModule::approve_transfer(RuntimeOrigin::signed(1), claim_hash, 2));
+        assert_eq!(Approvals::<Test>::get(claim_hash), Some(2));
+
+        assert_ok!(PoeModule::transfer_claim_from(RuntimeOrigin::signed(2), claim_hash, 3));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 3);
+        assert_eq!(Approvals::<Test>::get(claim_hash), None);
+        assert_noop!(
+            PoeModule::transfer_claim_from(RuntimeOrigin::signed(2), claim_hash, 1),
+            Error::<Test>::NotApproved
+        );
+    });
+}
+
+/// 测试取消授权以及撤销存证时授权被清除
+#[test]
+fn test_cancel_approval() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+
+        assert_noop!(
+            PoeModule::cancel_approval(RuntimeOrigin::signed(1), claim_hash),
+            Error::<Test>::NotApproved
+        );
+        assert_ok!(PoeModule::approve_transfer(RuntimeOrigin::signed(1), claim_hash, 2));
+        assert_ok!(PoeModule::cancel_approval(RuntimeOrigin::signed(1), claim_hash));
+        assert_eq!(Approvals::<Test>::get(claim_hash), None);
+
+        assert_ok!(PoeModule::approve_transfer(RuntimeOrigin::signed(1), claim_hash, 2));
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert_eq!(Approvals::<Test>::get(claim_hash), None);
+    });
+}
+
+/// 测试操作员可以转移和撤销所有者的存证，取消授权后不再可以
+#[test]
+fn test_operator_manages_claims() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        let other: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![5, 6]).unwrap();
+        let other_hash = PoeModule::claim_hash(&other);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), other));
+
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, claim_hash),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(PoeModule::set_operator(RuntimeOrigin::signed(1), 2, true));
+        assert!(Operators::<Test>::contains_key(1, 2));
+
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, claim_hash));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 3);
+        // 操作员只对授权它的所有者有效
+        assert_noop!(
+            PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim_hash),
+            Error::<Test>::NotClaimOwner
+        );
+
+        assert_ok!(PoeModule::set_operator(RuntimeOrigin::signed(1), 2, false));
+        assert_noop!(
+            PoeModule::revoke_claim(RuntimeOrigin::signed(2), other_hash),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(PoeModule::set_operator(RuntimeOrigin::signed(1), 2, true));
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), other_hash));
+        assert_eq!(Proofs::<Test>::get(other_hash), None);
+    });
+}
+
+/// 测试管理员强制撤销和强制转移存证
+#[test]
+fn test_force_revoke_and_transfer_claim() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+
+        // 所有者本人也不能使用强制调用
+        assert_noop!(
+            PoeModule::force_transfer_claim(RuntimeOrigin::signed(1), claim_hash, 2),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(PoeModule::force_transfer_claim(RuntimeOrigin::root(), claim_hash, 2));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 2);
+        assert_eq!(Balances::reserved_balance(2), 90);
+
+        assert_noop!(
+            PoeModule::force_revoke_claim(RuntimeOrigin::signed(2), claim_hash),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(PoeModule::force_revoke_claim(RuntimeOrigin::root(), claim_hash));
+        assert_eq!(Proofs::<Test>::get(claim_hash), None);
+        assert_eq!(Balances::reserved_balance(2), 0);
+    });
+}
+
+/// 测试暂停后所有调用失败，恢复后正常
+#[test]
+fn test_pause_and_unpause() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+
+        assert_noop!(PoeModule::pause(RuntimeOrigin::signed(1)), DispatchError::BadOrigin);
+        assert_ok!(PoeModule::pause(RuntimeOrigin::root()));
+
+        assert_noop!(
+            PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash),
+            Error::<Test>::PalletPaused
+        );
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash),
+            Error::<Test>::PalletPaused
+        );
+        assert_noop!(
+            PoeModule::force_revoke_claim(RuntimeOrigin::root(), claim_hash),
+            Error::<Test>::PalletPaused
+        );
+        assert_noop!(
+            PoeModule::set_trusted_para(RuntimeOrigin::root(), 2000, true),
+            Error::<Test>::PalletPaused
+        );
+        assert_noop!(
+            PoeModule::force_register_remote_claim(RuntimeOrigin::signed(2000), PoeModule::claim_hash(&[9]), 5),
+            Error::<Test>::PalletPaused
+        );
+
+        assert_ok!(PoeModule::unpause(RuntimeOrigin::root()));
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+    });
+}
+
+/// 测试每个账户的存证数量上限，撤销和转移会更新计数
+#[test]
+fn test_claim_quota() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for i in 0..3_u8 {
+            let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![i]).unwrap();
+            assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        }
+        assert_eq!(ClaimCountOf::<Test>::get(1), 3);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![3]).unwrap();
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()),
+            Error::<Test>::TooManyClaims
+        );
+
+        // 已满的账户也不能接收转移
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone()));
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(2), 1, claim_hash),
+            Error::<Test>::TooManyClaims
+        );
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), PoeModule::claim_hash(&[0])));
+        assert_eq!(ClaimCountOf::<Test>::get(1), 2);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(2), 1, claim_hash));
+        assert_eq!(ClaimCountOf::<Test>::get(1), 3);
+        assert_eq!(ClaimCountOf::<Test>::get(2), 0);
+    });
+}
+
+/// 测试创世配置预置的存证
+#[test]
+fn test_genesis_claims() {
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    crate::GenesisConfig::<Test> { claims: vec![(vec![1, 2, 3, 4], 1), (vec![5], 2)], ..Default::default() }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+    sp_io::TestExternalities::from(t).execute_with(|| {
+        let claim_hash = PoeModule::claim_hash(&[1, 2, 3, 4]);
+        assert_eq!(Proofs::<Test>::get(claim_hash), Some(ClaimDetails::new(1, 0, 100, 0)));
+        assert_eq!(PoeModule::claims_of(&2), vec![PoeModule::claim_hash(&[5])]);
+        assert_eq!(ClaimCountOf::<Test>::get(1), 1);
+    });
+}
+
+/// 测试创世存证超过最大长度时构建失败
+#[test]
+#[should_panic(expected = "genesis claim is longer than MaxClaimLength")]
+fn test_genesis_claim_too_long() {
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    crate::GenesisConfig::<Test> { claims: vec![(vec![1, 2, 3, 4, 5], 1)], ..Default::default() }
+        .assimilate_storage(&mut t)
+        .unwrap();
+}
+
+/// 测试创世配置设置存证长度限制
+#[test]
+fn test_genesis_max_length() {
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    crate::GenesisConfig::<Test> { claims: vec![], max_length: Some(2) }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+    sp_io::TestExternalities::from(t).execute_with(|| {
+        assert_eq!(CurrentMaxLength::<Test>::get(), 2);
+    });
+}
+
+/// 测试创世配置的存证长度限制超过 MaxClaimLength 时构建失败
+#[test]
+#[should_panic(expected = "genesis max_length exceeds MaxClaimLength")]
+fn test_genesis_max_length_too_large() {
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    crate::GenesisConfig::<Test> { claims: vec![], max_length: Some(5) }
+        .assimilate_storage(&mut t)
+        .unwrap();
+}
+
+/// 测试 Runtime API 使用的 claim_owner 查询，过期存证返回 None
+#[test]
+fn test_claim_owner_query() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_eq!(PoeModule::claim_owner(&claim_hash), None);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        assert_eq!(PoeModule::claim_owner(&claim_hash), Some((1, 1)));
+
+        System::set_block_number(101);
+        assert_eq!(PoeModule::claim_owner(&claim_hash), None);
+    });
+}
+
+/// 测试通过 ProofProvider 查询和创建存证
+#[test]
+fn test_proof_provider() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1, 2, 3, 4]);
+        assert_eq!(<PoeModule as ProofProvider<u64>>::owner_of(&claim_hash), None);
+
+        assert_ok!(<PoeModule as ProofProvider<u64>>::create_for(&1, claim_hash));
+        assert_eq!(<PoeModule as ProofProvider<u64>>::owner_of(&claim_hash), Some(1));
+        // 与 create_claim_by_hash 相同，按最大长度保留押金
+        assert_eq!(Balances::reserved_balance(1), 90);
+        assert_noop!(
+            <PoeModule as ProofProvider<u64>>::create_for(&2, claim_hash),
+            Error::<Test>::ProofAlreadyExist
+        );
+    });
+}
+
+/// 测试创建和撤销存证时调用回调，回调失败时撤销回滚
+#[test]
+fn test_claim_lifecycle_hooks() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert_eq!(
+            CLAIM_HOOK_CALLS.with(|calls| calls.borrow().clone()),
+            vec![(true, 1, claim_hash), (false, 1, claim_hash)]
+        );
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(3), claim));
+        assert_noop!(
+            PoeModule::revoke_claim(RuntimeOrigin::signed(3), claim_hash),
+            DispatchError::Other("revoke rejected by hook")
+        );
+    });
+}
+
+/// 测试存证总数随创建、撤销、过期清理更新，并受 MaxTotalClaims 限制
+#[test]
+fn test_total_claims() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for i in 0..8_u8 {
+            let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![i]).unwrap();
+            assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1 + (i / 3) as u64), claim));
+        }
+        assert_eq!(PoeModule::total_claims(), 8);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![8]).unwrap();
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(3), claim),
+            Error::<Test>::TooManyClaims
+        );
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), PoeModule::claim_hash(&[0])));
+        assert_eq!(PoeModule::total_claims(), 7);
+
+        // 过期清理同样减少总数
+        System::set_block_number(101);
+        PoeModule::on_initialize(101);
+        assert_eq!(PoeModule::total_claims(), 5);
+    });
+}
+
+/// 测试批量创建存证，其中一个失败时整批回滚
+#[test]
+fn test_create_claims_batch() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let batch = |items: Vec<Vec<u8>>| -> BoundedVec<BoundedVec<u8, ConstU32<4>>, ConstU32<3>> {
+            items.into_iter().map(|item| item.try_into().unwrap()).collect::<Vec<_>>().try_into().unwrap()
+        };
+
+        assert_ok!(PoeModule::create_claims(RuntimeOrigin::signed(1), batch(vec![vec![1], vec![2, 3]])));
+        assert_eq!(PoeModule::claims_of(&1).len(), 2);
+        assert_eq!(Balances::reserved_balance(1), 60 + 70);
+
+        // 第二个存证已存在，第一个也不会被创建
+        assert_noop!(
+            PoeModule::create_claims(RuntimeOrigin::signed(2), batch(vec![vec![4], vec![1]])),
+            Error::<Test>::ProofAlreadyExist
+        );
+        assert_eq!(Proofs::<Test>::get(PoeModule::claim_hash(&[4])), None);
+    });
+}
+
+/// 测试批量撤销和批量转移，任意一个失败时整批回滚
+#[test]
+fn test_revoke_and_transfer_claims_batch() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let hashes: Vec<_> = (0..3_u8).map(|i| PoeModule::claim_hash(&[i])).collect();
+        for i in 0..3_u8 {
+            let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![i]).unwrap();
+            assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        }
+        let batch = |hashes: &[sp_core::H256]| -> BoundedVec<sp_core::H256, ConstU32<3>> {
+            hashes.to_vec().try_into().unwrap()
+        };
+
+        // 不存在的存证导致整批失败
+        assert_noop!(
+            PoeModule::transfer_claims(RuntimeOrigin::signed(1), 2, batch(&[hashes[0], PoeModule::claim_hash(&[9])])),
+            Error::<Test>::ClaimNotExist
+        );
+        assert_ok!(PoeModule::transfer_claims(RuntimeOrigin::signed(1), 2, batch(&hashes[..2])));
+        assert_eq!(PoeModule::claims_of(&2).len(), 2);
+
+        assert_noop!(
+            PoeModule::revoke_claims(RuntimeOrigin::signed(2), batch(&hashes)),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(PoeModule::revoke_claims(RuntimeOrigin::signed(2), batch(&hashes[..2])));
+        assert!(PoeModule::claims_of(&2).is_empty());
+        assert_eq!(PoeModule::claims_of(&1), vec![hashes[2]]);
+    });
+}
+
+/// 测试代付创建存证：押金由代付方支付，撤销后退还给代付方
+#[test]
+fn test_create_claim_for() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+
+        assert_noop!(
+            PoeModule::create_claim_for(RuntimeOrigin::signed(1), 2, claim.clone()),
+            Error::<Test>::SponsorshipNotAllowed
+        );
+        assert_ok!(PoeModule::set_allow_sponsorship(RuntimeOrigin::signed(2), true));
+        assert_ok!(PoeModule::create_claim_for(RuntimeOrigin::signed(1), 2, claim));
+
+        let details = Proofs::<Test>::get(claim_hash).unwrap();
+        assert_eq!((details.owner, details.depositor), (2, 1));
+        assert_eq!(Balances::reserved_balance(1), 90);
+        assert_eq!(Balances::reserved_balance(2), 0);
+
+        // 转移后押金仍由代付方保留
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, claim_hash));
+        assert_eq!(Balances::reserved_balance(1), 90);
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(3), claim_hash));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 1_000);
+    });
+}
+
+/// 测试提交-揭示创建存证，需等待最小延迟
+#[test]
+fn test_commit_and_reveal_claim() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let salt = [7u8; 32];
+        let commitment = PoeModule::commitment_of(&claim, &salt);
+
+        assert_noop!(
+            PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), salt),
+            Error::<Test>::CommitmentNotExist
+        );
+        assert_ok!(PoeModule::commit_claim(RuntimeOrigin::signed(1), commitment));
+        assert_eq!(Balances::reserved_balance(1), 50);
+        assert_noop!(
+            PoeModule::commit_claim(RuntimeOrigin::signed(1), commitment),
+            Error::<Test>::CommitmentAlreadyExist
+        );
+
+        System::set_block_number(2);
+        assert_noop!(
+            PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), salt),
+            Error::<Test>::RevealTooEarly
+        );
+        // 错误的盐对应不到承诺
+        assert_noop!(
+            PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), [0u8; 32]),
+            Error::<Test>::CommitmentNotExist
+        );
+
+        System::set_block_number(3);
+        assert_ok!(PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), salt));
+
+        let details = Proofs::<Test>::get(PoeModule::claim_hash(&claim)).unwrap();
+        assert_eq!((details.owner, details.created_at), (1, 1));
+        // 承诺押金已退还，只剩存证押金
+        assert_eq!(Balances::reserved_balance(1), 90);
+    });
+}
+
+/// 测试承诺之后被抢先注册的存证让位给承诺者
+#[test]
+fn test_reveal_claim_supersedes_front_runner() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        let salt = [7u8; 32];
+        assert_ok!(PoeModule::commit_claim(RuntimeOrigin::signed(1), PoeModule::commitment_of(&claim, &salt)));
+
+        System::set_block_number(3);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone()));
+        assert_ok!(PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), salt));
+
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 1);
+        assert_eq!(Balances::reserved_balance(2), 0);
+
+        // 承诺之前就已存在的存证不会被覆盖
+        assert_ok!(PoeModule::commit_claim(RuntimeOrigin::signed(3), PoeModule::commitment_of(&claim, &salt)));
+        System::set_block_number(5);
+        assert_noop!(
+            PoeModule::reveal_claim(RuntimeOrigin::signed(3), claim, salt),
+            Error::<Test>::ProofAlreadyExist
+        );
+    });
+}
+
+/// 测试被抵押、争议或举报中的抢注存证不会被揭示撤销，流程结束后承诺仍可揭示
+#[test]
+fn test_reveal_claim_keeps_encumbered_front_runner() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        let evidence = PoeModule::claim_hash(b"evidence");
+        let salt = [7u8; 32];
+        assert_ok!(PoeModule::commit_claim(RuntimeOrigin::signed(1), PoeModule::commitment_of(&claim, &salt)));
+
+        System::set_block_number(3);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone()));
+
+        assert_ok!(PoeModule::lock_as_collateral(RuntimeOrigin::signed(2), claim_hash, 9));
+        assert_noop!(
+            PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), salt),
+            Error::<Test>::ClaimLocked
+        );
+        assert_ok!(PoeModule::unlock_collateral(RuntimeOrigin::signed(9), claim_hash));
+
+        assert_ok!(PoeModule::raise_dispute(RuntimeOrigin::signed(3), claim_hash, evidence));
+        assert_noop!(
+            PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), salt),
+            Error::<Test>::ClaimDisputed
+        );
+        assert_ok!(PoeModule::resolve_dispute(RuntimeOrigin::root(), claim_hash, DisputeResolution::Reject));
+
+        assert_ok!(PoeModule::report_claim(RuntimeOrigin::signed(3), claim_hash, evidence));
+        assert_noop!(
+            PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), salt),
+            Error::<Test>::ClaimUnderReport
+        );
+        assert_ok!(PoeModule::settle_report(RuntimeOrigin::root(), claim_hash, false));
+
+        assert_ok!(PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim, salt));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 1);
+    });
+}
+
+/// 测试冻结的存证不能转移但可以撤销，解冻后恢复转移
+#[test]
+fn test_freeze_and_unfreeze_claim() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+
+        assert_noop!(
+            PoeModule::unfreeze_claim(RuntimeOrigin::signed(1), claim_hash),
+            Error::<Test>::ClaimNotFrozen
+        );
+        assert_noop!(
+            PoeModule::freeze_claim(RuntimeOrigin::signed(2), claim_hash),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(PoeModule::freeze_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().status, ClaimStatus::Frozen);
+
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash),
+            Error::<Test>::ClaimFrozen
+        );
+        assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim_hash, 2));
+        assert_noop!(
+            PoeModule::accept_claim(RuntimeOrigin::signed(2), claim_hash),
+            Error::<Test>::ClaimFrozen
+        );
+
+        assert_ok!(PoeModule::unfreeze_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash));
+
+        // 冻结不影响撤销
+        assert_ok!(PoeModule::freeze_claim(RuntimeOrigin::signed(2), claim_hash));
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim_hash));
+    });
+}
+
+/// 测试发起争议后存证不能转移，驳回争议时没收保证金
+#[test]
+fn test_dispute_rejected() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        let evidence = PoeModule::claim_hash(b"evidence");
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+
+        assert_noop!(
+            PoeModule::raise_dispute(RuntimeOrigin::signed(1), claim_hash, evidence),
+            Error::<Test>::CannotDisputeOwnClaim
+        );
+        assert_ok!(PoeModule::raise_dispute(RuntimeOrigin::signed(2), claim_hash, evidence));
+        assert_eq!(Balances::reserved_balance(2), 100);
+        assert_noop!(
+            PoeModule::raise_dispute(RuntimeOrigin::signed(3), claim_hash, evidence),
+            Error::<Test>::DisputeAlreadyExist
+        );
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, claim_hash),
+            Error::<Test>::ClaimDisputed
+        );
+
+        assert_noop!(
+            PoeModule::resolve_dispute(RuntimeOrigin::signed(1), claim_hash, DisputeResolution::Reject),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(PoeModule::resolve_dispute(RuntimeOrigin::root(), claim_hash, DisputeResolution::Reject));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(2), 900);
+        assert!(!Disputes::<Test>::contains_key(claim_hash));
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, claim_hash));
+    });
+}
+
+/// 测试支持发起方时存证转给发起方或被撤销，保证金退还
+#[test]
+fn test_dispute_upheld() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        let other_hash = PoeModule::claim_hash(&[2]);
+        let evidence = PoeModule::claim_hash(b"evidence");
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![2]).unwrap()));
+
+        assert_ok!(PoeModule::raise_dispute(RuntimeOrigin::signed(2), claim_hash, evidence));
+        assert_ok!(PoeModule::resolve_dispute(
+            RuntimeOrigin::root(),
+            claim_hash,
+            DisputeResolution::TransferToChallenger
+        ));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 2);
+        // 只剩转移过来的存证押金
+        assert_eq!(Balances::reserved_balance(2), 60);
+
+        assert_ok!(PoeModule::raise_dispute(RuntimeOrigin::signed(3), other_hash, evidence));
+        assert_ok!(PoeModule::resolve_dispute(RuntimeOrigin::root(), other_hash, DisputeResolution::Revoke));
+        assert_eq!(Proofs::<Test>::get(other_hash), None);
+        assert_eq!(Balances::reserved_balance(3), 0);
+        assert_eq!(Balances::reserved_balance(1), 0);
+    });
+}
+
+/// 测试存证已过期但尚未清理时，支持发起方的仲裁只退还保证金，存证留给过期清理
+#[test]
+fn test_dispute_upheld_on_expired_claim() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        let evidence = PoeModule::claim_hash(b"evidence");
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::raise_dispute(RuntimeOrigin::signed(2), claim_hash, evidence));
+
+        System::set_block_number(101);
+        assert_ok!(PoeModule::resolve_dispute(
+            RuntimeOrigin::root(),
+            claim_hash,
+            DisputeResolution::TransferToChallenger
+        ));
+        System::assert_last_event(
+            crate::Event::DisputeResolved { claim_hash, resolution: DisputeResolution::TransferToChallenger }.into(),
+        );
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert!(!Disputes::<Test>::contains_key(claim_hash));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 1);
+    });
+}
+
+/// 测试举报虚假存证被驳回时没收举报人的保证金，举报期间存证不能转移或撤销
+#[test]
+fn test_report_rejected() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        let evidence = PoeModule::claim_hash(b"evidence");
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+
+        assert_noop!(
+            PoeModule::report_claim(RuntimeOrigin::signed(1), claim_hash, evidence),
+            Error::<Test>::CannotReportOwnClaim
+        );
+        assert_ok!(PoeModule::report_claim(RuntimeOrigin::signed(2), claim_hash, evidence));
+        assert_eq!(Balances::reserved_balance(2), 100);
+        assert_eq!(
+            Reports::<Test>::get(claim_hash),
+            Some(FraudReport { reporter: 2, evidence, bond: 100, reported_at: 1 })
+        );
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::ClaimReported {
+            who: 2,
+            claim_hash,
+            evidence,
+        }));
+        assert_noop!(
+            PoeModule::report_claim(RuntimeOrigin::signed(3), claim_hash, evidence),
+            Error::<Test>::ReportAlreadyExist
+        );
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, claim_hash),
+            Error::<Test>::ClaimUnderReport
+        );
+        assert_noop!(
+            PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash),
+            Error::<Test>::ClaimUnderReport
+        );
+
+        assert_noop!(
+            PoeModule::settle_report(RuntimeOrigin::signed(1), claim_hash, false),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(PoeModule::settle_report(RuntimeOrigin::root(), claim_hash, false));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(2), 900);
+        assert_eq!(Balances::free_balance(TREASURY), 100);
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::ReportRejected {
+            claim_hash,
+            reporter: 2,
+            bond: 100,
+        }));
+        assert_noop!(
+            PoeModule::settle_report(RuntimeOrigin::root(), claim_hash, false),
+            Error::<Test>::ReportNotExist
+        );
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+    });
+}
+
+/// 测试举报成立时撤销存证，罚没的押金按比例分给举报人和国库，举报人的保证金退还
+#[test]
+fn test_report_upheld() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        let evidence = PoeModule::claim_hash(b"evidence");
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_eq!(Balances::reserved_balance(1), 60);
+
+        assert_ok!(PoeModule::report_claim(RuntimeOrigin::signed(2), claim_hash, evidence));
+        assert_ok!(PoeModule::settle_report(RuntimeOrigin::root(), claim_hash, true));
+        assert_eq!(Proofs::<Test>::get(claim_hash), None);
+        assert!(!Reports::<Test>::contains_key(claim_hash));
+        // 押金不退还
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 940);
+        // 举报人拿回保证金并获得 40% 的罚没资金，其余进入国库
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(2), 1_024);
+        assert_eq!(Balances::free_balance(TREASURY), 36);
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::ReportUpheld {
+            claim_hash,
+            reporter: 2,
+            slashed: 60,
+            reward: 24,
+        }));
+    });
+}
+
+/// 测试被举报的存证在仲裁前过期时，举报人的保证金退还
+#[test]
+fn test_report_refunded_on_expiry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::report_claim(RuntimeOrigin::signed(2), claim_hash, PoeModule::claim_hash(b"evidence")));
+
+        System::set_block_number(101);
+        PoeModule::on_initialize(101);
+        assert_eq!(Proofs::<Test>::get(claim_hash), None);
+        assert!(!Reports::<Test>::contains_key(claim_hash));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_noop!(
+            PoeModule::settle_report(RuntimeOrigin::root(), claim_hash, true),
+            Error::<Test>::ReportNotExist
+        );
+    });
+}
+
+/// 测试存证已过期但尚未清理时举报成立，只退还举报人的保证金，存证留给过期清理
+#[test]
+fn test_report_upheld_on_expired_claim() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::report_claim(RuntimeOrigin::signed(2), claim_hash, PoeModule::claim_hash(b"evidence")));
+
+        System::set_block_number(101);
+        assert_ok!(PoeModule::settle_report(RuntimeOrigin::root(), claim_hash, true));
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::ReportUpheld {
+            claim_hash,
+            reporter: 2,
+            slashed: 0,
+            reward: 0,
+        }));
+        assert!(!Reports::<Test>::contains_key(claim_hash));
+        assert_eq!(Balances::free_balance(2), 1_000);
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 1);
+        assert_eq!(Balances::reserved_balance(1), 60);
+    });
+}
+
+/// 测试新存证在挑战期结束后自动确认，未仲裁的挑战推迟确认
+#[test]
+fn test_challenge_window() {
+    new_test_ext().execute_with(|| {
+        ChallengeWindow::set(5);
+        System::set_block_number(1);
+
+        let confirmed = PoeModule::claim_hash(&[1]);
+        let challenged = PoeModule::claim_hash(&[2]);
+        let revoked = PoeModule::claim_hash(&[3]);
+        for claim in [1, 2, 3] {
+            assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![claim]).unwrap()));
+        }
+        assert_eq!(ProvisionalClaims::<Test>::get(confirmed), Some(6));
+        assert_eq!(ConfirmationQueue::<Test>::get(6).into_inner(), vec![confirmed, challenged, revoked]);
+        assert!(!PoeModule::is_confirmed(&confirmed));
+
+        assert_ok!(PoeModule::raise_dispute(RuntimeOrigin::signed(2), challenged, PoeModule::claim_hash(b"evidence")));
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), revoked));
+        assert_eq!(ProvisionalClaims::<Test>::get(revoked), None);
+
+        System::set_block_number(6);
+        PoeModule::on_initialize(6);
+        assert!(PoeModule::is_confirmed(&confirmed));
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::ClaimConfirmed { who: 1, claim_hash: confirmed }));
+        // 争议尚未仲裁，存证仍待确认
+        assert!(!PoeModule::is_confirmed(&challenged));
+        assert_eq!(ConfirmationQueue::<Test>::get(6).len(), 0);
+
+        System::set_block_number(7);
+        assert_ok!(PoeModule::resolve_dispute(RuntimeOrigin::root(), challenged, DisputeResolution::Reject));
+        assert!(PoeModule::is_confirmed(&challenged));
+        System::assert_has_event(RuntimeEvent::PoeModule(crate::Event::ClaimConfirmed { who: 1, claim_hash: challenged }));
+    });
+}
+
+/// 测试声誉随存证确认、背书和成立的举报变化
+#[test]
+fn test_reputation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // 没有挑战期时存证创建即确认，但不增加声誉
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_eq!(PoeModule::reputation(1), 0);
+
+        // 自己的背书不计入，他人的背书加 1，撤回时减 1
+        assert_ok!(PoeModule::attest_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert_ok!(PoeModule::attest_claim(RuntimeOrigin::signed(2), claim_hash));
+        assert_eq!(PoeModule::reputation(1), 1);
+        assert_ok!(PoeModule::revoke_attestation(RuntimeOrigin::signed(2), claim_hash));
+        assert_eq!(PoeModule::reputation(1), 0);
+        assert_eq!(<PoeModule as ProofProvider<u64>>::reputation_of(&1), 0);
+
+        // 有挑战期时确认后才加分，存证删除时从确认时的所有者扣回
+        ChallengeWindow::set(5);
+        let confirmed = PoeModule::claim_hash(&[2]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![2]).unwrap()));
+        assert_eq!(PoeModule::reputation(1), 0);
+        PoeModule::on_initialize(6);
+        assert_eq!(PoeModule::reputation(1), 1);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, confirmed));
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(3), confirmed));
+        assert_eq!(PoeModule::reputation(1), 0);
+        assert_eq!(PoeModule::reputation(3), 0);
+        assert!(!ConfirmationCredits::<Test>::contains_key(confirmed));
+
+        assert_ok!(PoeModule::report_claim(RuntimeOrigin::signed(2), claim_hash, PoeModule::claim_hash(b"evidence")));
+        assert_ok!(PoeModule::settle_report(RuntimeOrigin::root(), claim_hash, true));
+        assert_eq!(Reputation::<Test>::get(1), -5);
+        assert_eq!(PoeModule::reputation(2), 0);
+    });
+}
+
+/// 测试授予、更新和撤销使用许可，许可到期后失效，存证撤销后许可被清除
+#[test]
+fn test_licenses() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        let terms = PoeModule::claim_hash(b"terms");
+        assert_noop!(
+            PoeModule::grant_license(RuntimeOrigin::signed(1), claim_hash, 2, terms, 10),
+            Error::<Test>::ClaimNotExist
+        );
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+
+        assert_noop!(
+            PoeModule::grant_license(RuntimeOrigin::signed(2), claim_hash, 2, terms, 10),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_noop!(
+            PoeModule::grant_license(RuntimeOrigin::signed(1), claim_hash, 2, terms, 1),
+            Error::<Test>::InvalidLicenseExpiry
+        );
+        assert_ok!(PoeModule::grant_license(RuntimeOrigin::signed(1), claim_hash, 2, terms, 10));
+        assert_ok!(PoeModule::grant_license(RuntimeOrigin::signed(1), claim_hash, 3, terms, 5));
+        System::assert_last_event(
+            crate::Event::LicenseGranted { claim_hash, licensee: 3, terms_hash: terms, expiry: 5 }.into(),
+        );
+        assert_noop!(
+            PoeModule::grant_license(RuntimeOrigin::signed(1), claim_hash, 4, terms, 10),
+            Error::<Test>::TooManyLicenses
+        );
+        // 已有许可时只更新条款，不占用新的名额
+        assert_ok!(PoeModule::grant_license(RuntimeOrigin::signed(1), claim_hash, 2, claim_hash, 20));
+        assert_eq!(
+            Licenses::<Test>::get(claim_hash, 2),
+            Some(License { terms_hash: claim_hash, expiry: 20, granted_at: 1 })
+        );
+        assert_eq!(LicenseCount::<Test>::get(claim_hash), 2);
+        assert!(PoeModule::has_license(&claim_hash, &2));
+        assert!(!PoeModule::has_license(&claim_hash, &4));
+
+        // 到期的许可失效，但仍占用名额直到被撤销
+        System::set_block_number(5);
+        assert!(!PoeModule::has_license(&claim_hash, &3));
+        assert_ok!(PoeModule::revoke_license(RuntimeOrigin::signed(1), claim_hash, 3));
+        System::assert_last_event(crate::Event::LicenseRevoked { claim_hash, licensee: 3 }.into());
+        assert_noop!(
+            PoeModule::revoke_license(RuntimeOrigin::signed(1), claim_hash, 3),
+            Error::<Test>::LicenseNotExist
+        );
+        assert_eq!(LicenseCount::<Test>::get(claim_hash), 1);
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert_eq!(Licenses::<Test>::get(claim_hash, 2), None);
+        assert_eq!(LicenseCount::<Test>::get(claim_hash), 0);
+        assert!(!PoeModule::has_license(&claim_hash, &2));
+    });
+}
+
+/// 测试付费许可：被许可人接受付款计划，到期自动结算或由任意一方补缴，余额不足时许可被撤销
+#[test]
+fn test_license_fees() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        let terms = PoeModule::claim_hash(b"terms");
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_noop!(
+            PoeModule::set_license_fee(RuntimeOrigin::signed(1), claim_hash, 2, 10, 5),
+            Error::<Test>::LicenseNotExist
+        );
+        assert_ok!(PoeModule::grant_license(RuntimeOrigin::signed(1), claim_hash, 2, terms, 100));
+        assert_noop!(
+            PoeModule::settle_license(RuntimeOrigin::signed(2), claim_hash, 2),
+            Error::<Test>::LicenseFeeNotExist
+        );
+        assert_noop!(
+            PoeModule::set_license_fee(RuntimeOrigin::signed(1), claim_hash, 2, 10, 0),
+            Error::<Test>::InvalidLicenseFee
+        );
+        assert_ok!(PoeModule::set_license_fee(RuntimeOrigin::signed(1), claim_hash, 2, 10, 5));
+
+        // 被许可人接受之前许可不生效，所有者不能代为结算
+        assert!(!PoeModule::has_license(&claim_hash, &2));
+        assert_noop!(
+            PoeModule::settle_license(RuntimeOrigin::signed(1), claim_hash, 2),
+            Error::<Test>::LicenseFeeNotAccepted
+        );
+        assert_noop!(
+            PoeModule::settle_license(RuntimeOrigin::signed(3), claim_hash, 2),
+            Error::<Test>::NotLicenseParty
+        );
+        let owner_balance = Balances::free_balance(1);
+        assert_ok!(PoeModule::settle_license(RuntimeOrigin::signed(2), claim_hash, 2));
+        System::assert_last_event(
+            crate::Event::LicenseFeePaid { claim_hash, licensee: 2, amount: 10, paid_until: 6 }.into(),
+        );
+        assert!(PoeModule::has_license(&claim_hash, &2));
+        assert_eq!(Balances::free_balance(2), 990);
+        assert_eq!(Balances::free_balance(1), owner_balance + 10);
+
+        // 到期区块自动结算下一期
+        System::set_block_number(6);
+        PoeModule::on_initialize(6);
+        assert_eq!(LicenseFees::<Test>::get(claim_hash, 2).unwrap().paid_until, Some(11));
+        assert_eq!(Balances::free_balance(2), 980);
+
+        // 没有自动结算时许可失效，补缴覆盖当前区块的全部期数
+        System::set_block_number(20);
+        assert!(!PoeModule::has_license(&claim_hash, &2));
+        assert_ok!(PoeModule::settle_license(RuntimeOrigin::signed(1), claim_hash, 2));
+        assert_eq!(LicenseFees::<Test>::get(claim_hash, 2).unwrap().paid_until, Some(21));
+        assert_eq!(Balances::free_balance(2), 960);
+        assert!(PoeModule::has_license(&claim_hash, &2));
+
+        // 余额不足时撤销许可
+        assert_ok!(PoeModule::grant_license(RuntimeOrigin::signed(1), claim_hash, 3, terms, 100));
+        assert_ok!(PoeModule::set_license_fee(RuntimeOrigin::signed(1), claim_hash, 3, 2_000, 5));
+        assert_ok!(PoeModule::settle_license(RuntimeOrigin::signed(3), claim_hash, 3));
+        System::assert_last_event(crate::Event::LicenseFeeMissed { claim_hash, licensee: 3, due: 2_000 }.into());
+        assert_eq!(Licenses::<Test>::get(claim_hash, 3), None);
+        assert_eq!(LicenseFees::<Test>::get(claim_hash, 3), None);
+        assert_eq!(LicenseCount::<Test>::get(claim_hash), 1);
+        assert_eq!(Balances::free_balance(3), 1_000);
+    });
+}
+
+/// 测试背书、背书数量上限和撤回背书，存证撤销后背书被清除
+#[test]
+fn test_attestations() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_noop!(
+            PoeModule::attest_claim(RuntimeOrigin::signed(2), claim_hash),
+            Error::<Test>::ClaimNotExist
+        );
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+
+        assert_ok!(PoeModule::attest_claim(RuntimeOrigin::signed(2), claim_hash));
+        assert_eq!(Attestations::<Test>::get(claim_hash, 2), Some(1));
+        assert_noop!(
+            PoeModule::attest_claim(RuntimeOrigin::signed(2), claim_hash),
+            Error::<Test>::AlreadyAttested
+        );
+        assert_ok!(PoeModule::attest_claim(RuntimeOrigin::signed(3), claim_hash));
+        assert_noop!(
+            PoeModule::attest_claim(RuntimeOrigin::signed(1), claim_hash),
+            Error::<Test>::TooManyAttestations
+        );
+
+        assert_ok!(PoeModule::revoke_attestation(RuntimeOrigin::signed(3), claim_hash));
+        assert_noop!(
+            PoeModule::revoke_attestation(RuntimeOrigin::signed(3), claim_hash),
+            Error::<Test>::AttestationNotExist
+        );
+        assert_eq!(AttestationCount::<Test>::get(claim_hash), 1);
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert_eq!(Attestations::<Test>::get(claim_hash, 2), None);
+        assert_eq!(AttestationCount::<Test>::get(claim_hash), 0);
+    });
+}
+
+/// 测试公证人注册、公证存证和注销
+#[test]
+fn test_notary_registry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+
+        assert_noop!(
+            PoeModule::notarize_claim(RuntimeOrigin::signed(2), claim_hash),
+            Error::<Test>::NotNotary
+        );
+        assert_ok!(PoeModule::register_notary(RuntimeOrigin::signed(2)));
+        assert_eq!(Notaries::<Test>::get(2), Some(200));
+        assert_eq!(Balances::reserved_balance(2), 200);
+        assert_noop!(PoeModule::register_notary(RuntimeOrigin::signed(2)), Error::<Test>::AlreadyNotary);
+
+        assert_ok!(PoeModule::notarize_claim(RuntimeOrigin::signed(2), claim_hash));
+        assert_eq!(Notarizations::<Test>::get(claim_hash), Some(2));
+        assert_noop!(
+            PoeModule::notarize_claim(RuntimeOrigin::signed(2), claim_hash),
+            Error::<Test>::AlreadyNotarized
+        );
+
+        assert_ok!(PoeModule::unregister_notary(RuntimeOrigin::signed(2)));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_noop!(PoeModule::unregister_notary(RuntimeOrigin::signed(2)), Error::<Test>::NotNotary);
+    });
+}
+
+/// 测试治理罚没公证人质押并移除错误的公证徽章
+#[test]
+fn test_slash_notary() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        assert_ok!(PoeModule::register_notary(RuntimeOrigin::signed(2)));
+        assert_ok!(PoeModule::notarize_claim(RuntimeOrigin::signed(2), claim_hash));
+
+        assert_noop!(
+            PoeModule::slash_notary(RuntimeOrigin::signed(1), 2, claim_hash),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(PoeModule::slash_notary(RuntimeOrigin::root(), 2, claim_hash));
+        assert_eq!(Notaries::<Test>::get(2), None);
+        assert_eq!(Notarizations::<Test>::get(claim_hash), None);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(2), 800);
+    });
+}
+
+/// 测试转移时记录历史所有者，超过上限时丢弃最早的记录
+#[test]
+fn test_ownership_history() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        assert!(PoeModule::ownership_history(&claim_hash).is_empty());
+
+        System::set_block_number(2);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash));
+        System::set_block_number(3);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, claim_hash));
+        assert_eq!(PoeModule::ownership_history(&claim_hash), vec![(1, 2), (2, 3)]);
+
+        System::set_block_number(4);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(3), 1, claim_hash));
+        assert_eq!(PoeModule::ownership_history(&claim_hash), vec![(2, 3), (3, 4)]);
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert!(PoeModule::ownership_history(&claim_hash).is_empty());
+    });
+}
+
+/// 测试按历史记录查询存证在某个区块的所有者
+#[test]
+fn test_owner_at() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_eq!(PoeModule::owner_at(&claim_hash, 1), None);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+
+        System::set_block_number(2);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash));
+        assert_eq!(PoeModule::owner_at(&claim_hash, 0), None);
+        assert_eq!(PoeModule::owner_at(&claim_hash, 1), Some(1));
+        assert_eq!(PoeModule::owner_at(&claim_hash, 2), Some(2));
+        assert_eq!(PoeModule::owner_at(&claim_hash, 50), Some(2));
+        // 到达过期区块后存证不再有所有者
+        assert_eq!(PoeModule::owner_at(&claim_hash, 101), None);
+
+        // 历史记录已满，最早的持有区间无法确定
+        System::set_block_number(3);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, claim_hash));
+        assert_eq!(PoeModule::owner_at(&claim_hash, 1), None);
+        assert_eq!(PoeModule::owner_at(&claim_hash, 2), Some(2));
+        assert_eq!(PoeModule::owner_at(&claim_hash, 3), Some(3));
+
+        System::set_block_number(4);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(3), 1, claim_hash));
+        assert_eq!(PoeModule::owner_at(&claim_hash, 2), None);
+        assert_eq!(PoeModule::owner_at(&claim_hash, 3), Some(3));
+        assert_eq!(PoeModule::owner_at(&claim_hash, 4), Some(1));
+    });
+}
+
+/// 测试登记新版本并建立版本链，撤销后链接断开
+#[test]
+fn test_supersede_claim() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let v1: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1]).unwrap();
+        let v2: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![2]).unwrap();
+        let (v1_hash, v2_hash) = (PoeModule::claim_hash(&v1), PoeModule::claim_hash(&v2));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), v1));
+
+        assert_noop!(
+            PoeModule::supersede_claim(RuntimeOrigin::signed(2), v1_hash, v2.clone()),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(PoeModule::supersede_claim(RuntimeOrigin::signed(1), v1_hash, v2.clone()));
+        assert_eq!(Proofs::<Test>::get(v2_hash).unwrap().owner, 1);
+        assert_eq!(Supersedes::<Test>::get(v2_hash), Some(v1_hash));
+        assert_eq!(SupersededBy::<Test>::get(v1_hash), Some(v2_hash));
+
+        let v3: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![3]).unwrap();
+        assert_noop!(
+            PoeModule::supersede_claim(RuntimeOrigin::signed(1), v1_hash, v3),
+            Error::<Test>::AlreadySuperseded
+        );
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), v2_hash));
+        assert_eq!(SupersededBy::<Test>::get(v1_hash), None);
+        assert_eq!(Supersedes::<Test>::get(v2_hash), None);
+    });
+}
+
+/// 测试在父存证下创建子存证，以及删除时的链接清理
+#[test]
+fn test_child_claims() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let parent_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+
+        assert_noop!(
+            PoeModule::create_child_claim(RuntimeOrigin::signed(2), parent_hash, BoundedVec::try_from(vec![2]).unwrap()),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(PoeModule::create_child_claim(RuntimeOrigin::signed(1), parent_hash, BoundedVec::try_from(vec![2]).unwrap()));
+        assert_ok!(PoeModule::create_child_claim(RuntimeOrigin::signed(1), parent_hash, BoundedVec::try_from(vec![3]).unwrap()));
+        let (child_a, child_b) = (PoeModule::claim_hash(&[2]), PoeModule::claim_hash(&[3]));
+        assert_eq!(Children::<Test>::get(parent_hash).into_inner(), vec![child_a, child_b]);
+        assert_eq!(ParentOf::<Test>::get(child_a), Some(parent_hash));
+
+        // 数量上限检查发生在子存证创建之后，失败时整体回滚
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, child_b));
+        assert_noop!(
+            PoeModule::create_child_claim(RuntimeOrigin::signed(1), parent_hash, BoundedVec::try_from(vec![4]).unwrap()),
+            Error::<Test>::TooManyChildren
+        );
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), child_a));
+        assert_eq!(Children::<Test>::get(parent_hash).into_inner(), vec![child_b]);
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), parent_hash));
+        assert_eq!(ParentOf::<Test>::get(child_b), None);
+        assert_eq!(Proofs::<Test>::get(child_b).unwrap().owner, 2);
+    });
+}
+
+/// 测试创建集合、把存证加入集合以及整体转移集合
+#[test]
+fn test_collections() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(PoeModule::create_collection(RuntimeOrigin::signed(1), BoundedVec::try_from(b"works".to_vec()).unwrap()));
+        assert_eq!(Collections::<Test>::get(0).unwrap().owner, 1);
+
+        let claim_hashes: Vec<_> = (1u8..=3).map(|i| PoeModule::claim_hash(&[i])).collect();
+        for i in 1u8..=3 {
+            assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![i]).unwrap()));
+        }
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), BoundedVec::try_from(vec![4]).unwrap()));
+
+        assert_noop!(
+            PoeModule::add_claim_to_collection(RuntimeOrigin::signed(2), 0, PoeModule::claim_hash(&[4])),
+            Error::<Test>::NotCollectionOwner
+        );
+        assert_ok!(PoeModule::add_claim_to_collection(RuntimeOrigin::signed(1), 0, claim_hashes[0]));
+        assert_noop!(
+            PoeModule::add_claim_to_collection(RuntimeOrigin::signed(1), 0, claim_hashes[0]),
+            Error::<Test>::ClaimAlreadyInCollection
+        );
+        assert_ok!(PoeModule::add_claim_to_collection(RuntimeOrigin::signed(1), 0, claim_hashes[1]));
+        assert_noop!(
+            PoeModule::add_claim_to_collection(RuntimeOrigin::signed(1), 0, claim_hashes[2]),
+            Error::<Test>::CollectionFull
+        );
+
+        // 单独转出的存证离开集合
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, claim_hashes[1]));
+        assert_eq!(ClaimCollection::<Test>::get(claim_hashes[1]), None);
+        assert_eq!(CollectionClaims::<Test>::get(0).into_inner(), vec![claim_hashes[0]]);
+
+        assert_noop!(
+            PoeModule::transfer_collection(RuntimeOrigin::signed(2), 0, 2),
+            Error::<Test>::NotCollectionOwner
+        );
+        assert_ok!(PoeModule::transfer_collection(RuntimeOrigin::signed(1), 0, 2));
+        assert_eq!(Collections::<Test>::get(0).unwrap().owner, 2);
+        assert_eq!(Proofs::<Test>::get(claim_hashes[0]).unwrap().owner, 2);
+        assert_eq!(ClaimCollection::<Test>::get(claim_hashes[0]), Some(0));
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::CollectionTransferred {
+            who: 1,
+            target: 2,
+            collection_id: 0,
+        }));
+    });
+}
+
+/// 测试集合中的存证冻结时整体转移失败并回滚
+#[test]
+fn test_transfer_collection_fails_for_frozen_claim() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_collection(RuntimeOrigin::signed(1), BoundedVec::default()));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::add_claim_to_collection(RuntimeOrigin::signed(1), 0, claim_hash));
+        assert_ok!(PoeModule::freeze_claim(RuntimeOrigin::signed(1), claim_hash));
+
+        assert_noop!(PoeModule::transfer_collection(RuntimeOrigin::signed(1), 0, 2), Error::<Test>::ClaimFrozen);
+        assert_eq!(Collections::<Test>::get(0).unwrap().owner, 1);
+    });
+}
+
+/// 测试命名空间限制以其为前缀的存证内容只能由所有者登记
+#[test]
+fn test_namespace_restricts_claims() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let prefix = BoundedVec::try_from(b"ab".to_vec()).unwrap();
+        assert_noop!(PoeModule::register_namespace(RuntimeOrigin::signed(1), BoundedVec::default()), Error::<Test>::InvalidNamespace);
+        assert_ok!(PoeModule::register_namespace(RuntimeOrigin::signed(1), prefix.clone()));
+        assert_eq!(Balances::reserved_balance(1), 50);
+
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(2), BoundedVec::try_from(b"abc".to_vec()).unwrap()),
+            Error::<Test>::NamespaceReserved
+        );
+        assert_noop!(
+            PoeModule::register_namespace(RuntimeOrigin::signed(2), BoundedVec::try_from(b"abc".to_vec()).unwrap()),
+            Error::<Test>::NamespaceReserved
+        );
+        assert_noop!(PoeModule::register_namespace(RuntimeOrigin::signed(2), prefix.clone()), Error::<Test>::NamespaceTaken);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), BoundedVec::try_from(b"a".to_vec()).unwrap()));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(b"abc".to_vec()).unwrap()));
+    });
+}
+
+/// 测试命名空间的转移、续期与失效后被重新注册
+#[test]
+fn test_namespace_transfer_and_expiry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let prefix = BoundedVec::try_from(b"ab".to_vec()).unwrap();
+        assert_ok!(PoeModule::register_namespace(RuntimeOrigin::signed(1), prefix.clone()));
+
+        assert_noop!(
+            PoeModule::transfer_namespace(RuntimeOrigin::signed(2), prefix.clone(), 2),
+            Error::<Test>::NotNamespaceOwner
+        );
+        assert_ok!(PoeModule::transfer_namespace(RuntimeOrigin::signed(1), prefix.clone(), 2));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), 50);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), BoundedVec::try_from(b"abc".to_vec()).unwrap()));
+
+        // 所有者再次注册即续期，不重复保留押金
+        System::set_block_number(11);
+        assert_ok!(PoeModule::register_namespace(RuntimeOrigin::signed(2), prefix.clone()));
+        assert_eq!(Namespaces::<Test>::get(&prefix).unwrap().expires_at, 31);
+        assert_eq!(Balances::reserved_balance(2), 50 + PoeModule::deposit_for(3));
+
+        System::set_block_number(31);
+        assert_noop!(
+            PoeModule::transfer_namespace(RuntimeOrigin::signed(2), prefix.clone(), 1),
+            Error::<Test>::NamespaceNotExist
+        );
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(3), BoundedVec::try_from(b"abd".to_vec()).unwrap()));
+        assert_ok!(PoeModule::register_namespace(RuntimeOrigin::signed(3), prefix.clone()));
+        assert_eq!(Namespaces::<Test>::get(&prefix).unwrap().owner, 3);
+        assert_eq!(Balances::reserved_balance(2), PoeModule::deposit_for(3));
+    });
+}
+
+/// 测试 `ClaimPolicy` 拒绝的账户不能创建、也不能被代付创建存证
+#[test]
+fn test_claim_policy_rejects_creation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim = BoundedVec::try_from(vec![1]).unwrap();
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(4), claim.clone()),
+            DispatchError::Other("creation rejected by policy")
+        );
+
+        assert_ok!(PoeModule::set_allow_sponsorship(RuntimeOrigin::signed(4), true));
+        assert_noop!(
+            PoeModule::create_claim_for(RuntimeOrigin::signed(1), 4, claim.clone()),
+            DispatchError::Other("creation rejected by policy")
+        );
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+    });
+}
+
+/// 测试被禁止的存证哈希不能通过任何途径登记
+#[test]
+fn test_banned_claim_hash() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim = BoundedVec::try_from(vec![1]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_noop!(PoeModule::ban_claim_hash(RuntimeOrigin::signed(1), claim_hash), DispatchError::BadOrigin);
+        assert_ok!(PoeModule::ban_claim_hash(RuntimeOrigin::root(), claim_hash));
+        assert!(Banned::<Test>::contains_key(claim_hash));
+        assert_noop!(PoeModule::ban_claim_hash(RuntimeOrigin::root(), claim_hash), Error::<Test>::ClaimBanned);
+
+        assert_noop!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()), Error::<Test>::ClaimBanned);
+        assert_noop!(PoeModule::create_claim_by_hash(RuntimeOrigin::signed(1), claim_hash), Error::<Test>::ClaimBanned);
+
+        assert_ok!(PoeModule::unban_claim_hash(RuntimeOrigin::root(), claim_hash));
+        assert_noop!(PoeModule::unban_claim_hash(RuntimeOrigin::root(), claim_hash), Error::<Test>::ClaimNotBanned);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+    });
+}
+
+/// 测试被禁止的账户不能创建或接收存证，但仍可转出已有的存证
+#[test]
+fn test_banned_account() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), BoundedVec::try_from(vec![2]).unwrap()));
+
+        assert_noop!(PoeModule::ban_account(RuntimeOrigin::signed(1), 2), DispatchError::BadOrigin);
+        assert_ok!(PoeModule::ban_account(RuntimeOrigin::root(), 2));
+        assert!(BannedAccounts::<Test>::contains_key(2));
+        assert_noop!(PoeModule::ban_account(RuntimeOrigin::root(), 2), Error::<Test>::AccountBanned);
+
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(2), BoundedVec::try_from(vec![3]).unwrap()),
+            Error::<Test>::AccountBanned
+        );
+        assert_noop!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash), Error::<Test>::AccountBanned);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, PoeModule::claim_hash(&[2])));
+
+        assert_ok!(PoeModule::unban_account(RuntimeOrigin::root(), 2));
+        assert_noop!(PoeModule::unban_account(RuntimeOrigin::root(), 2), Error::<Test>::AccountNotBanned);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash));
+    });
+}
+
+/// 测试共同所有者全部批准后主所有者才能撤销或转移存证
+#[test]
+fn test_co_owners_must_approve() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+
+        assert_noop!(PoeModule::add_co_owner(RuntimeOrigin::signed(2), claim_hash, 2), Error::<Test>::NotClaimOwner);
+        assert_ok!(PoeModule::add_co_owner(RuntimeOrigin::signed(1), claim_hash, 2));
+        assert_ok!(PoeModule::add_co_owner(RuntimeOrigin::signed(1), claim_hash, 3));
+        assert_noop!(PoeModule::add_co_owner(RuntimeOrigin::signed(1), claim_hash, 3), Error::<Test>::AlreadyCoOwner);
+        assert_noop!(PoeModule::add_co_owner(RuntimeOrigin::signed(1), claim_hash, 4), Error::<Test>::TooManyCoOwners);
+
+        assert_noop!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash), Error::<Test>::CoOwnerApprovalMissing);
+        assert_ok!(PoeModule::approve_co_owner_action(RuntimeOrigin::signed(2), claim_hash, CoOwnerAction::Revoke));
+        assert_ok!(PoeModule::approve_co_owner_action(RuntimeOrigin::signed(3), claim_hash, CoOwnerAction::Transfer(2)));
+        assert_noop!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash), Error::<Test>::CoOwnerApprovalMissing);
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash),
+            Error::<Test>::CoOwnerApprovalMissing
+        );
+
+        // 批准转移后执行，共同所有关系随转移结束
+        assert_ok!(PoeModule::approve_co_owner_action(RuntimeOrigin::signed(2), claim_hash, CoOwnerAction::Transfer(2)));
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash));
+        assert!(CoOwners::<Test>::get(claim_hash).is_empty());
+        assert_eq!(CoOwnerApprovals::<Test>::iter_prefix(claim_hash).count(), 0);
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim_hash));
+    });
+}
+
+/// 测试共同所有者可以自行退出，退出后其批准一并清除
+#[test]
+fn test_remove_co_owner() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::add_co_owner(RuntimeOrigin::signed(1), claim_hash, 2));
+        assert_ok!(PoeModule::approve_co_owner_action(RuntimeOrigin::signed(2), claim_hash, CoOwnerAction::Revoke));
+
+        assert_noop!(PoeModule::remove_co_owner(RuntimeOrigin::signed(3), claim_hash, 2), Error::<Test>::NotClaimOwner);
+        assert_noop!(PoeModule::remove_co_owner(RuntimeOrigin::signed(3), claim_hash, 3), Error::<Test>::NotCoOwner);
+        assert_ok!(PoeModule::remove_co_owner(RuntimeOrigin::signed(2), claim_hash, 2));
+        assert_eq!(CoOwnerApprovals::<Test>::get(claim_hash, 2), None);
+        assert_noop!(
+            PoeModule::approve_co_owner_action(RuntimeOrigin::signed(2), claim_hash, CoOwnerAction::Revoke),
+            Error::<Test>::NotCoOwner
+        );
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+    });
+}
+
+/// 测试设置撤销门限后需要收集足够的批准才能撤销
+#[test]
+fn test_revocation_threshold() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+
+        let signatories = BoundedVec::try_from(vec![2, 3, 4]).unwrap();
+        assert_noop!(
+            PoeModule::set_revocation_threshold(RuntimeOrigin::signed(1), claim_hash, signatories.clone(), 4),
+            Error::<Test>::InvalidThreshold
+        );
+        assert_noop!(
+            PoeModule::set_revocation_threshold(RuntimeOrigin::signed(1), claim_hash, BoundedVec::try_from(vec![2, 2]).unwrap(), 1),
+            Error::<Test>::InvalidThreshold
+        );
+        assert_ok!(PoeModule::set_revocation_threshold(RuntimeOrigin::signed(1), claim_hash, signatories, 2));
+        assert_eq!(RevocationGuards::<Test>::get(claim_hash).unwrap().threshold, 2);
+
+        // 未达到门限时既不能撤销，也不能放宽门限
+        assert_noop!(PoeModule::approve_revocation(RuntimeOrigin::signed(1), claim_hash), Error::<Test>::NotSignatory);
+        assert_ok!(PoeModule::approve_revocation(RuntimeOrigin::signed(2), claim_hash));
+        assert_noop!(
+            PoeModule::approve_revocation(RuntimeOrigin::signed(2), claim_hash),
+            Error::<Test>::AlreadyApprovedRevocation
+        );
+        assert_noop!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash), Error::<Test>::RevocationNotApproved);
+        assert_noop!(
+            PoeModule::set_revocation_threshold(RuntimeOrigin::signed(1), claim_hash, BoundedVec::try_from(vec![2]).unwrap(), 1),
+            Error::<Test>::RevocationNotApproved
+        );
+
+        assert_ok!(PoeModule::approve_revocation(RuntimeOrigin::signed(4), claim_hash));
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert!(!RevocationGuards::<Test>::contains_key(claim_hash));
+        assert!(RevocationApprovals::<Test>::get(claim_hash).is_empty());
+    });
+}
+
+/// 测试监护人达到门限并经过等待期后，存证全部转到新账户，无法转移的存证留在原账户
+#[test]
+fn test_social_recovery() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (claim_a, claim_b) = (PoeModule::claim_hash(&[1]), PoeModule::claim_hash(&[2]));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![2]).unwrap()));
+        assert_ok!(PoeModule::freeze_claim(RuntimeOrigin::signed(1), claim_b));
+
+        let guardians = BoundedVec::try_from(vec![2, 3]).unwrap();
+        assert_noop!(
+            PoeModule::set_recovery_config(RuntimeOrigin::signed(1), guardians.clone(), 2, 4),
+            Error::<Test>::RecoveryDelayTooShort
+        );
+        assert_ok!(PoeModule::set_recovery_config(RuntimeOrigin::signed(1), guardians, 2, 5));
+
+        assert_noop!(PoeModule::initiate_recovery(RuntimeOrigin::signed(4), 1, 5), Error::<Test>::NotGuardian);
+        assert_ok!(PoeModule::initiate_recovery(RuntimeOrigin::signed(2), 1, 5));
+        assert_noop!(PoeModule::initiate_recovery(RuntimeOrigin::signed(3), 1, 6), Error::<Test>::RecoveryTargetMismatch);
+        assert_noop!(PoeModule::complete_recovery(RuntimeOrigin::signed(4), 1), Error::<Test>::RecoveryNotReady);
+        assert_ok!(PoeModule::initiate_recovery(RuntimeOrigin::signed(3), 1, 5));
+        assert_eq!(ActiveRecoveries::<Test>::get(1).unwrap().started_at, Some(1));
+
+        System::set_block_number(5);
+        assert_noop!(PoeModule::complete_recovery(RuntimeOrigin::signed(4), 1), Error::<Test>::RecoveryNotReady);
+        System::set_block_number(6);
+        assert_ok!(PoeModule::complete_recovery(RuntimeOrigin::signed(4), 1));
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::RecoveryCompleted {
+            lost: 1,
+            rescuer: 5,
+            count: 1,
+        }));
+
+        assert_eq!(Proofs::<Test>::get(claim_a).unwrap().owner, 5);
+        assert_eq!(Proofs::<Test>::get(claim_b).unwrap().owner, 1);
+        assert!(!RecoveryConfigs::<Test>::contains_key(1));
+        assert!(!ActiveRecoveries::<Test>::contains_key(1));
+    });
+}
+
+/// 测试所有者可以在等待期内否决恢复
+#[test]
+fn test_veto_recovery() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::set_recovery_config(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![2]).unwrap(), 1, 5));
+        assert_ok!(PoeModule::initiate_recovery(RuntimeOrigin::signed(2), 1, 2));
+        assert_noop!(
+            PoeModule::set_recovery_config(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![3]).unwrap(), 1, 5),
+            Error::<Test>::RecoveryInProgress
+        );
+
+        assert_ok!(PoeModule::veto_recovery(RuntimeOrigin::signed(1)));
+        assert_noop!(PoeModule::veto_recovery(RuntimeOrigin::signed(1)), Error::<Test>::RecoveryNotExist);
+
+        System::set_block_number(10);
+        assert_noop!(PoeModule::complete_recovery(RuntimeOrigin::signed(2), 1), Error::<Test>::RecoveryNotExist);
+        assert_eq!(Proofs::<Test>::get(PoeModule::claim_hash(&[1])).unwrap().owner, 1);
+    });
+}
+
+/// 测试所有者超过不活动期后继承人取得存证，`ping` 可以推迟继承
+#[test]
+fn test_dead_man_switch() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+
+        assert_noop!(PoeModule::claim_inheritance(RuntimeOrigin::signed(3), claim_hash), Error::<Test>::NoInheritor);
+        assert_noop!(
+            PoeModule::set_inheritor(RuntimeOrigin::signed(1), claim_hash, 2, 9),
+            Error::<Test>::InactivityPeriodTooShort
+        );
+        assert_noop!(PoeModule::set_inheritor(RuntimeOrigin::signed(1), claim_hash, 1, 10), Error::<Test>::InvalidHeir);
+        assert_ok!(PoeModule::set_inheritor(RuntimeOrigin::signed(1), claim_hash, 2, 10));
+
+        System::set_block_number(8);
+        assert_ok!(PoeModule::ping(RuntimeOrigin::signed(1)));
+        System::set_block_number(17);
+        assert_noop!(PoeModule::claim_inheritance(RuntimeOrigin::signed(3), claim_hash), Error::<Test>::OwnerStillActive);
+
+        System::set_block_number(18);
+        assert_ok!(PoeModule::claim_inheritance(RuntimeOrigin::signed(3), claim_hash));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 2);
+        assert!(!Inheritors::<Test>::contains_key(claim_hash));
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::InheritanceClaimed {
+            owner: 1,
+            who: 2,
+            claim_hash,
+        }));
+    });
+}
+
+/// 测试存证转移后原所有者的继承设置失效
+#[test]
+fn test_inheritor_cleared_on_transfer() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::set_inheritor(RuntimeOrigin::signed(1), claim_hash, 2, 10));
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, claim_hash));
+
+        System::set_block_number(50);
+        assert_noop!(PoeModule::claim_inheritance(RuntimeOrigin::signed(2), claim_hash), Error::<Test>::NoInheritor);
+    });
+}
+
+/// 测试预约的转移在目标区块的 on_initialize 中执行，失败的转移不影响其他转移
+#[test]
+fn test_scheduled_transfers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (claim_a, claim_b) = (PoeModule::claim_hash(&[1]), PoeModule::claim_hash(&[2]));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![2]).unwrap()));
+
+        assert_noop!(PoeModule::schedule_transfer(RuntimeOrigin::signed(1), claim_a, 2, 1), Error::<Test>::InvalidScheduleBlock);
+        assert_noop!(PoeModule::schedule_transfer(RuntimeOrigin::signed(2), claim_a, 2, 5), Error::<Test>::NotClaimOwner);
+        assert_ok!(PoeModule::schedule_transfer(RuntimeOrigin::signed(1), claim_a, 2, 5));
+        assert_noop!(
+            PoeModule::schedule_transfer(RuntimeOrigin::signed(1), claim_a, 3, 6),
+            Error::<Test>::TransferAlreadyScheduled
+        );
+        assert_ok!(PoeModule::schedule_transfer(RuntimeOrigin::signed(1), claim_b, 3, 5));
+        assert_ok!(PoeModule::freeze_claim(RuntimeOrigin::signed(1), claim_b));
+
+        PoeModule::on_initialize(4);
+        assert_eq!(Proofs::<Test>::get(claim_a).unwrap().owner, 1);
+
+        System::set_block_number(5);
+        PoeModule::on_initialize(5);
+        assert_eq!(Proofs::<Test>::get(claim_a).unwrap().owner, 2);
+        assert_eq!(Proofs::<Test>::get(claim_b).unwrap().owner, 1);
+        System::assert_has_event(RuntimeEvent::PoeModule(crate::Event::ScheduledTransferFailed {
+            claim_hash: claim_b,
+            error: Error::<Test>::ClaimFrozen.into(),
+        }));
+        assert!(TransferQueue::<Test>::get(5).is_empty());
+        assert!(!ScheduledTransfers::<Test>::contains_key(claim_b));
+    });
+}
+
+/// 测试取消预约以及存证转移后预约失效
+#[test]
+fn test_cancel_scheduled_transfer() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::schedule_transfer(RuntimeOrigin::signed(1), claim_hash, 2, 5));
+        assert_ok!(PoeModule::cancel_scheduled_transfer(RuntimeOrigin::signed(1), claim_hash));
+        assert!(TransferQueue::<Test>::get(5).is_empty());
+        assert_noop!(
+            PoeModule::cancel_scheduled_transfer(RuntimeOrigin::signed(1), claim_hash),
+            Error::<Test>::ScheduledTransferNotExist
+        );
+
+        assert_ok!(PoeModule::schedule_transfer(RuntimeOrigin::signed(1), claim_hash, 2, 5));
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, claim_hash));
+        assert!(!ScheduledTransfers::<Test>::contains_key(claim_hash));
+
+        System::set_block_number(5);
+        PoeModule::on_initialize(5);
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 3);
+    });
+}
+
+/// 测试配置撤销等待期后只能两步撤销，等待期结束前不能完成
+#[test]
+fn test_delayed_revocation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        RevocationDelay::set(5);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_noop!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash), Error::<Test>::RevocationDelayed);
+
+        assert_noop!(PoeModule::begin_revoke(RuntimeOrigin::signed(2), claim_hash), Error::<Test>::NotClaimOwner);
+        assert_noop!(PoeModule::finalize_revoke(RuntimeOrigin::signed(1), claim_hash), Error::<Test>::RevocationNotPending);
+        assert_ok!(PoeModule::begin_revoke(RuntimeOrigin::signed(1), claim_hash));
+        assert_noop!(PoeModule::begin_revoke(RuntimeOrigin::signed(1), claim_hash), Error::<Test>::RevocationAlreadyPending);
+
+        System::set_block_number(5);
+        assert_noop!(
+            PoeModule::finalize_revoke(RuntimeOrigin::signed(1), claim_hash),
+            Error::<Test>::RevocationDelayNotElapsed
+        );
+        System::set_block_number(6);
+        assert_ok!(PoeModule::finalize_revoke(RuntimeOrigin::signed(1), claim_hash));
+        assert!(!Proofs::<Test>::contains_key(claim_hash));
+        assert!(!PendingRevocations::<Test>::contains_key(claim_hash));
+    });
+}
+
+/// 测试取消等待中的撤销，以及存证转移后等待中的撤销失效
+#[test]
+fn test_cancel_revoke() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        RevocationDelay::set(5);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::begin_revoke(RuntimeOrigin::signed(1), claim_hash));
+        assert_ok!(PoeModule::cancel_revoke(RuntimeOrigin::signed(1), claim_hash));
+        assert_noop!(PoeModule::cancel_revoke(RuntimeOrigin::signed(1), claim_hash), Error::<Test>::RevocationNotPending);
+
+        assert_ok!(PoeModule::begin_revoke(RuntimeOrigin::signed(1), claim_hash));
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash));
+        System::set_block_number(10);
+        assert_noop!(PoeModule::finalize_revoke(RuntimeOrigin::signed(2), claim_hash), Error::<Test>::RevocationNotPending);
+    });
+}
+
+/// 测试挂单出售：挂单期间不能直接转移，成交时货款扣除协议费后转给卖家
+#[test]
+fn test_claim_sale() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_noop!(PoeModule::list_claim_for_sale(RuntimeOrigin::signed(2), claim_hash, 100), Error::<Test>::NotClaimOwner);
+        assert_ok!(PoeModule::list_claim_for_sale(RuntimeOrigin::signed(1), claim_hash, 100));
+
+        assert_noop!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, claim_hash), Error::<Test>::ClaimListed);
+        assert_noop!(PoeModule::buy_claim(RuntimeOrigin::signed(1), claim_hash, 100), Error::<Test>::CannotBuyOwnClaim);
+        assert_noop!(PoeModule::buy_claim(RuntimeOrigin::signed(2), claim_hash, 99), Error::<Test>::PriceTooHigh);
+
+        let (seller_free, buyer_free) = (Balances::free_balance(1), Balances::free_balance(2));
+        let total_issuance = Balances::total_issuance();
+        assert_ok!(PoeModule::buy_claim(RuntimeOrigin::signed(2), claim_hash, 100));
+
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 2);
+        assert!(!Listings::<Test>::contains_key(claim_hash));
+        assert_eq!(Balances::free_balance(1), seller_free + 90);
+        assert_eq!(Balances::free_balance(2), buyer_free - 100);
+        assert_eq!(Balances::total_issuance(), total_issuance - 10);
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::ClaimSold {
+            seller: 1,
+            buyer: 2,
+            claim_hash,
+            price: 100,
+        }));
+    });
+}
+
+/// 测试托管出售：存证立即转给买家，货款在争议期结束后自动支付给卖家，放款前存证不能转移、撤销或挂单
+#[test]
+fn test_escrowed_sale() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_noop!(
+            PoeModule::buy_claim_escrowed(RuntimeOrigin::signed(2), claim_hash, 100),
+            Error::<Test>::NotListed
+        );
+        assert_ok!(PoeModule::list_claim_for_sale(RuntimeOrigin::signed(1), claim_hash, 100));
+
+        let (seller_free, buyer_free) = (Balances::free_balance(1), Balances::free_balance(2));
+        assert_ok!(PoeModule::buy_claim_escrowed(RuntimeOrigin::signed(2), claim_hash, 100));
+        System::assert_last_event(
+            crate::Event::ClaimSoldInEscrow { seller: 1, buyer: 2, claim_hash, price: 100, release_at: 11 }.into(),
+        );
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 2);
+        assert_eq!(Balances::free_balance(2), buyer_free - 100);
+        assert_eq!(Balances::free_balance(1), seller_free);
+
+        assert_noop!(PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, claim_hash), Error::<Test>::ClaimInEscrow);
+        assert_noop!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim_hash), Error::<Test>::ClaimInEscrow);
+        assert_noop!(
+            PoeModule::force_transfer_claim(RuntimeOrigin::root(), claim_hash, 3),
+            Error::<Test>::ClaimInEscrow
+        );
+        assert_noop!(
+            PoeModule::list_claim_for_sale(RuntimeOrigin::signed(2), claim_hash, 200),
+            Error::<Test>::ClaimInEscrow
+        );
+        assert_noop!(
+            PoeModule::list_claim_dutch(RuntimeOrigin::signed(2), claim_hash, 200, 100, 10),
+            Error::<Test>::ClaimInEscrow
+        );
+        assert_noop!(
+            PoeModule::start_auction(RuntimeOrigin::signed(2), claim_hash, 200, 10),
+            Error::<Test>::ClaimInEscrow
+        );
+        assert_noop!(
+            PoeModule::dispute_escrow(RuntimeOrigin::signed(3), claim_hash, claim_hash),
+            Error::<Test>::NotEscrowBuyer
+        );
+
+        System::set_block_number(11);
+        PoeModule::on_initialize(11);
+        System::assert_last_event(crate::Event::EscrowReleased { seller: 1, claim_hash, price: 100 }.into());
+        assert!(!Escrows::<Test>::contains_key(claim_hash));
+        assert_eq!(Balances::free_balance(1), seller_free + 90);
+        assert_eq!(Balances::free_balance(2), buyer_free - 100);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, claim_hash));
+    });
+}
+
+/// 测试托管出售的争议：争议期内发起的争议暂停放款，仲裁退款时存证归还卖家，驳回时没收保证金并放款
+#[test]
+fn test_escrow_dispute() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        let evidence = PoeModule::claim_hash(b"evidence");
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::list_claim_for_sale(RuntimeOrigin::signed(1), claim_hash, 100));
+        let (seller_free, buyer_free) = (Balances::free_balance(1), Balances::free_balance(2));
+        assert_ok!(PoeModule::buy_claim_escrowed(RuntimeOrigin::signed(2), claim_hash, 100));
+
+        assert_ok!(PoeModule::dispute_escrow(RuntimeOrigin::signed(2), claim_hash, evidence));
+        System::assert_last_event(crate::Event::EscrowDisputed { buyer: 2, claim_hash, evidence }.into());
+        assert_noop!(
+            PoeModule::dispute_escrow(RuntimeOrigin::signed(2), claim_hash, evidence),
+            Error::<Test>::EscrowAlreadyDisputed
+        );
+        assert_eq!(Balances::free_balance(2), buyer_free - 200);
+
+        // 有争议的托管到期不放款
+        System::set_block_number(11);
+        PoeModule::on_initialize(11);
+        assert!(Escrows::<Test>::contains_key(claim_hash));
+        assert_noop!(PoeModule::resolve_escrow(RuntimeOrigin::signed(1), claim_hash, true), DispatchError::BadOrigin);
+        // 退款时取消买家在限制生效前留下的挂单
+        Listings::<Test>::insert(claim_hash, Listing { seller: 2, price: 300, decay: None });
+        assert_ok!(PoeModule::resolve_escrow(RuntimeOrigin::root(), claim_hash, true));
+        System::assert_last_event(crate::Event::EscrowRefunded { buyer: 2, claim_hash, price: 100 }.into());
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 1);
+        assert!(!Listings::<Test>::contains_key(claim_hash));
+        assert_eq!(Balances::free_balance(2), buyer_free);
+        assert_eq!(Balances::free_balance(1), seller_free);
+
+        // 争议期结束后不能再发起争议；驳回争议时没收保证金并向卖家放款
+        assert_ok!(PoeModule::list_claim_for_sale(RuntimeOrigin::signed(1), claim_hash, 100));
+        assert_ok!(PoeModule::buy_claim_escrowed(RuntimeOrigin::signed(2), claim_hash, 100));
+        System::set_block_number(21);
+        assert_noop!(
+            PoeModule::dispute_escrow(RuntimeOrigin::signed(2), claim_hash, evidence),
+            Error::<Test>::EscrowPeriodEnded
+        );
+        System::set_block_number(20);
+        assert_ok!(PoeModule::dispute_escrow(RuntimeOrigin::signed(2), claim_hash, evidence));
+        assert_ok!(PoeModule::resolve_escrow(RuntimeOrigin::root(), claim_hash, false));
+        assert!(!Escrows::<Test>::contains_key(claim_hash));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 2);
+        assert_eq!(Balances::free_balance(1), seller_free + 90);
+        assert_eq!(Balances::free_balance(2), buyer_free - 200);
+    });
+}
+
+/// 测试打包出售：打包期间存证不能单独出售或转移，整体购买要么全部成交要么全部回滚，卖家可以移出部分存证
+#[test]
+fn test_claim_bundles() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let hashes: Vec<_> = (1..=4u8).map(|i| PoeModule::claim_hash(&[i])).collect();
+        for i in 1..=3u8 {
+            assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![i]).unwrap()));
+        }
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), BoundedVec::try_from(vec![4]).unwrap()));
+        let bundle = |claims: &[H256]| BoundedVec::try_from(claims.to_vec()).unwrap();
+
+        assert_noop!(PoeModule::create_bundle(RuntimeOrigin::signed(1), bundle(&[]), 300), Error::<Test>::EmptyBundle);
+        assert_noop!(
+            PoeModule::create_bundle(RuntimeOrigin::signed(1), bundle(&[hashes[0], hashes[3]]), 300),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_noop!(
+            PoeModule::create_bundle(RuntimeOrigin::signed(1), bundle(&[hashes[0], hashes[0]]), 300),
+            Error::<Test>::ClaimInBundle
+        );
+        assert_ok!(PoeModule::create_bundle(RuntimeOrigin::signed(1), bundle(&hashes[..3]), 300));
+        System::assert_last_event(crate::Event::BundleListed { who: 1, bundle_id: 0, price: 300 }.into());
+        assert_noop!(
+            PoeModule::list_claim_for_sale(RuntimeOrigin::signed(1), hashes[0], 100),
+            Error::<Test>::ClaimInBundle
+        );
+        assert_noop!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, hashes[0]), Error::<Test>::ClaimInBundle);
+
+        // 移出一个存证并降低总价
+        assert_noop!(
+            PoeModule::unbundle_claim(RuntimeOrigin::signed(2), 0, hashes[2], None),
+            Error::<Test>::NotBundleSeller
+        );
+        assert_noop!(
+            PoeModule::unbundle_claim(RuntimeOrigin::signed(1), 0, hashes[3], None),
+            Error::<Test>::ClaimNotInBundle
+        );
+        assert_ok!(PoeModule::unbundle_claim(RuntimeOrigin::signed(1), 0, hashes[2], Some(200)));
+        System::assert_last_event(crate::Event::BundlePriceUpdated { bundle_id: 0, price: 200 }.into());
+        assert_eq!(BundleClaims::<Test>::get(0).into_inner(), hashes[..2].to_vec());
+        assert_eq!(ClaimBundle::<Test>::get(hashes[2]), None);
+
+        assert_noop!(PoeModule::buy_bundle(RuntimeOrigin::signed(1), 0, 200), Error::<Test>::CannotBuyOwnClaim);
+        assert_noop!(PoeModule::buy_bundle(RuntimeOrigin::signed(2), 0, 199), Error::<Test>::PriceTooHigh);
+        let (seller_free, buyer_free) = (Balances::free_balance(1), Balances::free_balance(2));
+        assert_ok!(PoeModule::buy_bundle(RuntimeOrigin::signed(2), 0, 200));
+        System::assert_last_event(crate::Event::BundleSold { seller: 1, buyer: 2, bundle_id: 0, price: 200 }.into());
+        assert_eq!(Proofs::<Test>::get(hashes[0]).unwrap().owner, 2);
+        assert_eq!(Proofs::<Test>::get(hashes[1]).unwrap().owner, 2);
+        assert_eq!(Balances::free_balance(1), seller_free + 180);
+        assert_eq!(Balances::free_balance(2), buyer_free - 200);
+        assert!(!Bundles::<Test>::contains_key(0));
+        assert_eq!(ClaimBundle::<Test>::get(hashes[0]), None);
+
+        // 买家只付得起第一份货款时整体回滚
+        assert_ok!(PoeModule::create_bundle(RuntimeOrigin::signed(2), bundle(&[hashes[0], hashes[1]]), 1_800));
+        let buyer_free = Balances::free_balance(3);
+        assert!(PoeModule::buy_bundle(RuntimeOrigin::signed(3), 1, 1_800).is_err());
+        assert_eq!(ClaimBundle::<Test>::get(hashes[0]), Some(1));
+        assert_eq!(Proofs::<Test>::get(hashes[0]).unwrap().owner, 2);
+        assert_eq!(Balances::free_balance(3), buyer_free);
+
+        // 移出全部存证后打包取消
+        assert_ok!(PoeModule::unbundle_claim(RuntimeOrigin::signed(2), 1, hashes[0], None));
+        assert_ok!(PoeModule::unbundle_claim(RuntimeOrigin::signed(2), 1, hashes[1], Some(100)));
+        System::assert_last_event(crate::Event::BundleCancelled { bundle_id: 1 }.into());
+        assert!(!Bundles::<Test>::contains_key(1));
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, hashes[0]));
+    });
+}
+
+/// 测试打包中已过期但尚未清理的存证不出售，总价扣除其份额，其余存证照常成交
+#[test]
+fn test_buy_bundle_skips_expired_claims() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let hashes: Vec<_> = (1..=2u8).map(|i| PoeModule::claim_hash(&[i])).collect();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        System::set_block_number(50);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![2]).unwrap()));
+        assert_ok!(PoeModule::create_bundle(
+            RuntimeOrigin::signed(1),
+            BoundedVec::try_from(hashes.clone()).unwrap(),
+            300
+        ));
+
+        // 第一个存证在第 101 区块过期，尚未被清理
+        System::set_block_number(120);
+        let buyer_free = Balances::free_balance(2);
+        assert_ok!(PoeModule::buy_bundle(RuntimeOrigin::signed(2), 0, 300));
+        System::assert_last_event(crate::Event::BundleSold { seller: 1, buyer: 2, bundle_id: 0, price: 150 }.into());
+        assert_eq!(Balances::free_balance(2), buyer_free - 150);
+        assert_eq!(Proofs::<Test>::get(hashes[0]).unwrap().owner, 1);
+        assert_eq!(Proofs::<Test>::get(hashes[1]).unwrap().owner, 2);
+        assert_eq!(ClaimBundle::<Test>::get(hashes[0]), None);
+        assert!(!Bundles::<Test>::contains_key(0));
+
+        // 全部存证都已过期时不能购买
+        assert_ok!(PoeModule::create_bundle(
+            RuntimeOrigin::signed(2),
+            BoundedVec::try_from(vec![hashes[1]]).unwrap(),
+            100
+        ));
+        System::set_block_number(150);
+        assert_noop!(PoeModule::buy_bundle(RuntimeOrigin::signed(3), 1, 100), Error::<Test>::EmptyBundle);
+    });
+}
+
+/// 测试强制转移和仲裁转移会把存证移出打包出售，但不能转移作为抵押品锁定的存证
+#[test]
+fn test_forced_transfer_of_bundled_and_locked_claims() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let hashes: Vec<_> = (1..=3u8).map(|i| PoeModule::claim_hash(&[i])).collect();
+        for i in 1..=3u8 {
+            assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![i]).unwrap()));
+        }
+        let evidence = PoeModule::claim_hash(b"evidence");
+        assert_ok!(PoeModule::create_bundle(
+            RuntimeOrigin::signed(1),
+            BoundedVec::try_from(hashes[..2].to_vec()).unwrap(),
+            300
+        ));
+
+        assert_ok!(PoeModule::force_transfer_claim(RuntimeOrigin::root(), hashes[0], 2));
+        assert_eq!(Proofs::<Test>::get(hashes[0]).unwrap().owner, 2);
+        assert_eq!(ClaimBundle::<Test>::get(hashes[0]), None);
+        assert_eq!(BundleClaims::<Test>::get(0).into_inner(), vec![hashes[1]]);
+
+        // 打包中最后一个存证被仲裁转走时取消打包
+        assert_ok!(PoeModule::raise_dispute(RuntimeOrigin::signed(3), hashes[1], evidence));
+        assert_ok!(PoeModule::resolve_dispute(
+            RuntimeOrigin::root(),
+            hashes[1],
+            DisputeResolution::TransferToChallenger
+        ));
+        assert_eq!(Proofs::<Test>::get(hashes[1]).unwrap().owner, 3);
+        assert!(!Bundles::<Test>::contains_key(0));
+
+        // 抵押锁定保护锁定方的权益，需要锁定方先解锁
+        assert_ok!(PoeModule::lock_as_collateral(RuntimeOrigin::signed(1), hashes[2], 9));
+        assert_noop!(
+            PoeModule::force_transfer_claim(RuntimeOrigin::root(), hashes[2], 2),
+            Error::<Test>::ClaimLocked
+        );
+        assert_ok!(PoeModule::raise_dispute(RuntimeOrigin::signed(3), hashes[2], evidence));
+        assert_noop!(
+            PoeModule::resolve_dispute(RuntimeOrigin::root(), hashes[2], DisputeResolution::TransferToChallenger),
+            Error::<Test>::ClaimLocked
+        );
+    });
+}
+
+/// 测试取消挂单后存证恢复可转移，共同所有者需要批准出售
+#[test]
+fn test_cancel_listing() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::add_co_owner(RuntimeOrigin::signed(1), claim_hash, 3));
+        assert_noop!(
+            PoeModule::list_claim_for_sale(RuntimeOrigin::signed(1), claim_hash, 100),
+            Error::<Test>::CoOwnerApprovalMissing
+        );
+        assert_ok!(PoeModule::approve_co_owner_action(RuntimeOrigin::signed(3), claim_hash, CoOwnerAction::Sell));
+        assert_ok!(PoeModule::list_claim_for_sale(RuntimeOrigin::signed(1), claim_hash, 100));
+
+        assert_noop!(PoeModule::cancel_listing(RuntimeOrigin::signed(2), claim_hash), Error::<Test>::NotClaimOwner);
+        assert_ok!(PoeModule::cancel_listing(RuntimeOrigin::signed(1), claim_hash));
+        assert_noop!(PoeModule::buy_claim(RuntimeOrigin::signed(2), claim_hash, 100), Error::<Test>::NotListed);
+    });
+}
+
+/// 测试英式拍卖：被超过的出价立即退还，结束区块中最高出价者赢得存证，卖家收到扣除协议费后的货款
+#[test]
+fn test_english_auction() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_noop!(PoeModule::start_auction(RuntimeOrigin::signed(1), claim_hash, 100, 0), Error::<Test>::InvalidAuctionDuration);
+        assert_ok!(PoeModule::start_auction(RuntimeOrigin::signed(1), claim_hash, 100, 5));
+        assert_noop!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, claim_hash), Error::<Test>::ClaimInAuction);
+        assert_noop!(PoeModule::list_claim_for_sale(RuntimeOrigin::signed(1), claim_hash, 100), Error::<Test>::ClaimInAuction);
+
+        assert_noop!(PoeModule::bid(RuntimeOrigin::signed(1), claim_hash, 100), Error::<Test>::CannotBidOnOwnAuction);
+        assert_noop!(PoeModule::bid(RuntimeOrigin::signed(2), claim_hash, 99), Error::<Test>::BidTooLow);
+        assert_ok!(PoeModule::bid(RuntimeOrigin::signed(2), claim_hash, 100));
+        assert_eq!(Balances::reserved_balance(2), 100);
+        assert_noop!(PoeModule::bid(RuntimeOrigin::signed(3), claim_hash, 100), Error::<Test>::BidTooLow);
+        assert_ok!(PoeModule::bid(RuntimeOrigin::signed(3), claim_hash, 200));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::reserved_balance(3), 200);
+
+        System::set_block_number(6);
+        assert_noop!(PoeModule::bid(RuntimeOrigin::signed(2), claim_hash, 300), Error::<Test>::AuctionEnded);
+
+        let seller_free = Balances::free_balance(1);
+        PoeModule::on_initialize(6);
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 3);
+        assert!(!Auctions::<Test>::contains_key(claim_hash));
+        assert!(AuctionEnds::<Test>::get(6).is_empty());
+        assert_eq!(Balances::free_balance(1), seller_free + 180);
+        assert_eq!(Balances::free_balance(3), 800);
+        System::assert_has_event(RuntimeEvent::PoeModule(crate::Event::AuctionSettled {
+            seller: 1,
+            buyer: 3,
+            claim_hash,
+            price: 200,
+        }));
+    });
+}
+
+/// 测试没有出价的拍卖结束后存证留在卖家手中，撤销拍卖中的存证会退还最高出价
+#[test]
+fn test_auction_without_bids_and_revoke() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::start_auction(RuntimeOrigin::signed(1), claim_hash, 100, 5));
+        PoeModule::on_initialize(6);
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 1);
+        System::assert_has_event(RuntimeEvent::PoeModule(crate::Event::AuctionEndedWithoutBids { claim_hash }));
+
+        System::set_block_number(6);
+        assert_ok!(PoeModule::start_auction(RuntimeOrigin::signed(1), claim_hash, 100, 5));
+        assert_ok!(PoeModule::bid(RuntimeOrigin::signed(2), claim_hash, 100));
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert!(!Auctions::<Test>::contains_key(claim_hash));
+        assert!(AuctionEnds::<Test>::get(11).is_empty());
+    });
+}
+
+/// 测试荷兰式挂单：售价按区块线性下降至底价，买家按当前价格成交
+#[test]
+fn test_dutch_listing() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_noop!(
+            PoeModule::list_claim_dutch(RuntimeOrigin::signed(1), claim_hash, 100, 200, 10),
+            Error::<Test>::InvalidPriceRange
+        );
+        assert_ok!(PoeModule::list_claim_dutch(RuntimeOrigin::signed(1), claim_hash, 200, 100, 10));
+
+        let listing = Listings::<Test>::get(claim_hash).unwrap();
+        assert_eq!(listing.price_at(1), 200);
+        assert_eq!(listing.price_at(6), 150);
+        assert_eq!(listing.price_at(11), 100);
+        assert_eq!(listing.price_at(50), 100);
+
+        System::set_block_number(6);
+        assert_noop!(PoeModule::buy_claim(RuntimeOrigin::signed(2), claim_hash, 149), Error::<Test>::PriceTooHigh);
+
+        let seller_free = Balances::free_balance(1);
+        assert_ok!(PoeModule::buy_claim(RuntimeOrigin::signed(2), claim_hash, 200));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 2);
+        assert_eq!(Balances::free_balance(1), seller_free + 135);
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::ClaimSold {
+            seller: 1,
+            buyer: 2,
+            claim_hash,
+            price: 150,
+        }));
+    });
+}
+
+/// 测试版税：只有尚未转移过的存证的创建者可以设置，版税在转移后保留并在每次成交时支付
+#[test]
+fn test_royalty_on_sales() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_noop!(
+            PoeModule::set_royalty(RuntimeOrigin::signed(1), claim_hash, 1, Perbill::from_percent(21)),
+            Error::<Test>::RoyaltyTooHigh
+        );
+        assert_ok!(PoeModule::set_royalty(RuntimeOrigin::signed(1), claim_hash, 1, Perbill::from_percent(20)));
+
+        assert_ok!(PoeModule::list_claim_for_sale(RuntimeOrigin::signed(1), claim_hash, 100));
+        assert_ok!(PoeModule::buy_claim(RuntimeOrigin::signed(2), claim_hash, 100));
+        assert_noop!(
+            PoeModule::set_royalty(RuntimeOrigin::signed(2), claim_hash, 2, Perbill::from_percent(5)),
+            Error::<Test>::NotOriginalCreator
+        );
+        assert!(Royalties::<Test>::contains_key(claim_hash));
+
+        // 二次出售：成交价 100，协议费 10 销毁，版税 20 支付给创建者，卖家收到 70
+        let (creator_free, seller_free, buyer_free) =
+            (Balances::free_balance(1), Balances::free_balance(2), Balances::free_balance(3));
+        assert_ok!(PoeModule::list_claim_for_sale(RuntimeOrigin::signed(2), claim_hash, 100));
+        assert_ok!(PoeModule::buy_claim(RuntimeOrigin::signed(3), claim_hash, 100));
+        assert_eq!(Balances::free_balance(1), creator_free + 20);
+        assert_eq!(Balances::free_balance(2), seller_free + 70);
+        assert_eq!(Balances::free_balance(3), buyer_free - 100);
+        System::assert_has_event(RuntimeEvent::PoeModule(crate::Event::RoyaltyPaid {
+            claim_hash,
+            beneficiary: 1,
+            amount: 20,
+        }));
+    });
+}
+
+/// 测试创建存证时收取的费用转入国库，不随押金退还
+#[test]
+fn test_creation_fee_goes_to_treasury() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        CreationFee::set(5);
+
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1]).unwrap();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+        assert_eq!(Balances::free_balance(TREASURY), 5);
+        assert_eq!(Balances::free_balance(1), 1_000 - 60 - 5);
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), PoeModule::claim_hash(&claim)));
+        assert_eq!(Balances::free_balance(1), 1_000 - 5);
+    });
+}
+
+/// 测试免费名单：名单中的账户创建存证免交易费，且不保留押金、不支付创建费用
+#[test]
+fn test_fee_exempt_create_claim() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        CreationFee::set(5);
+
+        assert_noop!(PoeModule::add_fee_exempt(RuntimeOrigin::signed(1), 1), DispatchError::BadOrigin);
+        assert_ok!(PoeModule::add_fee_exempt(RuntimeOrigin::root(), 1));
+        assert_noop!(PoeModule::add_fee_exempt(RuntimeOrigin::root(), 1), Error::<Test>::AlreadyFeeExempt);
+        assert!(FeeExempt::<Test>::contains_key(1));
+
+        let post_info = PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+        assert_eq!(Proofs::<Test>::get(PoeModule::claim_hash(&[1])).unwrap().deposit, 0);
+        assert_eq!(Balances::free_balance(1), 1_000);
+        assert_eq!(Balances::reserved_balance(1), 0);
+
+        let post_info = PoeModule::create_claim(RuntimeOrigin::signed(2), BoundedVec::try_from(vec![2]).unwrap()).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::Yes);
+
+        assert_ok!(PoeModule::remove_fee_exempt(RuntimeOrigin::root(), 1));
+        assert_noop!(PoeModule::remove_fee_exempt(RuntimeOrigin::root(), 1), Error::<Test>::NotFeeExempt);
+    });
+}
+
+/// 测试撤销没有背书、子存证和共同所有者的存证时退还未使用的权重
+#[test]
+fn test_revoke_refunds_unused_weight() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        let post_info = PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash).unwrap();
+        // 背书、子存证和共同所有者的上限各为 2，都没有使用
+        assert_eq!(
+            post_info.actual_weight,
+            Some(<() as WeightInfo>::revoke_claim().saturating_sub(RocksDbWeight::get().writes(6)))
+        );
+
+        // 有一个背书时少退还一次写入
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::attest_claim(RuntimeOrigin::signed(2), claim_hash));
+        let post_info = PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash).unwrap();
+        assert_eq!(
+            post_info.actual_weight,
+            Some(<() as WeightInfo>::revoke_claim().saturating_sub(RocksDbWeight::get().writes(5)))
+        );
+    });
+}
+
+/// 测试转移没有共同所有者的存证、批量创建时没有覆盖过期存证时退还未使用的权重
+#[test]
+fn test_transfer_and_create_refund_unused_weight() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        // 共同所有者上限为 2，都没有使用
+        let post_info = PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash).unwrap();
+        assert_eq!(
+            post_info.actual_weight,
+            Some(<() as WeightInfo>::transfer_claim().saturating_sub(RocksDbWeight::get().reads_writes(2, 2)))
+        );
+
+        // mock 中清理一个过期存证的最坏情况为 29 次读取、61 次写入
+        let batch = BoundedVec::try_from(vec![
+            BoundedVec::try_from(vec![2]).unwrap(),
+            BoundedVec::try_from(vec![3]).unwrap(),
+        ])
+        .unwrap();
+        let post_info = PoeModule::create_claims(RuntimeOrigin::signed(1), batch).unwrap();
+        assert_eq!(
+            post_info.actual_weight,
+            Some(<() as WeightInfo>::create_claims(2).saturating_sub(RocksDbWeight::get().reads_writes(58, 122)))
+        );
+
+        let hashes = BoundedVec::try_from(vec![PoeModule::claim_hash(&[2]), PoeModule::claim_hash(&[3])]).unwrap();
+        let post_info = PoeModule::transfer_claims(RuntimeOrigin::signed(1), 2, hashes).unwrap();
+        assert_eq!(
+            post_info.actual_weight,
+            Some(<() as WeightInfo>::transfer_claims(2).saturating_sub(RocksDbWeight::get().reads_writes(4, 4)))
+        );
+    });
+}
+
+/// 测试管理员调用使用 Operational 类别且成功后免交易费，普通用户调用保持 Normal 类别
+#[test]
+fn test_admin_calls_are_operational() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let info = RuntimeCall::PoeModule(crate::Call::ban_account { who: 2 }).get_dispatch_info();
+        assert_eq!(info.class, DispatchClass::Operational);
+        assert_eq!(info.pays_fee, Pays::Yes);
+        let info = RuntimeCall::PoeModule(crate::Call::pause {}).get_dispatch_info();
+        assert_eq!(info.class, DispatchClass::Operational);
+        let info = RuntimeCall::PoeModule(crate::Call::create_claim { claim: BoundedVec::try_from(vec![1]).unwrap() })
+            .get_dispatch_info();
+        assert_eq!(info.class, DispatchClass::Normal);
+
+        // 授权失败时仍按声明的权重收费，成功后不收费
+        assert_noop!(PoeModule::ban_account(RuntimeOrigin::signed(1), 2), DispatchError::BadOrigin);
+        assert_eq!(PoeModule::ban_account(RuntimeOrigin::root(), 2).unwrap().pays_fee, Pays::No);
+    });
+}
+
+/// 测试 Root 调整最大存证长度后，超过新长度的存证不能创建
+#[test]
+fn test_set_max_claim_length() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_eq!(CurrentMaxLength::<Test>::get(), 4);
+        assert_noop!(PoeModule::set_max_claim_length(RuntimeOrigin::signed(1), 2), DispatchError::BadOrigin);
+        assert_noop!(PoeModule::set_max_claim_length(RuntimeOrigin::root(), 5), Error::<Test>::InvalidMaxClaimLength);
+        assert_noop!(PoeModule::set_max_claim_length(RuntimeOrigin::root(), 0), Error::<Test>::InvalidMaxClaimLength);
+        assert_ok!(PoeModule::set_max_claim_length(RuntimeOrigin::root(), 2));
+
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1, 2, 3]).unwrap()),
+            Error::<Test>::ClaimLengthTooLarge
+        );
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1, 2]).unwrap()));
+        System::assert_has_event(RuntimeEvent::PoeModule(crate::Event::MaxClaimLengthSet { max_length: 2 }));
+    });
+}
+
+/// 测试每个区块创建的存证数量受 `MaxClaimsPerBlock` 限制，计数在下一个区块开始时清零
+#[test]
+fn test_claims_per_block_limit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        MaxClaimsPerBlock::set(2);
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), BoundedVec::try_from(vec![2]).unwrap()));
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(3), BoundedVec::try_from(vec![3]).unwrap()),
+            Error::<Test>::BlockLimitReached
+        );
+        assert_eq!(ClaimsThisBlock::<Test>::get(), 2);
+
+        System::set_block_number(2);
+        PoeModule::on_initialize(2);
+        assert_eq!(ClaimsThisBlock::<Test>::get(), 0);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(3), BoundedVec::try_from(vec![3]).unwrap()));
+    });
+}
+
+/// 测试交易池去重：相同内容的登记交易提供相同的标签，不同内容或其他交易互不影响
+#[test]
+fn test_deduplicate_claims_extension() {
+    new_test_ext().execute_with(|| {
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1]).unwrap();
+        let validate = |who: u64, call: RuntimeCall| {
+            let info = call.get_dispatch_info();
+            DeduplicateClaims::<Test>::new().validate(&who, &call, &info, 0).unwrap()
+        };
+
+        let by_content = validate(1, RuntimeCall::PoeModule(crate::Call::create_claim { claim: claim.clone() }));
+        let by_hash = validate(
+            2,
+            RuntimeCall::PoeModule(crate::Call::create_claim_by_hash { claim_hash: PoeModule::claim_hash(&claim) }),
+        );
+        assert_eq!(by_content.provides.len(), 1);
+        assert_eq!(by_content.provides, by_hash.provides);
+
+        let other = validate(1, RuntimeCall::PoeModule(crate::Call::create_claim { claim: BoundedVec::try_from(vec![2]).unwrap() }));
+        assert_ne!(by_content.provides, other.provides);
+
+        let unrelated = validate(1, RuntimeCall::PoeModule(crate::Call::ping {}));
+        assert!(unrelated.provides.is_empty());
+    });
+}
+
+/// 测试无签名存证：签名无效或签名账户不在免费名单中时交易池拒绝，通过后由签名账户免押金拥有
+#[test]
+fn test_submit_claim_unsigned() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        let payload = ClaimPayload { public: UintAuthorityId(5), claim_hash, block_number: 1 };
+        let signature = UintAuthorityId(5).sign(&payload.encode()).unwrap();
+        let call = crate::Call::submit_claim_unsigned { payload: payload.clone(), signature: signature.clone() };
+
+        assert_eq!(
+            PoeModule::validate_unsigned(TransactionSource::External, &call),
+            InvalidTransaction::BadSigner.into()
+        );
+
+        assert_ok!(PoeModule::add_fee_exempt(RuntimeOrigin::root(), 5));
+        assert!(PoeModule::validate_unsigned(TransactionSource::External, &call).is_ok());
+
+        let forged = crate::Call::submit_claim_unsigned {
+            payload: payload.clone(),
+            signature: UintAuthorityId(6).sign(&payload.encode()).unwrap(),
+        };
+        assert_eq!(
+            PoeModule::validate_unsigned(TransactionSource::External, &forged),
+            InvalidTransaction::BadProof.into()
+        );
+
+        assert_noop!(
+            PoeModule::submit_claim_unsigned(RuntimeOrigin::signed(5), payload.clone(), signature.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(PoeModule::submit_claim_unsigned(RuntimeOrigin::none(), payload, signature));
+        let details = Proofs::<Test>::get(claim_hash).unwrap();
+        assert_eq!((details.owner, details.deposit), (5, 0));
+
+        assert_eq!(
+            PoeModule::validate_unsigned(TransactionSource::External, &call),
+            InvalidTransaction::Stale.into()
+        );
+    });
+}
+
+/// 测试链下工作机把本地存储中的存证哈希以无签名交易提交，并清空待提交列表
+#[test]
+fn test_offchain_worker_submits_pending_claims() {
+    let (offchain, _) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        UintAuthorityId::set_all_keys(vec![5u64]);
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, PENDING_CLAIMS_KEY, &vec![claim_hash].encode());
+
+        PoeModule::offchain_worker(1);
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        assert!(pool_state.read().transactions.is_empty());
+        let tx = TestXt::<RuntimeCall, ()>::decode(&mut &*tx).unwrap();
+        assert_eq!(tx.signature, None);
+        match tx.call {
+            RuntimeCall::PoeModule(crate::Call::submit_claim_unsigned { payload, .. }) => {
+                assert_eq!(payload, ClaimPayload { public: UintAuthorityId(5), claim_hash, block_number: 1 });
+            }
+            call => panic!("unexpected call: {:?}", call),
+        }
+        assert_eq!(sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, PENDING_CLAIMS_KEY), None);
+    });
+}
+
+/// 测试公证人报告存证内容的可用性，更换地址后不可访问的标记被清除
+#[test]
+fn test_report_availability() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        assert_ok!(PoeModule::register_notary(RuntimeOrigin::signed(2)));
+
+        assert_noop!(PoeModule::report_availability(RuntimeOrigin::signed(3), claim_hash, false), Error::<Test>::NotNotary);
+        assert_noop!(
+            PoeModule::report_availability(RuntimeOrigin::signed(2), claim_hash, true),
+            Error::<Test>::AvailabilityUnchanged
+        );
+        assert_ok!(PoeModule::report_availability(RuntimeOrigin::signed(2), claim_hash, false));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().status, ClaimStatus::ContentUnavailable);
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::ContentAvailabilityReported {
+            who: 2,
+            claim_hash,
+            available: false,
+        }));
+
+        let uri = Some(BoundedVec::try_from(b"https://example.com/new".to_vec()).unwrap());
+        assert_ok!(PoeModule::set_claim_metadata(RuntimeOrigin::signed(1), claim_hash, None, uri));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().status, ClaimStatus::Active);
+
+        assert_ok!(PoeModule::freeze_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert_noop!(PoeModule::report_availability(RuntimeOrigin::signed(2), claim_hash, false), Error::<Test>::ClaimFrozen);
+    });
+}
+
+/// 测试链下工作机按间隔检查 HTTP(S) 地址，可用性与链上状态不一致时提交签名交易
+#[test]
+fn test_offchain_worker_checks_availability() {
+    let (offchain, offchain_state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        UintAuthorityId::set_all_keys(vec![2u64]);
+        assert_ok!(PoeModule::register_notary(RuntimeOrigin::signed(2)));
+
+        let web: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1]).unwrap();
+        let web_hash = PoeModule::claim_hash(&web);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), web));
+        let uri = Some(BoundedVec::try_from(b"https://example.com/proof".to_vec()).unwrap());
+        assert_ok!(PoeModule::set_claim_metadata(RuntimeOrigin::signed(1), web_hash, None, uri));
+        assert_ok!(PoeModule::report_availability(RuntimeOrigin::signed(2), web_hash, false));
+
+        // 非 HTTP(S) 地址不会被请求
+        let ipfs: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![2]).unwrap();
+        let ipfs_hash = PoeModule::claim_hash(&ipfs);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), ipfs));
+        let uri = Some(BoundedVec::try_from(b"ipfs://proof".to_vec()).unwrap());
+        assert_ok!(PoeModule::set_claim_metadata(RuntimeOrigin::signed(1), ipfs_hash, None, uri));
+
+        // 不在检查间隔上的区块什么也不做
+        PoeModule::offchain_worker(9);
+        assert!(pool_state.read().transactions.is_empty());
+
+        offchain_state.write().expect_request(testing::PendingRequest {
+            method: "GET".into(),
+            uri: "https://example.com/proof".into(),
+            response: Some(b"proof".to_vec()),
+            sent: true,
+            ..Default::default()
+        });
+        PoeModule::offchain_worker(10);
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        assert!(pool_state.read().transactions.is_empty());
+        let tx = TestXt::<RuntimeCall, ()>::decode(&mut &*tx).unwrap();
+        assert!(tx.signature.is_some());
+        assert_eq!(
+            tx.call,
+            RuntimeCall::PoeModule(crate::Call::report_availability { claim_hash: web_hash, available: true })
+        );
+    });
+}
+
+/// 测试默克尔根：相邻节点拼接后哈希，奇数个节点时最后一个直接进入上一层
+#[test]
+fn test_merkle_root() {
+    new_test_ext().execute_with(|| {
+        let [a, b, c] = [1u8, 2, 3].map(|byte| PoeModule::claim_hash(&[byte]));
+        let node = |left: H256, right: H256| PoeModule::claim_hash(&[left.as_bytes(), right.as_bytes()].concat());
+
+        assert_eq!(PoeModule::merkle_root(&[]), H256::zero());
+        assert_eq!(PoeModule::merkle_root(&[a]), a);
+        assert_eq!(PoeModule::merkle_root(&[a, b]), node(a, b));
+        assert_eq!(PoeModule::merkle_root(&[a, b, c]), node(node(a, b), c));
+    });
+}
+
+/// 测试存证哈希使用配置的摘要算法，测试运行时的 Keccak-256 结果与以太坊工具一致
+#[test]
+fn test_claim_hash_uses_claim_hasher() {
+    new_test_ext().execute_with(|| {
+        let claim = b"hello".to_vec();
+        let claim_hash = H256(sp_io::hashing::keccak_256(&claim));
+        assert_eq!(PoeModule::claim_hash(&claim), claim_hash);
+        assert_ne!(claim_hash, <Test as frame_system::Config>::Hashing::hash(&claim));
+        let commitment = H256(sp_io::hashing::keccak_256(&[&claim[..], &[1; 32]].concat()));
+        assert_eq!(PoeModule::commitment_of(&claim, &[1; 32]), commitment);
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(claim).unwrap()));
+        assert!(Proofs::<Test>::contains_key(claim_hash));
+    });
+}
+
+/// 测试 CID 格式解析：文本 CIDv0、base32 与 base58btc 的 CIDv1，以及二进制形式
+#[test]
+fn test_content_id_parsing() {
+    let valid: [&[u8]; 5] = [
+        b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+        b"bafybeie5nqv6kd3qnfjupgvz34woh3oksc3iau6abmyajn7qvtf6d2ho34",
+        b"zdj7Wg2Qkk4mYgAkVU1kppfQ2sMGz5zPwERVpeWmxCQLDxVoC",
+        b"bafkqaaa",
+        &[0x01, 0x55, 0x00, 0x00],
+    ];
+    for content in valid {
+        assert!(cid::is_valid(content));
+    }
+
+    let invalid: [&[u8]; 6] = [
+        b"hello",
+        // 非 base58 字符，base32 截断，以及大写的 base32
+        b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0",
+        b"bafybeie5nqv6kd3qnfjupgvz34woh3oksc3iau6abmyajn7qvtf6d2ho3",
+        b"BAFKQAAA",
+        // 多哈希的摘要长度与实际不符，以及版本号不是 1
+        &[0x01, 0x55, 0x00, 0x01],
+        &[0x02, 0x55, 0x00, 0x00],
+    ];
+    for content in invalid {
+        assert!(!cid::is_valid(content));
+    }
+}
+
+/// 测试开启 CID 校验后只能按内容登记合法的 CID，按哈希登记不受影响
+#[test]
+fn test_validate_content_ids() {
+    new_test_ext().execute_with(|| {
+        ValidateContentIds::set(true);
+        let claim = BoundedVec::try_from(b"poe!".to_vec()).unwrap();
+        assert_noop!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()), Error::<Test>::InvalidContentId);
+        assert_ok!(PoeModule::create_claim_by_hash(RuntimeOrigin::signed(1), PoeModule::claim_hash(&claim)));
+
+        let cid = BoundedVec::try_from(vec![0x01, 0x55, 0x00, 0x00]).unwrap();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), cid.clone()));
+        assert!(Proofs::<Test>::contains_key(PoeModule::claim_hash(&cid)));
+
+        ValidateContentIds::set(false);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), BoundedVec::try_from(b"free".to_vec()).unwrap()));
+    });
+}
+
+/// 测试公证人记录锚定交易，同一个存证根只能记录一次
+#[test]
+fn test_record_anchor() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+        let root = PoeModule::claim_hash(b"root");
+        assert_noop!(PoeModule::record_anchor(RuntimeOrigin::signed(2), root, 1, 5, 3, [7; 32]), Error::<Test>::NotNotary);
+        assert_ok!(PoeModule::register_notary(RuntimeOrigin::signed(2)));
+
+        assert_noop!(
+            PoeModule::record_anchor(RuntimeOrigin::signed(2), root, 1, 6, 3, [7; 32]),
+            Error::<Test>::InvalidAnchorRange
+        );
+        assert_ok!(PoeModule::record_anchor(RuntimeOrigin::signed(2), root, 1, 5, 3, [7; 32]));
+        assert_eq!(
+            Anchors::<Test>::get(root),
+            Some(AnchorRecord { submitter: 2, from: 1, to: 5, count: 3, tx_hash: [7; 32], recorded_at: 5 })
+        );
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::AnchorRecorded {
+            who: 2,
+            root,
+            tx_hash: [7; 32],
+        }));
+        assert_noop!(
+            PoeModule::record_anchor(RuntimeOrigin::signed(2), root, 1, 5, 3, [7; 32]),
+            Error::<Test>::AnchorAlreadyRecorded
+        );
+    });
+}
+
+/// 测试链下工作机把锚定间隔内创建的存证的默克尔根发送到以太坊，并提交记录锚定的签名交易
+#[test]
+fn test_offchain_worker_anchors_recent_claims() {
+    let (offchain, offchain_state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        UintAuthorityId::set_all_keys(vec![2u64]);
+        sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, ANCHOR_ENDPOINT_KEY, b"http://localhost:8545");
+        sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, ANCHOR_ACCOUNT_KEY, b"0xab");
+
+        // 锚定间隔之前创建的存证不包含在本次的存证根中
+        System::set_block_number(20);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        System::set_block_number(21);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![2]).unwrap()));
+        System::set_block_number(40);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![3]).unwrap()));
+
+        let root = PoeModule::merkle_root(&[PoeModule::claim_hash(&[2]), PoeModule::claim_hash(&[3])]);
+        let body = format!(
+            r#"{{"jsonrpc":"2.0","id":1,"method":"eth_sendTransaction","params":[{{"from":"0xab","to":"0xab","data":"0x{}"}}]}}"#,
+            root.as_bytes().iter().map(|byte| format!("{:02x}", byte)).collect::<String>()
+        );
+        offchain_state.write().expect_request(testing::PendingRequest {
+            method: "POST".into(),
+            uri: "http://localhost:8545".into(),
+            headers: vec![("Content-Type".into(), "application/json".into())],
+            body: body.into_bytes(),
+            response: Some(format!(r#"{{"jsonrpc":"2.0","id":1,"result": "0x{}"}}"#, "0c".repeat(32)).into_bytes()),
+            sent: true,
+            ..Default::default()
+        });
+        PoeModule::offchain_worker(40);
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        assert!(pool_state.read().transactions.is_empty());
+        let tx = TestXt::<RuntimeCall, ()>::decode(&mut &*tx).unwrap();
+        assert_eq!(
+            tx.call,
+            RuntimeCall::PoeModule(crate::Call::record_anchor { root, from: 21, to: 40, count: 2, tx_hash: [0x0c; 32] })
+        );
+    });
+}
+
+/// 测试公证人提交原生代币价格
+#[test]
+fn test_report_price() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+        let price = FixedU128::from_rational(1, 2);
+        assert_noop!(PoeModule::report_price(RuntimeOrigin::signed(2), price), Error::<Test>::NotNotary);
+        assert_ok!(PoeModule::register_notary(RuntimeOrigin::signed(2)));
+
+        assert_noop!(
+            PoeModule::report_price(RuntimeOrigin::signed(2), FixedU128::from_u32(0)),
+            Error::<Test>::InvalidPrice
+        );
+        assert_ok!(PoeModule::report_price(RuntimeOrigin::signed(2), price));
+        assert_eq!(NativePrice::<Test>::get(), Some(PriceReport { price, reporter: 2, reported_at: 5 }));
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::PriceReported { who: 2, price }));
+    });
+}
+
+/// 测试每个押金周期按最新喂价重新计算基础押金，过期的喂价被忽略
+#[test]
+fn test_deposit_base_follows_price() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(PoeModule::register_notary(RuntimeOrigin::signed(2)));
+        // 尚未喂价时使用 DepositBase
+        PoeModule::on_initialize(50);
+        assert_eq!(EraDepositBase::<Test>::get(), None);
+        assert_eq!(PoeModule::deposit_for(1), 60);
+
+        // 单价 0.5 时，价值 1 的押金为 2 个代币
+        assert_ok!(PoeModule::report_price(RuntimeOrigin::signed(2), FixedU128::from_rational(1, 2)));
+        PoeModule::on_initialize(51);
+        assert_eq!(EraDepositBase::<Test>::get(), None);
+        PoeModule::on_initialize(100);
+        assert_eq!(EraDepositBase::<Test>::get(), Some(200));
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::DepositBaseUpdated {
+            deposit: 200,
+            price: FixedU128::from_rational(1, 2),
+        }));
+
+        System::set_block_number(100);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_eq!(Balances::reserved_balance(1), 210);
+        assert_eq!(Proofs::<Test>::get(PoeModule::claim_hash(&[1])).unwrap().deposit, 210);
+        // 承诺和默克尔根同样按本周期的基础押金保留
+        assert_ok!(PoeModule::commit_claim(RuntimeOrigin::signed(1), PoeModule::commitment_of(&[2], &[0; 32])));
+        assert_ok!(PoeModule::create_batch_root(RuntimeOrigin::signed(1), PoeModule::claim_hash(&[3]), 1));
+        assert_eq!(Balances::reserved_balance(1), 610);
+
+        // 喂价已超过 MaxPriceAge，保留上一个周期的押金
+        PoeModule::on_initialize(150);
+        assert_eq!(EraDepositBase::<Test>::get(), Some(200));
+
+        // 单价上涨后押金的代币数量随之减少
+        System::set_block_number(120);
+        assert_ok!(PoeModule::report_price(RuntimeOrigin::signed(2), FixedU128::from_u32(4)));
+        PoeModule::on_initialize(150);
+        assert_eq!(EraDepositBase::<Test>::get(), Some(25));
+        assert_eq!(PoeModule::deposit_for(1), 35);
+    });
+}
+
+/// 测试链下工作机在押金周期开始时读取价格接口并提交喂价
+#[test]
+fn test_offchain_worker_reports_price() {
+    let (offchain, offchain_state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        UintAuthorityId::set_all_keys(vec![2u64]);
+        sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, PRICE_ENDPOINT_KEY, b"http://localhost/price");
+
+        // 不在周期开始的区块不请求价格
+        PoeModule::offchain_worker(49);
+        assert!(pool_state.read().transactions.is_empty());
+
+        offchain_state.write().expect_request(testing::PendingRequest {
+            method: "GET".into(),
+            uri: "http://localhost/price".into(),
+            response: Some(br#"{"USD": 0.52}"#.to_vec()),
+            sent: true,
+            ..Default::default()
+        });
+        PoeModule::offchain_worker(50);
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        let tx = TestXt::<RuntimeCall, ()>::decode(&mut &*tx).unwrap();
+        assert_eq!(
+            tx.call,
+            RuntimeCall::PoeModule(crate::Call::report_price { price: FixedU128::from_rational(52, 100) })
+        );
+    });
+}
+
+/// 测试价格接口响应的解析
+#[test]
+fn test_parse_price() {
+    use crate::price::parse_price;
+
+    assert_eq!(parse_price(b"2"), Some(FixedU128::from_u32(2)));
+    assert_eq!(parse_price(br#"{"USD":0.000123}"#), Some(FixedU128::from_rational(123, 1_000_000)));
+    assert_eq!(parse_price(b"1.0000000000000000019"), Some(FixedU128::from_inner(1_000_000_000_000_000_001)));
+    assert_eq!(parse_price(br#"{"USD":0.0}"#), None);
+    assert_eq!(parse_price(br#"{"error":"unavailable"}"#), None);
+    assert_eq!(parse_price(b"999999999999999999999999"), None);
+}
+
+/// 测试存证的创建和撤销按账户顺序写入链下索引
+#[test]
+fn test_offchain_activity_index() {
+    let mut ext = new_test_ext();
+    let claim_hash = PoeModule::claim_hash(&[1]);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        System::set_block_number(2);
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert_eq!(ActivityCount::<Test>::get(1), 2);
+    });
+
+    ext.persist_offchain_overlay();
+    let db = ext.offchain_db();
+    let record = |index| {
+        db.get(&activity_key(&1u64, index)).map(|raw| ActivityRecord::<H256, u64>::decode(&mut &raw[..]).unwrap())
+    };
+    assert_eq!(record(0), Some(ActivityRecord { claim_hash, block_number: 1, kind: ActivityKind::Created }));
+    assert_eq!(record(1), Some(ActivityRecord { claim_hash, block_number: 2, kind: ActivityKind::Revoked }));
+    assert_eq!(record(2), None);
+}
+
+/// 测试批量存证：登记默克尔根后凭证明把单个文档上链，证明的位置或兄弟节点不符时拒绝
+#[test]
+fn test_batch_root_membership() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let leaves: Vec<H256> = (1u8..=5).map(|byte| PoeModule::claim_hash(&[byte])).collect();
+        let root = PoeModule::merkle_root(&leaves);
+        let node = |left: H256, right: H256| PoeModule::claim_hash(&[left.as_bytes(), right.as_bytes()].concat());
+        let proof = |leaf_index: u32, siblings: Vec<H256>| MerkleProof::<H256, ConstU32<8>> {
+            leaf_index,
+            siblings: BoundedVec::try_from(siblings).unwrap(),
+        };
+
+        assert_noop!(PoeModule::create_batch_root(RuntimeOrigin::signed(1), root, 0), Error::<Test>::InvalidBatchSize);
+        assert_ok!(PoeModule::create_batch_root(RuntimeOrigin::signed(1), root, 5));
+        assert_eq!(
+            BatchRoots::<Test>::get(root),
+            Some(BatchRoot { owner: 1, count: 5, created_at: 1, deposit: PoeModule::deposit_for(0) })
+        );
+        assert_noop!(
+            PoeModule::create_batch_root(RuntimeOrigin::signed(2), root, 5),
+            Error::<Test>::BatchRootAlreadyExists
+        );
+
+        // 第 2 个叶子：每一层都有兄弟节点
+        let abcd = node(node(leaves[0], leaves[1]), node(leaves[2], leaves[3]));
+        let second = proof(1, vec![leaves[0], node(leaves[2], leaves[3]), leaves[4]]);
+        assert_noop!(
+            PoeModule::prove_membership(RuntimeOrigin::signed(2), root, leaves[1], proof(0, second.siblings.to_vec())),
+            Error::<Test>::InvalidMerkleProof
+        );
+        assert_ok!(PoeModule::prove_membership(RuntimeOrigin::signed(2), root, leaves[1], second));
+        let details = Proofs::<Test>::get(leaves[1]).unwrap();
+        assert_eq!((details.owner, details.depositor), (1, 2));
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::BatchMembershipProven {
+            root,
+            claim_hash: leaves[1],
+        }));
+
+        // 最后一个叶子：前两层直接进入上一层，只需要一个兄弟节点
+        assert_noop!(
+            PoeModule::prove_membership(RuntimeOrigin::signed(2), root, leaves[4], proof(4, vec![abcd, abcd])),
+            Error::<Test>::InvalidMerkleProof
+        );
+        assert_ok!(PoeModule::prove_membership(RuntimeOrigin::signed(2), root, leaves[4], proof(4, vec![abcd])));
+
+        assert_noop!(PoeModule::remove_batch_root(RuntimeOrigin::signed(2), root), Error::<Test>::NotClaimOwner);
+        assert_ok!(PoeModule::remove_batch_root(RuntimeOrigin::signed(1), root));
+        assert!(!BatchRoots::<Test>::contains_key(root));
+        assert!(Proofs::<Test>::contains_key(leaves[1]));
+        assert_noop!(
+            PoeModule::prove_membership(RuntimeOrigin::signed(2), root, leaves[0], proof(0, vec![])),
+            Error::<Test>::BatchRootNotExist
+        );
+    });
+}
+
+/// 测试作者签名的存证：签名验证通过后保存作者公钥，签名无效或算法不一致时拒绝，撤销时一并删除
+#[test]
+fn test_create_signed_claim() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let claim: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![1, 2]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        let sr = sr25519::Pair::from_seed(&[1; 32]);
+        let ed = ed25519::Pair::from_seed(&[2; 32]);
+        let author = AuthorKey::Sr25519(sr.public());
+
+        assert_noop!(
+            PoeModule::create_signed_claim(
+                RuntimeOrigin::signed(1),
+                claim.clone(),
+                author.clone(),
+                AuthorSignature::Sr25519(sr.sign(&[9])),
+            ),
+            Error::<Test>::InvalidAuthorSignature
+        );
+        assert_noop!(
+            PoeModule::create_signed_claim(
+                RuntimeOrigin::signed(1),
+                claim.clone(),
+                author.clone(),
+                AuthorSignature::Ed25519(ed.sign(&claim)),
+            ),
+            Error::<Test>::InvalidAuthorSignature
+        );
+
+        assert_ok!(PoeModule::create_signed_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            author.clone(),
+            AuthorSignature::Sr25519(sr.sign(&claim)),
+        ));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 1);
+        assert_eq!(ClaimAuthors::<Test>::get(claim_hash), Some(author.clone()));
+        System::assert_last_event(RuntimeEvent::PoeModule(crate::Event::SignedClaimCreated {
+            who: 1,
+            claim_hash,
+            author,
+        }));
+
+        let other: BoundedVec<u8, ConstU32<4>> = BoundedVec::try_from(vec![3]).unwrap();
+        assert_ok!(PoeModule::create_signed_claim(
+            RuntimeOrigin::signed(2),
+            other.clone(),
+            AuthorKey::Ed25519(ed.public()),
+            AuthorSignature::Ed25519(ed.sign(&other)),
+        ));
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert_eq!(ClaimAuthors::<Test>::get(claim_hash), None);
+    });
+}
+
+/// 测试通过预映像创建存证：原文可以超过 `MaxClaimLength`，请求保留的原文在存证撤销后取消请求
+#[test]
+fn test_create_claim_from_preimage() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let content = vec![7u8; 1024];
+        let claim_hash = PoeModule::claim_hash(&content);
+        assert_noop!(
+            PoeModule::create_claim_from_preimage(RuntimeOrigin::signed(1), claim_hash, true),
+            Error::<Test>::PreimageNotExist
+        );
+
+        assert_eq!(TestPreimages::note(content.into()), Ok(claim_hash));
+        assert_ok!(PoeModule::create_claim_from_preimage(RuntimeOrigin::signed(1), claim_hash, true));
+        System::assert_last_event(
+            crate::Event::PreimageClaimCreated { who: 1, claim_hash, len: 1024, retained: true }.into(),
+        );
+        // 押金最多按 `MaxClaimLength` 收取
+        assert_eq!(Balances::reserved_balance(1), PoeModule::deposit_for(4));
+        assert!(RetainedPreimages::<Test>::contains_key(claim_hash));
+        assert!(TestPreimages::is_requested(&claim_hash));
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert!(!RetainedPreimages::<Test>::contains_key(claim_hash));
+        assert!(!TestPreimages::is_requested(&claim_hash));
+
+        assert_ok!(PoeModule::create_claim_from_preimage(RuntimeOrigin::signed(2), claim_hash, false));
+        assert!(!RetainedPreimages::<Test>::contains_key(claim_hash));
+        assert!(!TestPreimages::is_requested(&claim_hash));
+    });
+}
+
+/// 测试 NFT 镜像：存证创建、转移和撤销时同步 NFT，NFT 在外部被转移后可以把存证同步给新的持有者
+#[test]
+fn test_nft_mirror() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        assert_eq!(TestNftMirror::owner_of(&claim_hash), Some(1));
+        assert_noop!(PoeModule::sync_claim_owner(RuntimeOrigin::signed(3), claim_hash), Error::<Test>::NftOwnerUnchanged);
+
+        // NFT 在外部被转移，任何人都可以把存证同步给新的持有者
+        NFT_OWNERS.with(|owners| owners.borrow_mut().insert(claim_hash, 2));
+        assert_ok!(PoeModule::sync_claim_owner(RuntimeOrigin::signed(3), claim_hash));
+        assert_eq!(Proofs::<Test>::get(claim_hash).map(|details| details.owner), Some(2));
+        assert_eq!(PoeModule::claims_of(&2), vec![claim_hash]);
+
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(2), 1, claim_hash));
+        assert_eq!(TestNftMirror::owner_of(&claim_hash), Some(1));
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash));
+        assert_eq!(TestNftMirror::owner_of(&claim_hash), None);
+        assert_noop!(PoeModule::sync_claim_owner(RuntimeOrigin::signed(3), claim_hash), Error::<Test>::ClaimNotExist);
+
+        let unmirrored = PoeModule::claim_hash(b"hash");
+        assert_ok!(PoeModule::create_claim_by_hash(RuntimeOrigin::signed(1), unmirrored));
+        TestNftMirror::burn(&unmirrored);
+        assert_noop!(PoeModule::sync_claim_owner(RuntimeOrigin::signed(1), unmirrored), Error::<Test>::NftNotMirrored);
+    });
+}
+
+/// 测试 `nonfungibles_v2` 接口：以存证哈希为物品编号查询和转移存证，禁止转移即冻结存证
+#[test]
+fn test_nonfungibles_interface() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_eq!(<PoeModule as Inspect<u64>>::owner(&(), &claim_hash), None);
+        assert!(!<PoeModule as Inspect<u64>>::can_transfer(&(), &claim_hash));
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        let title = BoundedVec::try_from(b"title".to_vec()).unwrap();
+        assert_ok!(PoeModule::set_claim_metadata(RuntimeOrigin::signed(1), claim_hash, Some(title), None));
+        assert_eq!(<PoeModule as Inspect<u64>>::owner(&(), &claim_hash), Some(1));
+        assert_eq!(<PoeModule as Inspect<u64>>::attribute(&(), &claim_hash, b"title"), Some(b"title".to_vec()));
+        assert_eq!(<PoeModule as Inspect<u64>>::attribute(&(), &claim_hash, b"uri"), None);
+        assert!(<PoeModule as Inspect<u64>>::can_transfer(&(), &claim_hash));
+
+        assert_ok!(<PoeModule as Transfer<u64>>::transfer(&(), &claim_hash, &2));
+        assert_eq!(<PoeModule as Inspect<u64>>::owner(&(), &claim_hash), Some(2));
+        assert_eq!(PoeModule::claims_of(&2), vec![claim_hash]);
+
+        assert_ok!(<PoeModule as Transfer<u64>>::disable_transfer(&(), &claim_hash));
+        assert!(!<PoeModule as Inspect<u64>>::can_transfer(&(), &claim_hash));
+        assert_noop!(<PoeModule as Transfer<u64>>::transfer(&(), &claim_hash, &1), Error::<Test>::ClaimFrozen);
+        assert_noop!(<PoeModule as Transfer<u64>>::disable_transfer(&(), &claim_hash), Error::<Test>::ClaimFrozen);
+
+        assert_ok!(<PoeModule as Transfer<u64>>::enable_transfer(&(), &claim_hash));
+        assert_noop!(<PoeModule as Transfer<u64>>::enable_transfer(&(), &claim_hash), Error::<Test>::ClaimNotFrozen);
+        assert_ok!(<PoeModule as Transfer<u64>>::transfer(&(), &claim_hash, &1));
+        assert_eq!(<PoeModule as Inspect<u64>>::owner(&(), &claim_hash), Some(1));
+    });
+}
+
+/// 测试把存证转给可信兄弟平行链：源链发送登记存证的 `Transact` 并删除本地记录，目标链只接受可信来源
+#[test]
+fn test_transfer_claim_to_para() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+        let claim_hash = PoeModule::claim_hash(&claim);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+        assert_noop!(
+            PoeModule::transfer_claim_to_para(RuntimeOrigin::signed(1), claim_hash, 2000, 5),
+            Error::<Test>::UntrustedPara
+        );
+        assert_noop!(PoeModule::set_trusted_para(RuntimeOrigin::signed(1), 2000, true), DispatchError::BadOrigin);
+        assert_ok!(PoeModule::set_trusted_para(RuntimeOrigin::root(), 2000, true));
+        assert_noop!(
+            PoeModule::transfer_claim_to_para(RuntimeOrigin::signed(2), claim_hash, 2000, 5),
+            Error::<Test>::NotClaimOwner
+        );
+
+        // 删除本链记录失败时不发送消息
+        let rejected = BoundedVec::try_from(vec![0, 2]).unwrap();
+        let rejected_hash = PoeModule::claim_hash(&rejected);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(3), rejected));
+        assert_noop!(
+            PoeModule::transfer_claim_to_para(RuntimeOrigin::signed(3), rejected_hash, 2000, 5),
+            DispatchError::Other("revoke rejected by hook")
+        );
+        assert!(SENT_XCM.with(|sent| sent.borrow().is_empty()));
+
+        assert_ok!(PoeModule::transfer_claim_to_para(RuntimeOrigin::signed(1), claim_hash, 2000, 5));
+        System::assert_last_event(
+            crate::Event::ClaimSentToPara { who: 1, claim_hash, para_id: 2000, target: 5 }.into(),
+        );
+        assert!(!Proofs::<Test>::contains_key(claim_hash));
+        assert_eq!(Balances::reserved_balance(1), 0);
+
+        let (dest, message) = SENT_XCM.with(|sent| sent.borrow_mut().pop()).unwrap();
+        assert_eq!(dest, Location::new(1, [Junction::Parachain(2000)]));
+        let encoded = match message.0.as_slice() {
+            [Instruction::UnpaidExecution { .. }, Instruction::Transact { origin_kind: OriginKind::Xcm, call, .. }] => {
+                call.clone().into_encoded()
+            }
+            _ => panic!("unexpected message: {:?}", message),
+        };
+        assert_eq!(
+            RuntimeCall::decode(&mut &encoded[..]).unwrap(),
+            RuntimeCall::PoeModule(crate::Call::force_register_remote_claim { claim_hash, owner: 5 })
+        );
+
+        // 目标链上：只接受可信兄弟平行链的来源，转入的存证不保留押金
+        assert_noop!(PoeModule::force_register_remote_claim(RuntimeOrigin::signed(5), claim_hash, 5), DispatchError::BadOrigin);
+        assert_noop!(
+            PoeModule::force_register_remote_claim(RuntimeOrigin::signed(3000), claim_hash, 5),
+            Error::<Test>::UntrustedPara
+        );
+        assert_ok!(PoeModule::force_register_remote_claim(RuntimeOrigin::signed(2000), claim_hash, 5));
+        System::assert_last_event(crate::Event::RemoteClaimRegistered { para_id: 2000, who: 5, claim_hash }.into());
+        assert_eq!(Proofs::<Test>::get(claim_hash).map(|details| (details.owner, details.deposit)), Some((5, 0)));
+        assert_noop!(
+            PoeModule::force_register_remote_claim(RuntimeOrigin::signed(2000), claim_hash, 5),
+            Error::<Test>::ProofAlreadyExist.with_weight(RocksDbWeight::get().reads(2))
+        );
+    });
+}
+
+/// 测试其他链上的应用通过 XCM 创建存证：所有者为来源转换得到的本链账户，押金从该账户保留
+#[test]
+fn test_create_claim_via_xcm() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let claim_hash = PoeModule::claim_hash(b"remote");
+        assert_noop!(PoeModule::create_claim_via_xcm(RuntimeOrigin::signed(1), claim_hash), DispatchError::BadOrigin);
+
+        Balances::make_free_balance_be(&2000, 1_000);
+        assert_ok!(PoeModule::create_claim_via_xcm(RuntimeOrigin::signed(2000), claim_hash));
+        System::assert_last_event(
+            crate::Event::ClaimCreated { who: 2000, claim_hash, block: System::block_number() }.into(),
+        );
+        assert_eq!(Balances::reserved_balance(2000), PoeModule::deposit_for(4));
+        assert_noop!(
+            PoeModule::create_claim_via_xcm(RuntimeOrigin::signed(2000), claim_hash),
+            Error::<Test>::ProofAlreadyExist.with_weight(RocksDbWeight::get().reads(2))
+        );
+    });
+}
+
+/// 测试 try-state 不变量检查：正常操作后通过，存储不一致时报错
+#[test]
+fn test_try_state() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_a = BoundedVec::try_from(vec![1u8, 2]).unwrap();
+        let claim_b = BoundedVec::try_from(vec![3u8, 4]).unwrap();
+        let hash_a = PoeModule::claim_hash(&claim_a);
+        let hash_b = PoeModule::claim_hash(&claim_b);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim_a));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim_b));
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, hash_a));
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), hash_b));
+        assert_ok!(PoeModule::do_try_state());
+
+        // 索引缺失
+        OwnerClaims::<Test>::remove(2, hash_a);
+        assert!(PoeModule::do_try_state().is_err());
+        OwnerClaims::<Test>::insert(2, hash_a, ());
+        assert_ok!(PoeModule::do_try_state());
+
+        // 索引指向不存在的存证
+        OwnerClaims::<Test>::insert(1, hash_b, ());
+        assert!(PoeModule::do_try_state().is_err());
+        OwnerClaims::<Test>::remove(1, hash_b);
+
+        // 计数不一致
+        ClaimCountOf::<Test>::insert(2, 2);
+        assert!(PoeModule::do_try_state().is_err());
+        ClaimCountOf::<Test>::insert(2, 1);
+        assert_ok!(PoeModule::do_try_state());
+
+        // 长度上限超出配置
+        CurrentMaxLength::<Test>::put(5);
+        assert!(PoeModule::do_try_state().is_err());
+    });
+}
+
+/// 测试转移冷却期：冷却期内不能再次转移，强制转移不受限制
+#[test]
+fn test_transfer_cooldown() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        TransferCooldown::set(5);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().last_transfer, None);
+
+        // 新建的存证可以立即转移
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().last_transfer, Some(1));
+
+        System::set_block_number(5);
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, claim_hash),
+            Error::<Test>::CooldownActive
+        );
+
+        // 强制转移会跳过冷却期并重新计时
+        assert_ok!(PoeModule::force_transfer_claim(RuntimeOrigin::root(), claim_hash, 3));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().last_transfer, Some(5));
+
+        System::set_block_number(9);
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(3), 1, claim_hash),
+            Error::<Test>::CooldownActive
+        );
+        System::set_block_number(10);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(3), 1, claim_hash));
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 1);
+    });
+}
+
+/// 测试关注存证：转移和撤销时发出带有关注者列表的事件，存证删除后关注记录一并清除
+#[test]
+fn test_watch_claim() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_noop!(PoeModule::watch_claim(RuntimeOrigin::signed(2), claim_hash), Error::<Test>::ClaimNotExist);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+
+        assert_ok!(PoeModule::watch_claim(RuntimeOrigin::signed(2), claim_hash));
+        System::assert_last_event(crate::Event::ClaimWatched { who: 2, claim_hash }.into());
+        assert_noop!(PoeModule::watch_claim(RuntimeOrigin::signed(2), claim_hash), Error::<Test>::AlreadyWatching);
+        assert_ok!(PoeModule::watch_claim(RuntimeOrigin::signed(3), claim_hash));
+        assert_noop!(PoeModule::watch_claim(RuntimeOrigin::signed(4), claim_hash), Error::<Test>::TooManyWatchers);
+
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash));
+        System::assert_last_event(
+            crate::Event::WatchedClaimChanged {
+                claim_hash,
+                owner: Some(2),
+                watchers: BoundedVec::try_from(vec![2, 3]).unwrap(),
+            }
+            .into(),
+        );
+        assert_eq!(System::events().last().unwrap().topics, vec![claim_hash]);
+
+        assert_noop!(PoeModule::unwatch_claim(RuntimeOrigin::signed(4), claim_hash), Error::<Test>::NotWatching);
+        assert_ok!(PoeModule::unwatch_claim(RuntimeOrigin::signed(3), claim_hash));
+        System::assert_last_event(crate::Event::ClaimUnwatched { who: 3, claim_hash }.into());
+        assert_eq!(Watchers::<Test>::get(claim_hash).into_inner(), vec![2]);
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim_hash));
+        System::assert_has_event(
+            crate::Event::WatchedClaimChanged {
+                claim_hash,
+                owner: None,
+                watchers: BoundedVec::try_from(vec![2]).unwrap(),
+            }
+            .into(),
+        );
+        assert!(!Watchers::<Test>::contains_key(claim_hash));
+
+        // 没有关注者时只发出普通的转移事件
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, claim_hash));
+        System::assert_last_event(
+            crate::Event::ClaimTransfered { who: 1, target: 3, claim_hash, block: 1 }.into(),
+        );
+    });
+}
+
+/// 测试存证注释：所有者和背书人可以添加，记录作者和区块号，数量受上限约束，存证删除后清除
+#[test]
+fn test_annotate_claim() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        let note = |text: &[u8]| BoundedVec::try_from(text.to_vec()).unwrap();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+
+        assert_noop!(
+            PoeModule::annotate_claim(RuntimeOrigin::signed(1), claim_hash, note(b"")),
+            Error::<Test>::EmptyAnnotation
+        );
+        assert_noop!(
+            PoeModule::annotate_claim(RuntimeOrigin::signed(2), claim_hash, note(b"fake")),
+            Error::<Test>::NotAnnotator
+        );
+        assert_ok!(PoeModule::annotate_claim(RuntimeOrigin::signed(1), claim_hash, note(b"scanned")));
+        System::assert_last_event(crate::Event::ClaimAnnotated { who: 1, claim_hash, index: 0 }.into());
+
+        // 只有开启 `AttestersCanAnnotate` 时背书人才能注释
+        assert_ok!(PoeModule::attest_claim(RuntimeOrigin::signed(2), claim_hash));
+        AttestersCanAnnotate::set(false);
+        assert_noop!(
+            PoeModule::annotate_claim(RuntimeOrigin::signed(2), claim_hash, note(b"seen")),
+            Error::<Test>::NotAnnotator
+        );
+        AttestersCanAnnotate::set(true);
+        System::set_block_number(3);
+        assert_ok!(PoeModule::annotate_claim(RuntimeOrigin::signed(2), claim_hash, note(b"seen")));
+        System::assert_last_event(crate::Event::ClaimAnnotated { who: 2, claim_hash, index: 1 }.into());
+
+        assert_eq!(
+            Annotations::<Test>::get(claim_hash).into_inner(),
+            vec![
+                Annotation { author: 1, text: note(b"scanned"), block: 1 },
+                Annotation { author: 2, text: note(b"seen"), block: 3 },
+            ]
+        );
+        assert_noop!(
+            PoeModule::annotate_claim(RuntimeOrigin::signed(1), claim_hash, note(b"more")),
+            Error::<Test>::TooManyAnnotations
+        );
+
+        // 注释随存证转移保留
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 3, claim_hash));
+        assert_eq!(Annotations::<Test>::get(claim_hash).len(), 2);
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(3), claim_hash));
+        assert!(!Annotations::<Test>::contains_key(claim_hash));
+    });
+}
+
+/// 测试过期提醒：存证过期前 `WarningPeriod` 个区块发出 `ClaimExpiringSoon`，续期后按新的过期区块提醒
+#[test]
+fn test_expiry_warnings() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        WarningPeriod::set(10);
+
+        let claim_a = PoeModule::claim_hash(&[1]);
+        let claim_b = PoeModule::claim_hash(&[2]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), BoundedVec::try_from(vec![2]).unwrap()));
+        assert_ok!(PoeModule::renew_claim(RuntimeOrigin::signed(2), claim_b));
+        assert_eq!(ExpiringAt::<Test>::get(101).into_inner(), vec![claim_a]);
+        assert_eq!(ExpiringAt::<Test>::get(201).into_inner(), vec![claim_b]);
+
+        let warnings = || {
+            System::events()
+                .into_iter()
+                .filter_map(|record| match record.event {
+                    RuntimeEvent::PoeModule(crate::Event::ClaimExpiringSoon { claim_hash, expires_at, .. }) => {
+                        Some((claim_hash, expires_at))
+                    },
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        System::set_block_number(90);
+        PoeModule::on_finalize(90);
+        assert!(warnings().is_empty());
+
+        // 续期过的存证在旧的过期区块不再提醒
+        System::set_block_number(91);
+        PoeModule::on_finalize(91);
+        assert_eq!(warnings(), vec![(claim_a, 101)]);
+        System::assert_last_event(crate::Event::ClaimExpiringSoon { who: 1, claim_hash: claim_a, expires_at: 101 }.into());
+
+        System::reset_events();
+        System::set_block_number(191);
+        PoeModule::on_finalize(191);
+        assert_eq!(warnings(), vec![(claim_b, 201)]);
+
+        // 关闭提醒后不再发出事件
+        System::reset_events();
+        WarningPeriod::set(0);
+        PoeModule::on_finalize(191);
+        assert!(warnings().is_empty());
+    });
+}
+
+/// 测试过期索引已满时不能再创建同一区块过期的存证
+#[test]
+fn test_expiry_index_full() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let full: Vec<H256> = (0..64).map(H256::from_low_u64_be).collect();
+        ExpiringAt::<Test>::insert(101, BoundedVec::try_from(full).unwrap());
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()),
+            Error::<Test>::TooManyExpiring
+        );
+
+        System::set_block_number(2);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+        assert_eq!(ExpiringAt::<Test>::get(102).len(), 1);
+    });
+}
+
+/// 测试归属式转移：新所有者在归属期内可以注释但不能转移或撤销，强制转移不受归属期限制
+#[test]
+fn test_transfer_with_vesting() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+
+        assert_noop!(
+            PoeModule::transfer_with_vesting(RuntimeOrigin::signed(1), claim_hash, 2, 0),
+            Error::<Test>::InvalidVestingDuration
+        );
+        assert_noop!(
+            PoeModule::transfer_with_vesting(RuntimeOrigin::signed(2), claim_hash, 2, 10),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(PoeModule::transfer_with_vesting(RuntimeOrigin::signed(1), claim_hash, 2, 10));
+        System::assert_last_event(
+            crate::Event::VestingStarted { who: 1, target: 2, claim_hash, unlocks_at: 11 }.into(),
+        );
+        assert_eq!(Proofs::<Test>::get(claim_hash).unwrap().owner, 2);
+        assert_eq!(Vestings::<Test>::get(claim_hash), Some(Vesting { from: 1, unlocks_at: 11 }));
+
+        // 归属期内只有使用权
+        assert_ok!(PoeModule::annotate_claim(
+            RuntimeOrigin::signed(2),
+            claim_hash,
+            BoundedVec::try_from(b"received".to_vec()).unwrap()
+        ));
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, claim_hash),
+            Error::<Test>::ClaimVesting
+        );
+        assert_noop!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim_hash), Error::<Test>::ClaimVesting);
+
+        System::set_block_number(11);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(2), 3, claim_hash));
+        assert!(!Vestings::<Test>::contains_key(claim_hash));
+
+        // 强制转移清除归属记录
+        let other = PoeModule::claim_hash(&[2]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![2]).unwrap()));
+        assert_ok!(PoeModule::transfer_with_vesting(RuntimeOrigin::signed(1), other, 2, 10));
+        assert_ok!(PoeModule::force_transfer_claim(RuntimeOrigin::root(), other, 3));
+        assert_eq!(Proofs::<Test>::get(other).unwrap().owner, 3);
+        assert!(!Vestings::<Test>::contains_key(other));
+    });
+}
+
+/// 测试抵押锁定：锁定期间存证不能转移、撤销或挂单，只有登记的锁定方可以解锁，借贷 pallet 可以通过 trait 锁定
+#[test]
+fn test_collateral_lock() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let claim_hash = PoeModule::claim_hash(&[1]);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), BoundedVec::try_from(vec![1]).unwrap()));
+
+        assert_noop!(
+            PoeModule::lock_as_collateral(RuntimeOrigin::signed(2), claim_hash, 9),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(PoeModule::lock_as_collateral(RuntimeOrigin::signed(1), claim_hash, 9));
+        System::assert_last_event(crate::Event::CollateralLocked { who: 1, claim_hash, locker: 9 }.into());
+        assert_eq!(<PoeModule as ClaimCollateral<u64>>::collateral_locker(&claim_hash), Some(9));
+
+        assert_noop!(
+            PoeModule::lock_as_collateral(RuntimeOrigin::signed(1), claim_hash, 8),
+            Error::<Test>::ClaimLocked
+        );
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash),
+            Error::<Test>::ClaimLocked
+        );
+        assert_noop!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim_hash), Error::<Test>::ClaimLocked);
+        assert_noop!(
+            PoeModule::list_claim_for_sale(RuntimeOrigin::signed(1), claim_hash, 100),
+            Error::<Test>::ClaimLocked
+        );
+        assert_noop!(
+            PoeModule::force_transfer_claim(RuntimeOrigin::root(), claim_hash, 2),
+            Error::<Test>::ClaimLocked
+        );
+
+        assert_noop!(PoeModule::unlock_collateral(RuntimeOrigin::signed(1), claim_hash), Error::<Test>::NotLocker);
+        assert_ok!(PoeModule::unlock_collateral(RuntimeOrigin::signed(9), claim_hash));
+        System::assert_last_event(crate::Event::CollateralUnlocked { claim_hash, locker: 9 }.into());
+        assert_noop!(PoeModule::unlock_collateral(RuntimeOrigin::signed(9), claim_hash), Error::<Test>::NotLocked);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim_hash));
+
+        // 通过 trait 锁定，撤销存证前必须先由锁定方解锁
+        assert_noop!(
+            <PoeModule as ClaimCollateral<u64>>::lock_as_collateral(&1, claim_hash, &9),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(<PoeModule as ClaimCollateral<u64>>::lock_as_collateral(&2, claim_hash, &9));
+        assert_noop!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim_hash), Error::<Test>::ClaimLocked);
+        assert_ok!(<PoeModule as ClaimCollateral<u64>>::unlock_collateral(&9, claim_hash));
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim_hash));
+        assert!(!CollateralLocks::<Test>::contains_key(claim_hash));
+    });
+}
+
+/// 测试徽章：经过挑战期确认且未删除的存证达到 `BadgeThreshold` 时颁发一次，与账户绑定，存证转移后不随之转移
+#[test]
+fn test_badges() {
+    new_test_ext().execute_with(|| {
+        BadgeThreshold::set(2);
+        System::set_block_number(1);
+
+        let create = |who: u64, claim: u8| {
+            assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(who), BoundedVec::try_from(vec![claim]).unwrap()));
+        };
+        // 不经过挑战期的存证不计数
+        create(1, 1);
+        assert_eq!(ConfirmedClaimCount::<Test>::get(1), 0);
+
+        ChallengeWindow::set(5);
+        create(1, 2);
+        create(1, 3);
+        assert_eq!(PoeModule::badge_of(1), None);
+        System::set_block_number(6);
+        PoeModule::on_initialize(6);
+        System::assert_has_event(crate::Event::BadgeMinted { who: 1, confirmed: 2 }.into());
+        assert_eq!(Badges::<Test>::get(1), Some(Badge { minted_at: 6 }));
+
+        // 转移的存证不计入接收方的确认数量，徽章也不随存证转移
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), 2, PoeModule::claim_hash(&[2])));
+        assert_eq!(PoeModule::badge_of(2), None);
+        assert_eq!(ConfirmedClaimCount::<Test>::get(2), 0);
+
+        // 确认的存证删除后计数减少，反复创建和撤销不能累计确认数量
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), PoeModule::claim_hash(&[2])));
+        assert_eq!(ConfirmedClaimCount::<Test>::get(1), 1);
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), PoeModule::claim_hash(&[1])));
+        assert_eq!(ConfirmedClaimCount::<Test>::get(1), 1);
+
+        // 每个账户只颁发一次
+        System::set_block_number(7);
+        create(1, 4);
+        System::set_block_number(12);
+        PoeModule::on_initialize(12);
+        assert_eq!(ConfirmedClaimCount::<Test>::get(1), 2);
+        assert_eq!(PoeModule::badge_of(1), Some(Badge { minted_at: 6 }));
     });
 }
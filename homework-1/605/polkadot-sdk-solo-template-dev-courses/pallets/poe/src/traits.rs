@@ -0,0 +1,113 @@
+//! 供其他 pallet 使用的存证接口，调用方只依赖该 trait，而不依赖本 pallet 的内部实现
+
+use frame_support::pallet_prelude::DispatchResult;
+
+/// 查询和创建存证的能力
+pub trait ProofProvider<AccountId> {
+    /// 存证哈希的类型
+    type Hash;
+
+    /// 未过期存证的所有者
+    fn owner_of(claim_hash: &Self::Hash) -> Option<AccountId>;
+
+    /// 以 `owner` 的名义创建存证，押金从 `owner` 保留，与 `create_claim_by_hash` 的规则相同
+    fn create_for(owner: &AccountId, claim_hash: Self::Hash) -> DispatchResult;
+
+    /// 账户作为存证所有者的声誉，其他 pallet 可以据此限制声誉过低的账户使用某些功能
+    fn reputation_of(who: &AccountId) -> i32;
+}
+
+/// 把存证锁定为借贷抵押品的能力，借贷 pallet 在发放贷款前锁定借款人的存证，还款或清算后解锁
+///
+/// 锁定期间存证不能转移或撤销，只有锁定时登记的 `locker` 可以解锁。
+pub trait ClaimCollateral<AccountId> {
+    /// 存证哈希的类型
+    type Hash;
+
+    /// 把 `owner` 名下的存证锁定为 `locker` 的抵押品，调用方负责确认 `owner` 同意锁定
+    fn lock_as_collateral(owner: &AccountId, claim_hash: Self::Hash, locker: &AccountId) -> DispatchResult;
+
+    /// 由登记的锁定方解除锁定
+    fn unlock_collateral(locker: &AccountId, claim_hash: Self::Hash) -> DispatchResult;
+
+    /// 存证当前登记的锁定方，未锁定时返回 `None`
+    fn collateral_locker(claim_hash: &Self::Hash) -> Option<AccountId>;
+}
+
+/// 创建存证前的准入检查，运行时可以据此要求身份认证、成员资格或其他业务规则
+///
+/// 所有创建存证的途径（包括代付和其他 pallet 通过 [`ProofProvider`] 创建）都会先经过该检查，
+/// 返回错误时创建失败。`()` 允许所有账户创建，元组要求每个成员都允许。
+pub trait ClaimPolicy<AccountId, Hash> {
+    fn can_create(owner: &AccountId, claim_hash: &Hash) -> DispatchResult;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl<AccountId, Hash> ClaimPolicy<AccountId, Hash> for Tuple {
+    fn can_create(owner: &AccountId, claim_hash: &Hash) -> DispatchResult {
+        for_tuples!( #( Tuple::can_create(owner, claim_hash)?; )* );
+        Ok(())
+    }
+}
+
+/// 存证创建后的回调，下游 pallet（NFT 镜像、统计、索引等）可以据此同步状态
+///
+/// 回调在创建存证的调用中执行，返回错误会使整个调用回滚。
+pub trait OnClaimCreated<AccountId, Hash> {
+    fn on_claim_created(owner: &AccountId, claim_hash: &Hash) -> DispatchResult;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl<AccountId, Hash> OnClaimCreated<AccountId, Hash> for Tuple {
+    fn on_claim_created(owner: &AccountId, claim_hash: &Hash) -> DispatchResult {
+        for_tuples!( #( Tuple::on_claim_created(owner, claim_hash)?; )* );
+        Ok(())
+    }
+}
+
+/// 存证被撤销后的回调，语义与 [`OnClaimCreated`] 相同
+pub trait OnClaimRevoked<AccountId, Hash> {
+    fn on_claim_revoked(owner: &AccountId, claim_hash: &Hash) -> DispatchResult;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl<AccountId, Hash> OnClaimRevoked<AccountId, Hash> for Tuple {
+    fn on_claim_revoked(owner: &AccountId, claim_hash: &Hash) -> DispatchResult {
+        for_tuples!( #( Tuple::on_claim_revoked(owner, claim_hash)?; )* );
+        Ok(())
+    }
+}
+
+/// 把存证镜像为 NFT 的扩展点，存证创建、转移和删除时同步对应的 NFT，使存证可以在 NFT 市场中交易
+///
+/// NFT 在外部被转移后，新的持有者调用 `sync_claim_owner` 即可把存证转到自己名下。
+/// `()` 表示不镜像；`pallet_nfts` 可以使用 [`crate::nft::NonfungiblesMirror`]。
+pub trait ClaimNftMirror<AccountId, Hash> {
+    /// 存证创建后铸造对应的 NFT，返回错误时创建失败
+    fn mint(owner: &AccountId, claim_hash: &Hash) -> DispatchResult;
+
+    /// 存证转移后把 NFT 转给新的所有者，NFT 已在新所有者名下时不做任何事；返回错误时转移失败
+    fn transfer(claim_hash: &Hash, to: &AccountId) -> DispatchResult;
+
+    /// 存证删除后销毁对应的 NFT；存证的删除不能失败，销毁失败时忽略
+    fn burn(claim_hash: &Hash);
+
+    /// 对应 NFT 的当前持有者，没有镜像时返回 `None`
+    fn owner_of(claim_hash: &Hash) -> Option<AccountId>;
+}
+
+impl<AccountId, Hash> ClaimNftMirror<AccountId, Hash> for () {
+    fn mint(_owner: &AccountId, _claim_hash: &Hash) -> DispatchResult {
+        Ok(())
+    }
+
+    fn transfer(_claim_hash: &Hash, _to: &AccountId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn burn(_claim_hash: &Hash) {}
+
+    fn owner_of(_claim_hash: &Hash) -> Option<AccountId> {
+        None
+    }
+}
@@ -0,0 +1,541 @@
+//! PoE pallet 中存储使用的数据结构
+
+use crate::Config;
+use frame_support::{
+    pallet_prelude::*, traits::Currency, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::{
+    offchain::{SignedPayload, SigningTypes},
+    pallet_prelude::BlockNumberFor,
+};
+use sp_core::{ed25519, sr25519};
+use sp_runtime::{
+    traits::{AtLeast32BitUnsigned, Saturating, Verify},
+    FixedU128, Perbill,
+};
+use sp_std::vec::Vec;
+
+/// `Config::Currency` 的余额类型
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// `Config::Currency` 的负不平衡类型，即从账户扣除、等待处理的资金
+pub type NegativeImbalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
+/// 存证的状态
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ClaimStatus {
+    /// 正常有效的存证
+    #[default]
+    Active,
+    /// 被所有者冻结，不能转移但仍可撤销（例如作为抵押品或正在接受外部审查）
+    Frozen,
+    /// 公证人的链下工作机检测到 `uri` 指向的内容已无法访问，其余行为与 `Active` 相同
+    ContentUnavailable,
+}
+
+/// 存证的标题
+pub type ClaimTitleOf<T> = BoundedVec<u8, <T as Config>::MaxTitleLength>;
+
+/// 存证指向的外部资源地址
+pub type ClaimUriOf<T> = BoundedVec<u8, <T as Config>::MaxUriLength>;
+
+/// 存证注释的内容
+pub type AnnotationTextOf<T> = BoundedVec<u8, <T as Config>::MaxAnnotationLength>;
+
+/// 存证集合的编号
+pub type CollectionId = u32;
+
+/// 打包出售的编号
+pub type BundleId = u32;
+
+/// 存证集合的元数据
+pub type CollectionMetadataOf<T> = BoundedVec<u8, <T as Config>::MaxCollectionMetadataLength>;
+
+/// 一组作为整体管理的存证，例如同一出版方的全部作品
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Collection<AccountId, Metadata> {
+    /// 集合的所有者，集合中的存证都归该账户所有
+    pub owner: AccountId,
+    /// 集合的元数据（如名称或链下描述的哈希）
+    pub metadata: Metadata,
+}
+
+/// 命名空间前缀
+pub type NamespaceOf<T> = BoundedVec<u8, <T as Config>::MaxNamespaceLength>;
+
+/// 已注册的命名空间，以该前缀开头的存证内容只能由所有者登记
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Namespace<AccountId, BlockNumber, Balance> {
+    /// 命名空间的所有者
+    pub owner: AccountId,
+    /// 到达该区块后命名空间失效，任何人都可以重新注册
+    pub expires_at: BlockNumber,
+    /// 注册时保留的押金，失效后被他人重新注册时退还
+    pub deposit: Balance,
+}
+
+impl<AccountId, BlockNumber: PartialOrd, Balance> Namespace<AccountId, BlockNumber, Balance> {
+    /// 命名空间在 `now` 时是否已经失效
+    pub fn is_expired(&self, now: BlockNumber) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// 等待接收方确认的存证转移
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingTransfer<AccountId, BlockNumber> {
+    /// 发起转移时的所有者
+    pub from: AccountId,
+    /// 接收方，只有该账户可以接受转移
+    pub to: AccountId,
+    /// 超过该区块后转移邀请失效
+    pub expires_at: BlockNumber,
+}
+
+/// 针对存证的争议
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Dispute<AccountId, Hash, BlockNumber, Balance> {
+    /// 发起争议的账户
+    pub challenger: AccountId,
+    /// 链下证据的哈希
+    pub evidence: Hash,
+    /// 发起争议时保留的保证金
+    pub bond: Balance,
+    /// 发起争议的区块号
+    pub raised_at: BlockNumber,
+}
+
+/// 尚未仲裁的虚假存证举报
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FraudReport<AccountId, Hash, BlockNumber, Balance> {
+    /// 举报人
+    pub reporter: AccountId,
+    /// 链下证据的哈希
+    pub evidence: Hash,
+    /// 举报时保留的保证金
+    pub bond: Balance,
+    /// 举报的区块号
+    pub reported_at: BlockNumber,
+}
+
+/// 仲裁结果
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum DisputeResolution {
+    /// 驳回争议，没收发起方的保证金
+    Reject,
+    /// 支持发起方，存证强制转给发起方
+    TransferToChallenger,
+    /// 支持发起方，存证被强制撤销
+    Revoke,
+}
+
+/// 共同所有者批准的操作
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CoOwnerAction<AccountId> {
+    /// 撤销存证
+    Revoke,
+    /// 把存证转给指定账户
+    Transfer(AccountId),
+    /// 挂单出售存证
+    Sell,
+}
+
+/// 撤销存证需要的多签门限
+#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct RevocationGuard<T: Config> {
+    /// 可以批准撤销的账户
+    pub signatories: BoundedVec<T::AccountId, T::MaxSignatories>,
+    /// 撤销前至少需要的批准数量
+    pub threshold: u32,
+}
+
+/// 所有者预先设置的社交恢复方案
+#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct RecoveryConfig<T: Config> {
+    /// 可以发起恢复的监护人
+    pub guardians: BoundedVec<T::AccountId, T::MaxGuardians>,
+    /// 启动恢复至少需要的监护人数量
+    pub threshold: u32,
+    /// 恢复启动后需要等待的区块数，期间所有者可以否决
+    pub delay: BlockNumberFor<T>,
+}
+
+/// 正在进行的社交恢复
+#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct ActiveRecovery<T: Config> {
+    /// 存证将转入的新账户
+    pub new_owner: T::AccountId,
+    /// 已经发起恢复的监护人
+    pub vouchers: BoundedVec<T::AccountId, T::MaxGuardians>,
+    /// 监护人数量达到门限的区块号，之前为 `None`
+    pub started_at: Option<BlockNumberFor<T>>,
+}
+
+/// 存证的继承设置
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Inheritance<AccountId, BlockNumber> {
+    /// 继承人
+    pub heir: AccountId,
+    /// 所有者连续多少个区块没有活动后，继承人可以取得存证
+    pub inactivity_blocks: BlockNumber,
+}
+
+/// 预约在指定区块执行的存证转移
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ScheduledTransfer<AccountId, BlockNumber> {
+    /// 预约时的所有者，执行时所有权已变化则不再转移
+    pub from: AccountId,
+    /// 接收方
+    pub to: AccountId,
+    /// 执行转移的区块号
+    pub at: BlockNumber,
+}
+
+/// 存证的出售挂单
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Listing<AccountId, Balance, BlockNumber> {
+    /// 卖家，即挂单时的所有者
+    pub seller: AccountId,
+    /// 售价；荷兰式挂单中为起始价
+    pub price: Balance,
+    /// 荷兰式挂单的降价规则，`None` 表示固定价格
+    pub decay: Option<PriceDecay<Balance, BlockNumber>>,
+}
+
+/// 荷兰式挂单的降价规则：售价从起始价按区块线性下降，到 `end` 时降至底价并保持不变
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PriceDecay<Balance, BlockNumber> {
+    /// 底价
+    pub floor: Balance,
+    /// 开始降价的区块号
+    pub start: BlockNumber,
+    /// 降至底价的区块号
+    pub end: BlockNumber,
+}
+
+impl<AccountId, Balance, BlockNumber> Listing<AccountId, Balance, BlockNumber>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+    BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+    /// 挂单在 `now` 时的售价
+    pub fn price_at(&self, now: BlockNumber) -> Balance {
+        let Some(decay) = &self.decay else {
+            return self.price;
+        };
+        if now >= decay.end {
+            return decay.floor;
+        }
+        let elapsed = Perbill::from_rational(now.saturating_sub(decay.start), decay.end.saturating_sub(decay.start));
+        self.price.saturating_sub(elapsed * self.price.saturating_sub(decay.floor))
+    }
+}
+
+/// 存证的版税设置，存证每次通过出售或拍卖成交时按比例支付给受益人
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Royalty<AccountId> {
+    /// 版税受益人
+    pub beneficiary: AccountId,
+    /// 版税占成交价的比例
+    pub rate: Perbill,
+}
+
+/// 存证的英式拍卖，出价最高者在结束区块赢得存证
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Auction<AccountId, Balance, BlockNumber> {
+    /// 卖家，即发起拍卖时的所有者
+    pub seller: AccountId,
+    /// 最低出价
+    pub min_bid: Balance,
+    /// 结束区块号，该区块的 `on_initialize` 中结算
+    pub end: BlockNumber,
+    /// 当前最高出价及出价人，出价金额从出价人保留
+    pub best_bid: Option<(AccountId, Balance)>,
+}
+
+/// 存证所有者授予的使用许可
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct License<Hash, BlockNumber> {
+    /// 链下许可条款的哈希
+    pub terms_hash: Hash,
+    /// 许可的到期区块号，当前区块达到该值时许可失效
+    pub expiry: BlockNumber,
+    /// 授予或最近一次更新许可的区块号
+    pub granted_at: BlockNumber,
+}
+
+/// 付费许可的付款计划，每期费用在期初到期
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LicenseFee<Balance, BlockNumber> {
+    /// 每期的许可费
+    pub amount: Balance,
+    /// 付款周期（区块数）
+    pub period: BlockNumber,
+    /// 已付款的截止区块，`None` 表示被许可人尚未接受付款计划
+    pub paid_until: Option<BlockNumber>,
+}
+
+/// 托管出售，货款从买家保留到争议期结束
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Escrow<AccountId, Hash, BlockNumber, Balance> {
+    /// 卖家，放款时的收款方
+    pub seller: AccountId,
+    /// 买家，即当前的所有者
+    pub buyer: AccountId,
+    /// 成交价
+    pub price: Balance,
+    /// 争议期结束、自动放款的区块号
+    pub release_at: BlockNumber,
+    /// 买家发起的争议：链下证据的哈希和保留的保证金
+    pub dispute: Option<(Hash, Balance)>,
+}
+
+/// 多个存证以一个总价打包出售，打包中的存证保存在 `BundleClaims`
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Bundle<AccountId, Balance> {
+    /// 卖家，即打包时全部存证的所有者
+    pub seller: AccountId,
+    /// 全部存证的总价
+    pub price: Balance,
+}
+
+/// 灵魂绑定徽章，确认的存证达到 `BadgeThreshold` 时颁发给所有者
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Badge<BlockNumber> {
+    /// 颁发徽章的区块号
+    pub minted_at: BlockNumber,
+}
+
+/// 归属式转移的记录，归属期结束前新所有者只有使用权
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Vesting<AccountId, BlockNumber> {
+    /// 转出存证的原所有者
+    pub from: AccountId,
+    /// 完整所有权解锁的区块号
+    pub unlocks_at: BlockNumber,
+}
+
+/// 附加在存证上的一条溯源注释
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Annotation<AccountId, Text, BlockNumber> {
+    /// 添加注释的账户
+    pub author: AccountId,
+    /// 注释内容
+    pub text: Text,
+    /// 添加注释的区块号
+    pub block: BlockNumber,
+}
+
+/// 链下工作机以无签名交易提交的存证，由 `public` 对应的密钥签名
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ClaimPayload<Public, Hash, BlockNumber> {
+    /// 签名的公钥，对应的账户成为存证所有者
+    pub public: Public,
+    /// 要创建的存证哈希
+    pub claim_hash: Hash,
+    /// 链下工作机生成载荷时的区块号
+    pub block_number: BlockNumber,
+}
+
+impl<T: SigningTypes, Hash: Encode, BlockNumber: Encode> SignedPayload<T> for ClaimPayload<T::Public, Hash, BlockNumber> {
+    fn public(&self) -> T::Public {
+        self.public.clone()
+    }
+}
+
+/// 链下索引中存证活动的键前缀
+pub const ACTIVITY_INDEX_PREFIX: &[u8] = b"poe::activity";
+
+/// 账户的第 `index` 条存证活动在链下数据库中的键
+pub fn activity_key<AccountId: Encode>(owner: &AccountId, index: u32) -> Vec<u8> {
+    (ACTIVITY_INDEX_PREFIX, owner, index).encode()
+}
+
+/// 存证活动的类型
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ActivityKind {
+    /// 创建了存证
+    Created,
+    /// 撤销了存证
+    Revoked,
+}
+
+/// 写入链下索引的存证活动，浏览器可以通过 `poe_getActivity` RPC 按账户读取
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ActivityRecord<Hash, BlockNumber> {
+    /// 存证哈希
+    pub claim_hash: Hash,
+    /// 活动发生的区块号
+    pub block_number: BlockNumber,
+    /// 活动类型
+    pub kind: ActivityKind,
+}
+
+/// 对存证内容签名的作者公钥
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AuthorKey {
+    /// sr25519 公钥
+    Sr25519(sr25519::Public),
+    /// ed25519 公钥
+    Ed25519(ed25519::Public),
+}
+
+/// 作者对存证原始内容的签名，算法需要与 [`AuthorKey`] 一致
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum AuthorSignature {
+    /// sr25519 签名
+    Sr25519(sr25519::Signature),
+    /// ed25519 签名
+    Ed25519(ed25519::Signature),
+}
+
+impl AuthorKey {
+    /// `signature` 是否是该公钥对 `message` 的有效签名
+    pub fn verify(&self, message: &[u8], signature: &AuthorSignature) -> bool {
+        match (self, signature) {
+            (AuthorKey::Sr25519(public), AuthorSignature::Sr25519(signature)) => signature.verify(message, public),
+            (AuthorKey::Ed25519(public), AuthorSignature::Ed25519(signature)) => signature.verify(message, public),
+            _ => false,
+        }
+    }
+}
+
+/// 一批文档哈希的默克尔根
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct BatchRoot<AccountId, Balance, BlockNumber> {
+    /// 登记者，批量中的存证上链后归其所有
+    pub owner: AccountId,
+    /// 默克尔树的叶子数量，决定了树的形状
+    pub count: u32,
+    /// 登记的区块号
+    pub created_at: BlockNumber,
+    /// 登记时保留的押金
+    pub deposit: Balance,
+}
+
+/// 批量存证中单个叶子的默克尔证明
+#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(MaxDepth))]
+pub struct MerkleProof<Hash: Clone + Eq + PartialEq + core::fmt::Debug, MaxDepth: Get<u32>> {
+    /// 叶子在批量中的位置，从 0 开始
+    pub leaf_index: u32,
+    /// 从叶子到根路径上的兄弟节点，奇数层的最后一个节点没有兄弟节点
+    pub siblings: BoundedVec<Hash, MaxDepth>,
+}
+
+/// 存证根在以太坊上的锚定记录
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AnchorRecord<AccountId, BlockNumber> {
+    /// 记录锚定的公证人
+    pub submitter: AccountId,
+    /// 存证根覆盖的第一个区块
+    pub from: BlockNumber,
+    /// 存证根覆盖的最后一个区块
+    pub to: BlockNumber,
+    /// 存证根包含的存证数量
+    pub count: u32,
+    /// 以太坊上锚定交易的哈希
+    pub tx_hash: [u8; 32],
+    /// 记录上链的区块号
+    pub recorded_at: BlockNumber,
+}
+
+/// 公证人提交的原生代币价格
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PriceReport<AccountId, BlockNumber> {
+    /// 一个完整原生代币以法币计价的单价
+    pub price: FixedU128,
+    /// 提交价格的公证人
+    pub reporter: AccountId,
+    /// 价格上链的区块号
+    pub reported_at: BlockNumber,
+}
+
+/// 提交-揭示流程中尚未揭示的承诺
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ClaimCommitment<BlockNumber, Balance> {
+    /// 提交承诺时的区块号，揭示后作为该存证的创建区块号
+    pub committed_at: BlockNumber,
+    /// 提交承诺时保留的押金，揭示时退还
+    pub deposit: Balance,
+}
+
+/// `Proofs` 中保存的存证详情
+#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct ClaimDetails<T: Config> {
+    /// 存证的所有者
+    pub owner: T::AccountId,
+    /// 存证创建时的区块号，转移存证时保持不变
+    pub created_at: BlockNumberFor<T>,
+    /// 可选的标题
+    pub title: Option<ClaimTitleOf<T>>,
+    /// 可选的外部资源地址（如 IPFS / HTTP 链接）
+    pub uri: Option<ClaimUriOf<T>>,
+    /// 存证状态
+    pub status: ClaimStatus,
+    /// 存证过期的区块号，到达该区块后存证视为不存在
+    pub expires_at: BlockNumberFor<T>,
+    /// 创建时实际保留的押金，退还时以此为准，不受之后押金参数调整的影响
+    pub deposit: BalanceOf<T>,
+    /// 押金从哪个账户保留，代付创建的存证为代付方，否则与所有者相同
+    pub depositor: T::AccountId,
+    /// 最近一次计入转移冷却期的转移区块号，从未转移过时为 `None`
+    pub last_transfer: Option<BlockNumberFor<T>>,
+}
+
+impl<T: Config> ClaimDetails<T> {
+    /// 创建一个不带元数据、由所有者自己支付押金的存证
+    pub fn new(
+        owner: T::AccountId,
+        created_at: BlockNumberFor<T>,
+        expires_at: BlockNumberFor<T>,
+        deposit: BalanceOf<T>,
+    ) -> Self {
+        Self {
+            depositor: owner.clone(),
+            owner,
+            created_at,
+            title: None,
+            uri: None,
+            status: ClaimStatus::Active,
+            expires_at,
+            deposit,
+            last_transfer: None,
+        }
+    }
+
+    /// 存证在 `now` 时是否已经过期
+    pub fn is_expired(&self, now: BlockNumberFor<T>) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// 存证活动统计，用于单个区块的快照和链上的累计值
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ClaimStats {
+    /// 创建的存证数量
+    pub created: u64,
+    /// 撤销的存证数量，过期清理不计入
+    pub revoked: u64,
+    /// 转移的次数
+    pub transferred: u64,
+}
+
+impl ClaimStats {
+    /// 把另一份统计累加到自身
+    pub fn accrue(&mut self, other: &Self) {
+        self.created = self.created.saturating_add(other.created);
+        self.revoked = self.revoked.saturating_add(other.revoked);
+        self.transferred = self.transferred.saturating_add(other.transferred);
+    }
+}
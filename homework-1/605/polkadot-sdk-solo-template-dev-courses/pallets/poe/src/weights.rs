@@ -0,0 +1,7701 @@
+//! Weights for pallet_poe
+//!
+//! 这些权重是手工估算的占位值，不是 `benchmark pallet` 的测量结果。每个函数上方列出的存储读写
+//! 按代码中的最坏路径逐项统计，例如创建存证时覆盖并清理一个已过期的存证；执行时间和证明大小为估计值。
+//! 上线前应在参考硬件上用下面的命令重新生成本文件。
+
+// Regenerate with:
+// ./target/release/solochain-template-node
+// benchmark
+// pallet
+// --chain
+// dev
+// --pallet
+// pallet_poe
+// --extrinsic
+// *
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --output
+// pallets/poe/src/weights.rs
+// --template
+// ../../.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_poe.
+pub trait WeightInfo {
+	fn create_claim() -> Weight;
+	fn revoke_claim() -> Weight;
+	fn transfer_claim() -> Weight;
+	fn create_claim_by_hash() -> Weight;
+	fn set_claim_metadata() -> Weight;
+	fn renew_claim() -> Weight;
+	fn offer_claim() -> Weight;
+	fn accept_claim() -> Weight;
+	fn cancel_offer() -> Weight;
+	fn approve_transfer() -> Weight;
+	fn cancel_approval() -> Weight;
+	fn transfer_claim_from() -> Weight;
+	fn set_operator() -> Weight;
+	fn force_revoke_claim() -> Weight;
+	fn force_transfer_claim() -> Weight;
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
+	fn create_claims(n: u32) -> Weight;
+	fn revoke_claims(n: u32) -> Weight;
+	fn transfer_claims(n: u32) -> Weight;
+	fn set_allow_sponsorship() -> Weight;
+	fn create_claim_for() -> Weight;
+	fn commit_claim() -> Weight;
+	fn reveal_claim() -> Weight;
+	fn freeze_claim() -> Weight;
+	fn unfreeze_claim() -> Weight;
+	fn raise_dispute() -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn attest_claim() -> Weight;
+	fn revoke_attestation() -> Weight;
+	fn register_notary() -> Weight;
+	fn unregister_notary() -> Weight;
+	fn notarize_claim() -> Weight;
+	fn slash_notary() -> Weight;
+	fn supersede_claim() -> Weight;
+	fn create_child_claim() -> Weight;
+	fn create_collection() -> Weight;
+	fn add_claim_to_collection() -> Weight;
+	fn transfer_collection(n: u32) -> Weight;
+	fn register_namespace() -> Weight;
+	fn transfer_namespace() -> Weight;
+	fn ban_claim_hash() -> Weight;
+	fn unban_claim_hash() -> Weight;
+	fn ban_account() -> Weight;
+	fn unban_account() -> Weight;
+	fn add_co_owner() -> Weight;
+	fn remove_co_owner() -> Weight;
+	fn approve_co_owner_action() -> Weight;
+	fn set_revocation_threshold() -> Weight;
+	fn approve_revocation() -> Weight;
+	fn set_recovery_config() -> Weight;
+	fn initiate_recovery() -> Weight;
+	fn veto_recovery() -> Weight;
+	fn complete_recovery(n: u32) -> Weight;
+	fn set_inheritor() -> Weight;
+	fn ping() -> Weight;
+	fn claim_inheritance() -> Weight;
+	fn schedule_transfer() -> Weight;
+	fn cancel_scheduled_transfer() -> Weight;
+	fn begin_revoke() -> Weight;
+	fn finalize_revoke() -> Weight;
+	fn cancel_revoke() -> Weight;
+	fn list_claim_for_sale() -> Weight;
+	fn buy_claim() -> Weight;
+	fn cancel_listing() -> Weight;
+	fn start_auction() -> Weight;
+	fn bid() -> Weight;
+	fn list_claim_dutch() -> Weight;
+	fn set_royalty() -> Weight;
+	fn add_fee_exempt() -> Weight;
+	fn remove_fee_exempt() -> Weight;
+	fn set_max_claim_length() -> Weight;
+	fn submit_claim_unsigned() -> Weight;
+	fn report_availability() -> Weight;
+	fn record_anchor() -> Weight;
+	fn create_batch_root() -> Weight;
+	fn prove_membership(d: u32) -> Weight;
+	fn remove_batch_root() -> Weight;
+	fn create_signed_claim() -> Weight;
+	fn create_claim_from_preimage() -> Weight;
+	fn sync_claim_owner() -> Weight;
+	fn set_trusted_para() -> Weight;
+	fn transfer_claim_to_para() -> Weight;
+	fn force_register_remote_claim() -> Weight;
+	fn report_price() -> Weight;
+	fn report_claim() -> Weight;
+	fn settle_report() -> Weight;
+	fn grant_license() -> Weight;
+	fn revoke_license() -> Weight;
+	fn set_license_fee() -> Weight;
+	fn settle_license() -> Weight;
+	fn buy_claim_escrowed() -> Weight;
+	fn dispute_escrow() -> Weight;
+	fn resolve_escrow() -> Weight;
+	fn create_bundle(n: u32) -> Weight;
+	fn buy_bundle(n: u32) -> Weight;
+	fn unbundle_claim() -> Weight;
+	fn watch_claim() -> Weight;
+	fn unwatch_claim() -> Weight;
+	fn annotate_claim() -> Weight;
+	fn transfer_with_vesting() -> Weight;
+	fn lock_as_collateral() -> Weight;
+	fn unlock_collateral() -> Weight;
+	fn migrate_v2_step() -> Weight;
+}
+
+/// Estimated weights for pallet_poe.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:0)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn create_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(70_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(75_u64))
+			.saturating_add(T::DbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:1)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:1 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:1 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn revoke_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(38_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(54_u64))
+			.saturating_add(T::DbWeight::get().writes(177_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn transfer_claim() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(62_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(42_u64))
+			.saturating_add(T::DbWeight::get().writes(42_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn create_claim_by_hash() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(68_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(42_u64))
+			.saturating_add(T::DbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	fn set_claim_metadata() -> Weight {
+		// Estimated proof size: `3768` bytes.
+		Weight::from_parts(24_000_000, 3768)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:2 w:2)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	fn renew_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(40_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn offer_claim() -> Weight {
+		// Estimated proof size: `3768` bytes.
+		Weight::from_parts(20_000_000, 3768)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:1 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn accept_claim() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(66_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(42_u64))
+			.saturating_add(T::DbWeight::get().writes(42_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:1 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn cancel_offer() -> Weight {
+		// Estimated proof size: `3581` bytes.
+		Weight::from_parts(17_000_000, 3581)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn approve_transfer() -> Weight {
+		// Estimated proof size: `3768` bytes.
+		Weight::from_parts(19_000_000, 3768)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:1 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn cancel_approval() -> Weight {
+		// Estimated proof size: `3768` bytes.
+		Weight::from_parts(21_000_000, 3768)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:1 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn transfer_claim_from() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(65_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(42_u64))
+			.saturating_add(T::DbWeight::get().writes(42_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:0 w:1)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn set_operator() -> Weight {
+		// Estimated proof size: `0` bytes.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:1)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn force_revoke_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(36_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().writes(177_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn force_transfer_claim() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(60_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(25_u64))
+			.saturating_add(T::DbWeight::get().writes(45_u64))
+	}
+	/// Storage: PoeModule Paused (r:0 w:1)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn pause() -> Weight {
+		// Estimated proof size: `0` bytes.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:0 w:1)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn unpause() -> Weight {
+		// Estimated proof size: `0` bytes.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	fn create_claims(n: u32) -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(20_000_000, 18873)
+			.saturating_add(Weight::from_parts(78_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().reads((72_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes((187_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 18873).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	fn revoke_claims(n: u32) -> Weight {
+		// Estimated proof size: `1489` bytes.
+		Weight::from_parts(10_000_000, 1489)
+			.saturating_add(Weight::from_parts(37_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((33_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((37_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 18873).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	fn transfer_claims(n: u32) -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(16_000_000, 3593)
+			.saturating_add(Weight::from_parts(57_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((43_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((43_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2815).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule AllowSponsorship (r:0 w:1)
+	/// Proof: PoeModule AllowSponsorship (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn set_allow_sponsorship() -> Weight {
+		// Estimated proof size: `1486` bytes.
+		Weight::from_parts(11_000_000, 1486)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule AllowSponsorship (r:1 w:0)
+	/// Proof: PoeModule AllowSponsorship (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:0)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:2 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn create_claim_for() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(73_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(77_u64))
+			.saturating_add(T::DbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Commitments (r:1 w:1)
+	/// Proof: PoeModule Commitments (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn commit_claim() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Commitments (r:1 w:1)
+	/// Proof: PoeModule Commitments (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:0)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn reveal_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(95_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(78_u64))
+			.saturating_add(T::DbWeight::get().writes(184_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	fn freeze_claim() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(21_000_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	fn unfreeze_claim() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(21_000_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn raise_dispute() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(34_000_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:1 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:0 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn resolve_dispute() -> Weight {
+		// Estimated proof size: `8799` bytes.
+		Weight::from_parts(78_000_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(30_u64))
+			.saturating_add(T::DbWeight::get().writes(50_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:1 w:1)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn attest_claim() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(26_000_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:1 w:1)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn revoke_attestation() -> Weight {
+		// Estimated proof size: `3565` bytes.
+		Weight::from_parts(22_000_000, 3565)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:1)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn register_notary() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:1)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn unregister_notary() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(31_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:0)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notarizations (r:1 w:1)
+	/// Proof: PoeModule Notarizations (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn notarize_claim() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(25_000_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:1)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notarizations (r:1 w:1)
+	/// Proof: PoeModule Notarizations (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn slash_notary() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(38_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:2 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:0)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn supersede_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(77_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(76_u64))
+			.saturating_add(T::DbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:2 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:0)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn create_child_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(80_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(76_u64))
+			.saturating_add(T::DbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule NextCollectionId (r:1 w:1)
+	/// Proof: PoeModule NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:0 w:1)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	fn create_collection() -> Weight {
+		// Estimated proof size: `1489` bytes.
+		Weight::from_parts(15_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	fn add_claim_to_collection() -> Weight {
+		// Estimated proof size: `5536` bytes.
+		Weight::from_parts(28_000_000, 5536)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:1)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:0)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 64]`.
+	fn transfer_collection(n: u32) -> Weight {
+		// Estimated proof size: `5536` bytes.
+		Weight::from_parts(22_000_000, 5536)
+			.saturating_add(Weight::from_parts(63_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((28_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((27_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2815).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:1)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn register_namespace() -> Weight {
+		// Estimated proof size: `85934` bytes.
+		Weight::from_parts(95_000_000, 85934)
+			.saturating_add(T::DbWeight::get().reads(35_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:1 w:1)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn transfer_namespace() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(44_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:1)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn ban_claim_hash() -> Weight {
+		// Estimated proof size: `3513` bytes.
+		Weight::from_parts(13_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:1)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn unban_claim_hash() -> Weight {
+		// Estimated proof size: `3513` bytes.
+		Weight::from_parts(14_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:1)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn ban_account() -> Weight {
+		// Estimated proof size: `3513` bytes.
+		Weight::from_parts(13_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:1)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn unban_account() -> Weight {
+		// Estimated proof size: `3513` bytes.
+		Weight::from_parts(14_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	fn add_co_owner() -> Weight {
+		// Estimated proof size: `4026` bytes.
+		Weight::from_parts(24_000_000, 4026)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:1)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	fn remove_co_owner() -> Weight {
+		// Estimated proof size: `4026` bytes.
+		Weight::from_parts(27_000_000, 4026)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:0)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:1)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	fn approve_co_owner_action() -> Weight {
+		// Estimated proof size: `4026` bytes.
+		Weight::from_parts(23_000_000, 4026)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:1 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:1 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	fn set_revocation_threshold() -> Weight {
+		// Estimated proof size: `4030` bytes.
+		Weight::from_parts(29_000_000, 4030)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:1 w:0)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:1 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	fn approve_revocation() -> Weight {
+		// Estimated proof size: `4030` bytes.
+		Weight::from_parts(27_000_000, 4030)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActiveRecoveries (r:1 w:0)
+	/// Proof: PoeModule ActiveRecoveries (max_values: None, max_size: Some(633), added: 3108, mode: MaxEncodedLen)
+	/// Storage: PoeModule RecoveryConfigs (r:0 w:1)
+	/// Proof: PoeModule RecoveryConfigs (max_values: None, max_size: Some(600), added: 3075, mode: MaxEncodedLen)
+	fn set_recovery_config() -> Weight {
+		// Estimated proof size: `4098` bytes.
+		Weight::from_parts(27_000_000, 4098)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule RecoveryConfigs (r:1 w:0)
+	/// Proof: PoeModule RecoveryConfigs (max_values: None, max_size: Some(600), added: 3075, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActiveRecoveries (r:1 w:1)
+	/// Proof: PoeModule ActiveRecoveries (max_values: None, max_size: Some(633), added: 3108, mode: MaxEncodedLen)
+	fn initiate_recovery() -> Weight {
+		// Estimated proof size: `4098` bytes.
+		Weight::from_parts(30_000_000, 4098)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActiveRecoveries (r:1 w:1)
+	/// Proof: PoeModule ActiveRecoveries (max_values: None, max_size: Some(633), added: 3108, mode: MaxEncodedLen)
+	fn veto_recovery() -> Weight {
+		// Estimated proof size: `4098` bytes.
+		Weight::from_parts(18_000_000, 4098)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActiveRecoveries (r:1 w:1)
+	/// Proof: PoeModule ActiveRecoveries (max_values: None, max_size: Some(633), added: 3108, mode: MaxEncodedLen)
+	/// Storage: PoeModule RecoveryConfigs (r:1 w:1)
+	/// Proof: PoeModule RecoveryConfigs (max_values: None, max_size: Some(600), added: 3075, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn complete_recovery(n: u32) -> Weight {
+		// Estimated proof size: `4098` bytes.
+		Weight::from_parts(35_000_000, 4098)
+			.saturating_add(Weight::from_parts(70_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((29_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((29_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2815).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule LastActivity (r:0 w:1)
+	/// Proof: PoeModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_inheritor() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(22_000_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule LastActivity (r:0 w:1)
+	/// Proof: PoeModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn ping() -> Weight {
+		// Estimated proof size: `1486` bytes.
+		Weight::from_parts(10_000_000, 1486)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:1 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule LastActivity (r:1 w:0)
+	/// Proof: PoeModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn claim_inheritance() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(68_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(25_u64))
+			.saturating_add(T::DbWeight::get().writes(41_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	fn schedule_transfer() -> Weight {
+		// Estimated proof size: `4506` bytes.
+		Weight::from_parts(28_000_000, 4506)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	fn cancel_scheduled_transfer() -> Weight {
+		// Estimated proof size: `4506` bytes.
+		Weight::from_parts(24_000_000, 4506)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:1 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn begin_revoke() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(20_000_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:1)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:1 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:1 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:1 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn finalize_revoke() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(41_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(55_u64))
+			.saturating_add(T::DbWeight::get().writes(177_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:1 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn cancel_revoke() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(20_000_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:0)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:0)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:0)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	fn list_claim_for_sale() -> Weight {
+		// Estimated proof size: `4026` bytes.
+		Weight::from_parts(21_000_000, 4026)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:4 w:4)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:1 w:0)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn buy_claim() -> Weight {
+		// Estimated proof size: `8799` bytes.
+		Weight::from_parts(92_000_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(26_u64))
+			.saturating_add(T::DbWeight::get().writes(44_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	fn cancel_listing() -> Weight {
+		// Estimated proof size: `3561` bytes.
+		Weight::from_parts(17_000_000, 3561)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:0)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:0)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:0)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	fn start_auction() -> Weight {
+		// Estimated proof size: `4506` bytes.
+		Weight::from_parts(27_000_000, 4506)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn bid() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(45_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:0)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:0)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:0)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	fn list_claim_dutch() -> Weight {
+		// Estimated proof size: `4026` bytes.
+		Weight::from_parts(22_000_000, 4026)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:0)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn set_royalty() -> Weight {
+		// Estimated proof size: `4200` bytes.
+		Weight::from_parts(19_000_000, 4200)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:1)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn add_fee_exempt() -> Weight {
+		// Estimated proof size: `3513` bytes.
+		Weight::from_parts(13_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:1)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn remove_fee_exempt() -> Weight {
+		// Estimated proof size: `3513` bytes.
+		Weight::from_parts(14_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:0 w:1)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_max_claim_length() -> Weight {
+		// Estimated proof size: `1489` bytes.
+		Weight::from_parts(9_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:2 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn submit_claim_unsigned() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(59_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(42_u64))
+			.saturating_add(T::DbWeight::get().writes(182_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:0)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	fn report_availability() -> Weight {
+		// Estimated proof size: `3768` bytes.
+		Weight::from_parts(21_000_000, 3768)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:0)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: PoeModule Anchors (r:1 w:1)
+	/// Proof: PoeModule Anchors (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn record_anchor() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(18_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BatchRoots (r:1 w:1)
+	/// Proof: PoeModule BatchRoots (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn create_batch_root() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BatchRoots (r:1 w:0)
+	/// Proof: PoeModule BatchRoots (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 32]`.
+	fn prove_membership(d: u32) -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(70_000_000, 18873)
+			.saturating_add(Weight::from_parts(1_200_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(43_u64))
+			.saturating_add(T::DbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule BatchRoots (r:1 w:1)
+	/// Proof: PoeModule BatchRoots (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn remove_batch_root() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(28_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:0)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn create_signed_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(117_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(75_u64))
+			.saturating_add(T::DbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Preimage StatusFor (r:1 w:1)
+	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn create_claim_from_preimage() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(77_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(43_u64))
+			.saturating_add(T::DbWeight::get().writes(184_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn sync_claim_owner() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(58_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(25_u64))
+			.saturating_add(T::DbWeight::get().writes(42_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule TrustedParas (r:0 w:1)
+	/// Proof: PoeModule TrustedParas (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	fn set_trusted_para() -> Weight {
+		// Estimated proof size: `0` bytes.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:1)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:1 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:1 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule TrustedParas (r:1 w:0)
+	/// Proof: PoeModule TrustedParas (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn transfer_claim_to_para() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(52_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(55_u64))
+			.saturating_add(T::DbWeight::get().writes(177_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TrustedParas (r:1 w:0)
+	/// Proof: PoeModule TrustedParas (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn force_register_remote_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(52_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(40_u64))
+			.saturating_add(T::DbWeight::get().writes(182_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:0)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: PoeModule NativePrice (r:0 w:1)
+	/// Proof: PoeModule NativePrice (max_values: Some(1), max_size: Some(52), added: 547, mode: MaxEncodedLen)
+	fn report_price() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(17_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn report_claim() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(34_000_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:1)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:1 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:1 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn settle_report() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(56_000_000, 18873)
+			.saturating_add(T::DbWeight::get().reads(54_u64))
+			.saturating_add(T::DbWeight::get().writes(179_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:1 w:1)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn grant_license() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(24_000_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:1 w:1)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:1)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn revoke_license() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(23_000_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:1 w:0)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:1)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	fn set_license_fee() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(21_000_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:1 w:1)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:1 w:0)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFeeQueue (r:1 w:1)
+	/// Proof: PoeModule LicenseFeeQueue (max_values: None, max_size: Some(2061), added: 4536, mode: MaxEncodedLen)
+	fn settle_license() -> Weight {
+		// Estimated proof size: `5526` bytes.
+		Weight::from_parts(48_000_000, 5526)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule EscrowReleases (r:1 w:1)
+	/// Proof: PoeModule EscrowReleases (max_values: None, max_size: Some(1037), added: 3512, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn buy_claim_escrowed() -> Weight {
+		// Estimated proof size: `8799` bytes.
+		Weight::from_parts(88_000_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(24_u64))
+			.saturating_add(T::DbWeight::get().writes(43_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn dispute_escrow() -> Weight {
+		// Estimated proof size: `3650` bytes.
+		Weight::from_parts(30_000_000, 3650)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn resolve_escrow() -> Weight {
+		// Estimated proof size: `8799` bytes.
+		Weight::from_parts(84_000_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(24_u64))
+			.saturating_add(T::DbWeight::get().writes(44_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule NextBundleId (r:1 w:1)
+	/// Proof: PoeModule NextBundleId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:0 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	fn create_bundle(n: u32) -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(18_000_000, 3800)
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2815).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:1 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	fn buy_bundle(n: u32) -> Weight {
+		// Estimated proof size: `5536` bytes.
+		Weight::from_parts(26_000_000, 5536)
+			.saturating_add(Weight::from_parts(71_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((19_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((15_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2815).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:1 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	fn unbundle_claim() -> Weight {
+		// Estimated proof size: `4510` bytes.
+		Weight::from_parts(22_000_000, 4510)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	fn watch_claim() -> Weight {
+		// Estimated proof size: `4538` bytes.
+		Weight::from_parts(20_000_000, 4538)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	fn unwatch_claim() -> Weight {
+		// Estimated proof size: `4538` bytes.
+		Weight::from_parts(18_000_000, 4538)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:1 w:0)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:1 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	fn annotate_claim() -> Weight {
+		// Estimated proof size: `8212` bytes.
+		Weight::from_parts(27_000_000, 8212)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn transfer_with_vesting() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(64_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(42_u64))
+			.saturating_add(T::DbWeight::get().writes(42_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:0)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn lock_as_collateral() -> Weight {
+		// Estimated proof size: `3805` bytes.
+		Weight::from_parts(21_000_000, 3805)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn unlock_collateral() -> Weight {
+		// Estimated proof size: `3545` bytes.
+		Weight::from_parts(17_000_000, 3545)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	fn migrate_v2_step() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(14_000_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:0)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn create_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(70_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(75_u64))
+			.saturating_add(RocksDbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:1)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:1 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:1 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn revoke_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(38_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(54_u64))
+			.saturating_add(RocksDbWeight::get().writes(177_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn transfer_claim() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(62_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(42_u64))
+			.saturating_add(RocksDbWeight::get().writes(42_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn create_claim_by_hash() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(68_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(42_u64))
+			.saturating_add(RocksDbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	fn set_claim_metadata() -> Weight {
+		// Estimated proof size: `3768` bytes.
+		Weight::from_parts(24_000_000, 3768)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:2 w:2)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	fn renew_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(40_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn offer_claim() -> Weight {
+		// Estimated proof size: `3768` bytes.
+		Weight::from_parts(20_000_000, 3768)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:1 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn accept_claim() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(66_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(42_u64))
+			.saturating_add(RocksDbWeight::get().writes(42_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:1 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn cancel_offer() -> Weight {
+		// Estimated proof size: `3581` bytes.
+		Weight::from_parts(17_000_000, 3581)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn approve_transfer() -> Weight {
+		// Estimated proof size: `3768` bytes.
+		Weight::from_parts(19_000_000, 3768)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:1 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn cancel_approval() -> Weight {
+		// Estimated proof size: `3768` bytes.
+		Weight::from_parts(21_000_000, 3768)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:1 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn transfer_claim_from() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(65_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(42_u64))
+			.saturating_add(RocksDbWeight::get().writes(42_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:0 w:1)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn set_operator() -> Weight {
+		// Estimated proof size: `0` bytes.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:1)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn force_revoke_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(36_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().writes(177_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn force_transfer_claim() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(60_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(25_u64))
+			.saturating_add(RocksDbWeight::get().writes(45_u64))
+	}
+	/// Storage: PoeModule Paused (r:0 w:1)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn pause() -> Weight {
+		// Estimated proof size: `0` bytes.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:0 w:1)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn unpause() -> Weight {
+		// Estimated proof size: `0` bytes.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	fn create_claims(n: u32) -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(20_000_000, 18873)
+			.saturating_add(Weight::from_parts(78_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().reads((72_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes((187_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 18873).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	fn revoke_claims(n: u32) -> Weight {
+		// Estimated proof size: `1489` bytes.
+		Weight::from_parts(10_000_000, 1489)
+			.saturating_add(Weight::from_parts(37_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((33_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((37_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 18873).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	fn transfer_claims(n: u32) -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(16_000_000, 3593)
+			.saturating_add(Weight::from_parts(57_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((43_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((43_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2815).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule AllowSponsorship (r:0 w:1)
+	/// Proof: PoeModule AllowSponsorship (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn set_allow_sponsorship() -> Weight {
+		// Estimated proof size: `1486` bytes.
+		Weight::from_parts(11_000_000, 1486)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule AllowSponsorship (r:1 w:0)
+	/// Proof: PoeModule AllowSponsorship (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:0)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:2 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn create_claim_for() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(73_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(77_u64))
+			.saturating_add(RocksDbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Commitments (r:1 w:1)
+	/// Proof: PoeModule Commitments (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn commit_claim() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Commitments (r:1 w:1)
+	/// Proof: PoeModule Commitments (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:0)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn reveal_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(95_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(78_u64))
+			.saturating_add(RocksDbWeight::get().writes(184_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	fn freeze_claim() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(21_000_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	fn unfreeze_claim() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(21_000_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn raise_dispute() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(34_000_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:1 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:0 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn resolve_dispute() -> Weight {
+		// Estimated proof size: `8799` bytes.
+		Weight::from_parts(78_000_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(30_u64))
+			.saturating_add(RocksDbWeight::get().writes(50_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:1 w:1)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn attest_claim() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(26_000_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:1 w:1)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn revoke_attestation() -> Weight {
+		// Estimated proof size: `3565` bytes.
+		Weight::from_parts(22_000_000, 3565)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:1)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn register_notary() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:1)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn unregister_notary() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(31_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:0)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notarizations (r:1 w:1)
+	/// Proof: PoeModule Notarizations (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn notarize_claim() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(25_000_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:1)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notarizations (r:1 w:1)
+	/// Proof: PoeModule Notarizations (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn slash_notary() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(38_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:2 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:0)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn supersede_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(77_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(76_u64))
+			.saturating_add(RocksDbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:2 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:0)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn create_child_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(80_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(76_u64))
+			.saturating_add(RocksDbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule NextCollectionId (r:1 w:1)
+	/// Proof: PoeModule NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:0 w:1)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	fn create_collection() -> Weight {
+		// Estimated proof size: `1489` bytes.
+		Weight::from_parts(15_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	fn add_claim_to_collection() -> Weight {
+		// Estimated proof size: `5536` bytes.
+		Weight::from_parts(28_000_000, 5536)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:1)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:0)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 64]`.
+	fn transfer_collection(n: u32) -> Weight {
+		// Estimated proof size: `5536` bytes.
+		Weight::from_parts(22_000_000, 5536)
+			.saturating_add(Weight::from_parts(63_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((28_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((27_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2815).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:1)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn register_namespace() -> Weight {
+		// Estimated proof size: `85934` bytes.
+		Weight::from_parts(95_000_000, 85934)
+			.saturating_add(RocksDbWeight::get().reads(35_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:1 w:1)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn transfer_namespace() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(44_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:1)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn ban_claim_hash() -> Weight {
+		// Estimated proof size: `3513` bytes.
+		Weight::from_parts(13_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:1)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn unban_claim_hash() -> Weight {
+		// Estimated proof size: `3513` bytes.
+		Weight::from_parts(14_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:1)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn ban_account() -> Weight {
+		// Estimated proof size: `3513` bytes.
+		Weight::from_parts(13_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:1)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn unban_account() -> Weight {
+		// Estimated proof size: `3513` bytes.
+		Weight::from_parts(14_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	fn add_co_owner() -> Weight {
+		// Estimated proof size: `4026` bytes.
+		Weight::from_parts(24_000_000, 4026)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:1)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	fn remove_co_owner() -> Weight {
+		// Estimated proof size: `4026` bytes.
+		Weight::from_parts(27_000_000, 4026)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:0)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:1)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	fn approve_co_owner_action() -> Weight {
+		// Estimated proof size: `4026` bytes.
+		Weight::from_parts(23_000_000, 4026)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:1 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:1 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	fn set_revocation_threshold() -> Weight {
+		// Estimated proof size: `4030` bytes.
+		Weight::from_parts(29_000_000, 4030)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:1 w:0)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:1 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	fn approve_revocation() -> Weight {
+		// Estimated proof size: `4030` bytes.
+		Weight::from_parts(27_000_000, 4030)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActiveRecoveries (r:1 w:0)
+	/// Proof: PoeModule ActiveRecoveries (max_values: None, max_size: Some(633), added: 3108, mode: MaxEncodedLen)
+	/// Storage: PoeModule RecoveryConfigs (r:0 w:1)
+	/// Proof: PoeModule RecoveryConfigs (max_values: None, max_size: Some(600), added: 3075, mode: MaxEncodedLen)
+	fn set_recovery_config() -> Weight {
+		// Estimated proof size: `4098` bytes.
+		Weight::from_parts(27_000_000, 4098)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule RecoveryConfigs (r:1 w:0)
+	/// Proof: PoeModule RecoveryConfigs (max_values: None, max_size: Some(600), added: 3075, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActiveRecoveries (r:1 w:1)
+	/// Proof: PoeModule ActiveRecoveries (max_values: None, max_size: Some(633), added: 3108, mode: MaxEncodedLen)
+	fn initiate_recovery() -> Weight {
+		// Estimated proof size: `4098` bytes.
+		Weight::from_parts(30_000_000, 4098)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActiveRecoveries (r:1 w:1)
+	/// Proof: PoeModule ActiveRecoveries (max_values: None, max_size: Some(633), added: 3108, mode: MaxEncodedLen)
+	fn veto_recovery() -> Weight {
+		// Estimated proof size: `4098` bytes.
+		Weight::from_parts(18_000_000, 4098)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActiveRecoveries (r:1 w:1)
+	/// Proof: PoeModule ActiveRecoveries (max_values: None, max_size: Some(633), added: 3108, mode: MaxEncodedLen)
+	/// Storage: PoeModule RecoveryConfigs (r:1 w:1)
+	/// Proof: PoeModule RecoveryConfigs (max_values: None, max_size: Some(600), added: 3075, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn complete_recovery(n: u32) -> Weight {
+		// Estimated proof size: `4098` bytes.
+		Weight::from_parts(35_000_000, 4098)
+			.saturating_add(Weight::from_parts(70_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((29_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((29_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2815).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule LastActivity (r:0 w:1)
+	/// Proof: PoeModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_inheritor() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(22_000_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule LastActivity (r:0 w:1)
+	/// Proof: PoeModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn ping() -> Weight {
+		// Estimated proof size: `1486` bytes.
+		Weight::from_parts(10_000_000, 1486)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:1 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule LastActivity (r:1 w:0)
+	/// Proof: PoeModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn claim_inheritance() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(68_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(25_u64))
+			.saturating_add(RocksDbWeight::get().writes(41_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	fn schedule_transfer() -> Weight {
+		// Estimated proof size: `4506` bytes.
+		Weight::from_parts(28_000_000, 4506)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	fn cancel_scheduled_transfer() -> Weight {
+		// Estimated proof size: `4506` bytes.
+		Weight::from_parts(24_000_000, 4506)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:1 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn begin_revoke() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(20_000_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:1)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:1 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:1 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:1 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn finalize_revoke() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(41_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(55_u64))
+			.saturating_add(RocksDbWeight::get().writes(177_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:1 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn cancel_revoke() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(20_000_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:0)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:0)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:0)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	fn list_claim_for_sale() -> Weight {
+		// Estimated proof size: `4026` bytes.
+		Weight::from_parts(21_000_000, 4026)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:4 w:4)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:1 w:0)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn buy_claim() -> Weight {
+		// Estimated proof size: `8799` bytes.
+		Weight::from_parts(92_000_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().writes(44_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	fn cancel_listing() -> Weight {
+		// Estimated proof size: `3561` bytes.
+		Weight::from_parts(17_000_000, 3561)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:0)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:0)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:0)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	fn start_auction() -> Weight {
+		// Estimated proof size: `4506` bytes.
+		Weight::from_parts(27_000_000, 4506)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn bid() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(45_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:0)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:0)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:0)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	fn list_claim_dutch() -> Weight {
+		// Estimated proof size: `4026` bytes.
+		Weight::from_parts(22_000_000, 4026)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:0)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn set_royalty() -> Weight {
+		// Estimated proof size: `4200` bytes.
+		Weight::from_parts(19_000_000, 4200)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:1)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn add_fee_exempt() -> Weight {
+		// Estimated proof size: `3513` bytes.
+		Weight::from_parts(13_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:1)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn remove_fee_exempt() -> Weight {
+		// Estimated proof size: `3513` bytes.
+		Weight::from_parts(14_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:0 w:1)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_max_claim_length() -> Weight {
+		// Estimated proof size: `1489` bytes.
+		Weight::from_parts(9_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:2 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn submit_claim_unsigned() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(59_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(42_u64))
+			.saturating_add(RocksDbWeight::get().writes(182_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:0)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	fn report_availability() -> Weight {
+		// Estimated proof size: `3768` bytes.
+		Weight::from_parts(21_000_000, 3768)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:0)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: PoeModule Anchors (r:1 w:1)
+	/// Proof: PoeModule Anchors (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn record_anchor() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(18_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BatchRoots (r:1 w:1)
+	/// Proof: PoeModule BatchRoots (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn create_batch_root() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BatchRoots (r:1 w:0)
+	/// Proof: PoeModule BatchRoots (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 32]`.
+	fn prove_membership(d: u32) -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(70_000_000, 18873)
+			.saturating_add(Weight::from_parts(1_200_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(43_u64))
+			.saturating_add(RocksDbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule BatchRoots (r:1 w:1)
+	/// Proof: PoeModule BatchRoots (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn remove_batch_root() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(28_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Namespaces (r:32 w:0)
+	/// Proof: PoeModule Namespaces (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CurrentMaxLength (r:1 w:0)
+	/// Proof: PoeModule CurrentMaxLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn create_signed_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(117_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(75_u64))
+			.saturating_add(RocksDbWeight::get().writes(183_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule EraDepositBase (r:1 w:0)
+	/// Proof: PoeModule EraDepositBase (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Preimage StatusFor (r:1 w:1)
+	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn create_claim_from_preimage() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(77_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(43_u64))
+			.saturating_add(RocksDbWeight::get().writes(184_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn sync_claim_owner() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(58_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(25_u64))
+			.saturating_add(RocksDbWeight::get().writes(42_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule TrustedParas (r:0 w:1)
+	/// Proof: PoeModule TrustedParas (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	fn set_trusted_para() -> Weight {
+		// Estimated proof size: `0` bytes.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:1)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:1 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:1 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule TrustedParas (r:1 w:0)
+	/// Proof: PoeModule TrustedParas (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn transfer_claim_to_para() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(52_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(55_u64))
+			.saturating_add(RocksDbWeight::get().writes(177_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Banned (r:1 w:0)
+	/// Proof: PoeModule Banned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule FeeExempt (r:1 w:0)
+	/// Proof: PoeModule FeeExempt (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	/// Proof: PoeModule ClaimsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationQueue (r:1 w:1)
+	/// Proof: PoeModule ConfirmationQueue (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Badges (r:1 w:1)
+	/// Proof: PoeModule Badges (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TrustedParas (r:1 w:0)
+	/// Proof: PoeModule TrustedParas (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:0 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:0 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn force_register_remote_claim() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(52_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(40_u64))
+			.saturating_add(RocksDbWeight::get().writes(182_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Notaries (r:1 w:0)
+	/// Proof: PoeModule Notaries (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: PoeModule NativePrice (r:0 w:1)
+	/// Proof: PoeModule NativePrice (max_values: Some(1), max_size: Some(52), added: 547, mode: MaxEncodedLen)
+	fn report_price() -> Weight {
+		// Estimated proof size: `3593` bytes.
+		Weight::from_parts(17_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn report_claim() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(34_000_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:1)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:1 w:1)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule TotalClaims (r:1 w:1)
+	/// Proof: PoeModule TotalClaims (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule AttestationCount (r:1 w:1)
+	/// Proof: PoeModule AttestationCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:0 w:16)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:0 w:16)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:16)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Proof: PoeModule Supersedes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Proof: PoeModule SupersededBy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ParentOf (r:1 w:65)
+	/// Proof: PoeModule ParentOf (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmationCredits (r:1 w:1)
+	/// Proof: PoeModule ConfirmationCredits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ConfirmedClaimCount (r:1 w:1)
+	/// Proof: PoeModule ConfirmedClaimCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Children (r:2 w:2)
+	/// Proof: PoeModule Children (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationGuards (r:1 w:1)
+	/// Proof: PoeModule RevocationGuards (max_values: None, max_size: Some(565), added: 3040, mode: MaxEncodedLen)
+	/// Storage: PoeModule RevocationApprovals (r:1 w:1)
+	/// Proof: PoeModule RevocationApprovals (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:0 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule Royalties (r:0 w:1)
+	/// Proof: PoeModule Royalties (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:0 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:0 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:0 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	/// Storage: PoeModule ActivityCount (r:1 w:1)
+	/// Proof: PoeModule ActivityCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimAuthors (r:0 w:1)
+	/// Proof: PoeModule ClaimAuthors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: PoeModule RetainedPreimages (r:1 w:1)
+	/// Proof: PoeModule RetainedPreimages (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reputation (r:1 w:1)
+	/// Proof: PoeModule Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn settle_report() -> Weight {
+		// Estimated proof size: `18873` bytes.
+		Weight::from_parts(56_000_000, 18873)
+			.saturating_add(RocksDbWeight::get().reads(54_u64))
+			.saturating_add(RocksDbWeight::get().writes(179_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:1 w:1)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn grant_license() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(24_000_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:1 w:1)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:1)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseCount (r:1 w:1)
+	/// Proof: PoeModule LicenseCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn revoke_license() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(23_000_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:1 w:0)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:0 w:1)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	fn set_license_fee() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(21_000_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFees (r:1 w:1)
+	/// Proof: PoeModule LicenseFees (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Licenses (r:1 w:0)
+	/// Proof: PoeModule Licenses (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule LicenseFeeQueue (r:1 w:1)
+	/// Proof: PoeModule LicenseFeeQueue (max_values: None, max_size: Some(2061), added: 4536, mode: MaxEncodedLen)
+	fn settle_license() -> Weight {
+		// Estimated proof size: `5526` bytes.
+		Weight::from_parts(48_000_000, 5526)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule EscrowReleases (r:1 w:1)
+	/// Proof: PoeModule EscrowReleases (max_values: None, max_size: Some(1037), added: 3512, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn buy_claim_escrowed() -> Weight {
+		// Estimated proof size: `8799` bytes.
+		Weight::from_parts(88_000_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(43_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn dispute_escrow() -> Weight {
+		// Estimated proof size: `3650` bytes.
+		Weight::from_parts(30_000_000, 3650)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:0 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:1)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:1)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule AuctionEnds (r:1 w:1)
+	/// Proof: PoeModule AuctionEnds (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn resolve_escrow() -> Weight {
+		// Estimated proof size: `8799` bytes.
+		Weight::from_parts(84_000_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(44_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule NextBundleId (r:1 w:1)
+	/// Proof: PoeModule NextBundleId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:0 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	fn create_bundle(n: u32) -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(18_000_000, 3800)
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2815).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:1 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	fn buy_bundle(n: u32) -> Weight {
+		// Estimated proof size: `5536` bytes.
+		Weight::from_parts(26_000_000, 5536)
+			.saturating_add(Weight::from_parts(71_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((19_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((15_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2815).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:1 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	fn unbundle_claim() -> Weight {
+		// Estimated proof size: `4510` bytes.
+		Weight::from_parts(22_000_000, 4510)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	fn watch_claim() -> Weight {
+		// Estimated proof size: `4538` bytes.
+		Weight::from_parts(20_000_000, 4538)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:1)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	fn unwatch_claim() -> Weight {
+		// Estimated proof size: `4538` bytes.
+		Weight::from_parts(18_000_000, 4538)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule Attestations (r:1 w:0)
+	/// Proof: PoeModule Attestations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: PoeModule Annotations (r:1 w:1)
+	/// Proof: PoeModule Annotations (max_values: None, max_size: Some(4737), added: 7212, mode: MaxEncodedLen)
+	fn annotate_claim() -> Weight {
+		// Estimated proof size: `8212` bytes.
+		Weight::from_parts(27_000_000, 8212)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnerClaims (r:0 w:2)
+	/// Proof: PoeModule OwnerClaims (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Proof: PoeModule PendingTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule Approvals (r:0 w:1)
+	/// Proof: PoeModule Approvals (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCountOf (r:2 w:2)
+	/// Proof: PoeModule ClaimCountOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Disputes (r:1 w:1)
+	/// Proof: PoeModule Disputes (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Reports (r:1 w:1)
+	/// Proof: PoeModule Reports (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PoeModule Escrows (r:1 w:1)
+	/// Proof: PoeModule Escrows (max_values: None, max_size: Some(185), added: 2660, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimBundle (r:1 w:1)
+	/// Proof: PoeModule ClaimBundle (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule BundleClaims (r:1 w:1)
+	/// Proof: PoeModule BundleClaims (max_values: None, max_size: Some(1045), added: 3520, mode: MaxEncodedLen)
+	/// Storage: PoeModule Bundles (r:0 w:1)
+	/// Proof: PoeModule Bundles (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: PoeModule ProvisionalClaims (r:0 w:1)
+	/// Proof: PoeModule ProvisionalClaims (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule OwnershipHistory (r:1 w:1)
+	/// Proof: PoeModule OwnershipHistory (max_values: None, max_size: Some(1205), added: 3680, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:1)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:0)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Watchers (r:1 w:0)
+	/// Proof: PoeModule Watchers (max_values: None, max_size: Some(1073), added: 3548, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimCollection (r:1 w:1)
+	/// Proof: PoeModule ClaimCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Collections (r:1 w:0)
+	/// Proof: PoeModule Collections (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollectionClaims (r:1 w:1)
+	/// Proof: PoeModule CollectionClaims (max_values: None, max_size: Some(2071), added: 4546, mode: MaxEncodedLen)
+	/// Storage: PoeModule BannedAccounts (r:1 w:0)
+	/// Proof: PoeModule BannedAccounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwners (r:1 w:1)
+	/// Proof: PoeModule CoOwners (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PoeModule CoOwnerApprovals (r:16 w:16)
+	/// Proof: PoeModule CoOwnerApprovals (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: PoeModule Inheritors (r:0 w:1)
+	/// Proof: PoeModule Inheritors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: PoeModule ScheduledTransfers (r:1 w:1)
+	/// Proof: PoeModule ScheduledTransfers (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: PoeModule TransferQueue (r:1 w:1)
+	/// Proof: PoeModule TransferQueue (max_values: None, max_size: Some(1041), added: 3516, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingRevocations (r:0 w:1)
+	/// Proof: PoeModule PendingRevocations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PoeModule Listings (r:1 w:0)
+	/// Proof: PoeModule Listings (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: PoeModule Auctions (r:1 w:0)
+	/// Proof: PoeModule Auctions (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	/// Storage: PoeModule BlockStats (r:1 w:1)
+	/// Proof: PoeModule BlockStats (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn transfer_with_vesting() -> Weight {
+		// Estimated proof size: `6196` bytes.
+		Weight::from_parts(64_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(42_u64))
+			.saturating_add(RocksDbWeight::get().writes(42_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Proof: PoeModule Operators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: PoeModule Vestings (r:1 w:0)
+	/// Proof: PoeModule Vestings (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn lock_as_collateral() -> Weight {
+		// Estimated proof size: `3805` bytes.
+		Weight::from_parts(21_000_000, 3805)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Paused (r:1 w:0)
+	/// Proof: PoeModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: PoeModule CollateralLocks (r:1 w:1)
+	/// Proof: PoeModule CollateralLocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn unlock_collateral() -> Weight {
+		// Estimated proof size: `3545` bytes.
+		Weight::from_parts(17_000_000, 3545)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringAt (r:1 w:1)
+	/// Proof: PoeModule ExpiringAt (max_values: None, max_size: Some(16398), added: 18873, mode: MaxEncodedLen)
+	fn migrate_v2_step() -> Weight {
+		// Estimated proof size: `3800` bytes.
+		Weight::from_parts(14_000_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
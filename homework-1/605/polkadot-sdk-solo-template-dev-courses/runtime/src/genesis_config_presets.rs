@@ -0,0 +1,126 @@
+//! 预置的创世配置：`development` 与 `local`，包含几条演示存证、设置好的 PoE 参数和一种可支付手续费的演示稳定币
+//!
+//! 当前版本的 `sp_genesis_builder::GenesisBuilder` 还没有按名称获取预设的接口，
+//! 因此预设只在 std 下编译，由节点的 chain spec 通过 [`get_preset`] 引用。
+//! `chain-spec-builder` 可以用 `create-default` 生成默认配置后再以这里的 JSON 作为补丁。
+
+use crate::{AccountId, Balance, Signature, EXISTENTIAL_DEPOSIT};
+use serde_json::{json, Value};
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_consensus_grandpa::AuthorityId as GrandpaId;
+use sp_core::{sr25519, Pair, Public};
+use sp_runtime::traits::{IdentifyAccount, Verify};
+
+/// 开发链预设的名称
+pub const DEVELOPMENT: &str = "development";
+/// 本地测试网预设的名称
+pub const LOCAL: &str = "local";
+
+/// 预置账户的初始余额
+const ENDOWMENT: Balance = 1 << 60;
+
+/// 演示用的稳定币，可以用来支付手续费；`min_balance` 等于存在性押金，手续费按 1:1 换算
+const DEMO_STABLE_ASSET: u32 = 1;
+
+/// 演示存证的内容，长度不超过运行时的 `MaxClaimLength`
+const DEMO_CLAIMS: [&[u8]; 3] = [b"poe1", b"poe2", b"poe3"];
+
+fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
+    TPublic::Pair::from_string(&format!("//{}", seed), None)
+        .expect("static values are valid; qed")
+        .public()
+}
+
+fn account(seed: &str) -> AccountId {
+    <Signature as Verify>::Signer::from(get_from_seed::<sr25519::Public>(seed)).into_account()
+}
+
+fn authority_keys(seed: &str) -> (AuraId, GrandpaId) {
+    (get_from_seed::<AuraId>(seed), get_from_seed::<GrandpaId>(seed))
+}
+
+fn testnet_genesis(
+    initial_authorities: Vec<(AuraId, GrandpaId)>,
+    root_key: AccountId,
+    endowed_accounts: Vec<AccountId>,
+    demo_claims: Vec<(Vec<u8>, AccountId)>,
+    max_length: u32,
+) -> Value {
+    json!({
+        "balances": {
+            "balances": endowed_accounts.iter().cloned().map(|k| (k, ENDOWMENT)).collect::<Vec<_>>(),
+        },
+        "assets": {
+            "assets": vec![(DEMO_STABLE_ASSET, root_key.clone(), true, EXISTENTIAL_DEPOSIT)],
+            "metadata": vec![(DEMO_STABLE_ASSET, b"PoE Dollar".to_vec(), b"PUSD".to_vec(), 6)],
+            "accounts": endowed_accounts
+                .iter()
+                .cloned()
+                .map(|k| (DEMO_STABLE_ASSET, k, ENDOWMENT))
+                .collect::<Vec<_>>(),
+        },
+        "aura": {
+            "authorities": initial_authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>(),
+        },
+        "grandpa": {
+            "authorities": initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>(),
+        },
+        "sudo": {
+            // Assign network admin rights.
+            "key": Some(root_key),
+        },
+        "poeModule": {
+            "claims": demo_claims,
+            "maxLength": Some(max_length),
+        },
+    })
+}
+
+/// 开发链：Alice 是唯一的出块节点和 sudo，演示存证分别属于 Alice 和 Bob
+pub fn development_config_genesis() -> Value {
+    testnet_genesis(
+        vec![authority_keys("Alice")],
+        account("Alice"),
+        ["Alice", "Bob", "Alice//stash", "Bob//stash"].into_iter().map(account).collect(),
+        vec![
+            (DEMO_CLAIMS[0].to_vec(), account("Alice")),
+            (DEMO_CLAIMS[1].to_vec(), account("Alice")),
+            (DEMO_CLAIMS[2].to_vec(), account("Bob")),
+        ],
+        4,
+    )
+}
+
+/// 本地测试网：Alice 和 Bob 轮流出块，演示存证分散在几个测试账户上
+pub fn local_config_genesis() -> Value {
+    let accounts = ["Alice", "Bob", "Charlie", "Dave", "Eve", "Ferdie"];
+    testnet_genesis(
+        vec![authority_keys("Alice"), authority_keys("Bob")],
+        account("Alice"),
+        accounts
+            .iter()
+            .map(|seed| account(seed))
+            .chain(accounts.iter().map(|seed| account(&format!("{seed}//stash"))))
+            .collect(),
+        vec![
+            (DEMO_CLAIMS[0].to_vec(), account("Alice")),
+            (DEMO_CLAIMS[1].to_vec(), account("Bob")),
+            (DEMO_CLAIMS[2].to_vec(), account("Charlie")),
+        ],
+        4,
+    )
+}
+
+/// 按名称获取预设的创世配置补丁，名称未知时返回 `None`
+pub fn get_preset(id: &str) -> Option<Value> {
+    match id {
+        DEVELOPMENT => Some(development_config_genesis()),
+        LOCAL => Some(local_config_genesis()),
+        _ => None,
+    }
+}
+
+/// 所有预设的名称
+pub fn preset_names() -> Vec<&'static str> {
+    vec![DEVELOPMENT, LOCAL]
+}
@@ -3,15 +3,20 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+#[cfg(feature = "std")]
+pub mod genesis_config_presets;
+
 use pallet_grandpa::AuthorityId as GrandpaId;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
     create_runtime_str, generic, impl_opaque_keys,
-    traits::{BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify},
-    transaction_validity::{TransactionSource, TransactionValidity},
-    ApplyExtrinsicResult, MultiSignature,
+    traits::{
+        BlakeTwo256, Block as BlockT, ConvertInto, IdentifyAccount, NumberFor, One, SaturatedConversion, Verify,
+    },
+    transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
+    ApplyExtrinsicResult, FixedU128, MultiSignature,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
@@ -22,8 +27,8 @@ use frame_support::genesis_builder_helper::{build_config, create_default_config}
 pub use frame_support::{
     construct_runtime, derive_impl, parameter_types,
     traits::{
-        ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
-        StorageInfo,
+        fungible::HoldConsideration, AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32,
+        ConstU64, ConstU8, KeyOwnerProofSystem, LinearStoragePrice, Randomness, StorageInfo,
     },
     weights::{
         constants::{
@@ -223,7 +228,7 @@ impl pallet_balances::Config for Runtime {
     type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
     type FreezeIdentifier = ();
     type MaxFreezes = ();
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type RuntimeFreezeReason = ();
 }
 
@@ -252,9 +257,201 @@ impl pallet_template::Config for Runtime {
     type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const PreimageBaseDeposit: Balance = 10 * EXISTENTIAL_DEPOSIT;
+    pub const PreimageByteDeposit: Balance = EXISTENTIAL_DEPOSIT / 10;
+    pub const PreimageHoldReason: RuntimeHoldReason = RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+}
+
+impl pallet_preimage::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+    type Currency = Balances;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type Consideration = HoldConsideration<
+        AccountId,
+        Balances,
+        PreimageHoldReason,
+        LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+    >;
+}
+
+impl pallet_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<1_000>;
+    type AssetId = u32;
+    type AssetIdParameter = codec::Compact<u32>;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type AssetAccountDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type MetadataDepositBase = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+    type MetadataDepositPerByte = ConstU128<{ EXISTENTIAL_DEPOSIT / 10 }>;
+    type ApprovalDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+/// 交易可以选择用 `is_sufficient` 的资产支付手续费，例如公证服务发行的稳定币。
+/// 金额按资产 `min_balance` 与原生代币存在性押金的比值换算，与原生代币手续费一样直接销毁。
+impl pallet_asset_tx_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Fungibles = Assets;
+    type OnChargeAssetTransaction = pallet_asset_tx_payment::FungiblesAdapter<
+        pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>,
+        (),
+    >;
+}
+
+parameter_types! {
+    pub const PoeSaleFee: Perbill = Perbill::from_percent(2);
+    pub const PoeMaxRoyalty: Perbill = Perbill::from_percent(10);
+    pub const PoeUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+    /// 基础押金保持价值 1 美元的原生代币
+    pub const PoeFiatDeposit: FixedU128 = FixedU128::from_u32(1);
+    pub const PoeReporterReward: Perbill = Perbill::from_percent(50);
+}
+
 impl pallet_poe::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type MaxClaimLength = ConstU32<4>;
+    type MaxTitleLength = ConstU32<64>;
+    type MaxUriLength = ConstU32<128>;
+    type MaxAnnotationLength = ConstU32<256>;
+    type ClaimLifetime = ConstU32<{ 365 * DAYS }>;
+    type MaxClaimsPerBlock = ConstU32<256>;
+    type MaxExpirationsPerBlock = ConstU32<50>;
+    type MaxExpiringPerBlock = ConstU32<512>;
+    type WarningPeriod = ConstU32<{ 7 * DAYS }>;
+    type Currency = Balances;
+    type RenewalFee = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type CreationFee = ConstU128<EXISTENTIAL_DEPOSIT>;
+    // 运行时没有国库，创建费用直接销毁
+    type OnCreationFee = ();
+    type DepositBase = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+    type DepositPerByte = ConstU128<{ EXISTENTIAL_DEPOSIT / 10 }>;
+    type FiatDeposit = PoeFiatDeposit;
+    // 与节点默认的 12 位代币精度一致
+    type NativeUnit = ConstU128<1_000_000_000_000>;
+    type DepositEra = ConstU32<{ 1 * DAYS }>;
+    type MaxPriceAge = ConstU32<{ 2 * DAYS }>;
+    type OfferLifetime = ConstU32<{ 7 * DAYS }>;
+    type MaxClaimsPerAccount = ConstU32<1_000>;
+    type MaxTotalClaims = ConstU32<1_000_000>;
+    type MaxBatchSize = ConstU32<32>;
+    type MinRevealDelay = ConstU32<2>;
+    type DisputeBond = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type ChallengeWindow = ConstU32<{ 1 * DAYS }>;
+    type ArbitrationOrigin = frame_system::EnsureRoot<AccountId>;
+    type ReportBond = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type ReporterReward = PoeReporterReward;
+    // 罚没的资金同样直接销毁
+    type OnSlash = ();
+    type FraudReputationPenalty = ConstU32<10>;
+    type BadgeThreshold = ConstU32<10>;
+    type MaxLicenses = ConstU32<16>;
+    type MaxWatchers = ConstU32<32>;
+    type MaxAnnotations = ConstU32<16>;
+    type AttestersCanAnnotate = ConstBool<true>;
+    type MaxAttestations = ConstU32<16>;
+    type NotaryStake = ConstU128<{ 1_000 * EXISTENTIAL_DEPOSIT }>;
+    type MaxHistoryLen = ConstU32<32>;
+    type MaxChildren = ConstU32<64>;
+    type MaxCollectionMetadataLength = ConstU32<256>;
+    type MaxCollectionSize = ConstU32<64>;
+    type MaxBundleSize = ConstU32<32>;
+    type MaxNamespaceLength = ConstU32<32>;
+    type NamespaceDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type NamespaceLifetime = ConstU32<{ 365 * DAYS }>;
+    type MaxCoOwners = ConstU32<16>;
+    type MaxSignatories = ConstU32<16>;
+    type MaxGuardians = ConstU32<16>;
+    type MinRecoveryDelay = ConstU32<{ 7 * DAYS }>;
+    type MinInactivityPeriod = ConstU32<{ 30 * DAYS }>;
+    type MaxScheduledPerBlock = ConstU32<32>;
+    type RevocationDelay = ConstU32<{ 1 * DAYS }>;
+    type TransferCooldown = ConstU32<{ 1 * HOURS }>;
+    type SaleFee = PoeSaleFee;
+    type MaxRoyalty = PoeMaxRoyalty;
+    type MaxAuctionsPerBlock = ConstU32<32>;
+    type EscrowPeriod = ConstU32<{ 3 * DAYS }>;
+    type AuthorityId = pallet_poe::crypto::AuthorityId;
+    type UnsignedPriority = PoeUnsignedPriority;
+    type AvailabilityCheckInterval = ConstU32<{ 1 * HOURS }>;
+    type MaxAvailabilityChecks = ConstU32<16>;
+    type AnchorInterval = ConstU32<{ 6 * HOURS }>;
+    type MaxProofDepth = ConstU32<32>;
+    // 需要与以太坊工具计算的存证哈希一致时改为 `sp_runtime::traits::Keccak256`，
+    // 此时 `Preimages` 也需要换成使用 Keccak-256 的预映像实现
+    type ClaimHasher = BlakeTwo256;
+    type ValidateContentIds = ConstBool<false>;
+    type Preimages = Preimage;
+    type ClaimPolicy = ();
+    type OnClaimCreated = ();
+    type OnClaimRevoked = ();
+    // 需要把存证镜像为 NFT 时，加入 `pallet_nfts`（`ItemId` 设为 `Hash`）并改为
+    // `pallet_poe::nft::NonfungiblesMirror<Nfts, 集合编号, pallet_nfts::ItemConfig>`
+    type NftMirror = ();
+    // 独立链不支持 XCM；作为平行链部署时改为 XCM 路由和
+    // `pallet_xcm::EnsureXcm<pallet_poe::remote::IsTrustedSibling<Runtime>>`
+    type XcmSender = ();
+    type RemoteOrigin = frame_system::EnsureNever<xcm::latest::Location>;
+    type XcmOrigin = frame_system::EnsureNever<AccountId>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime>;
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = UncheckedExtrinsic;
+}
+
+/// 链下工作机提交签名交易时使用，附加的 `SignedExtra` 与普通交易相同
+impl<C> frame_system::offchain::CreateSignedTransaction<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    fn create_transaction<A: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        public: <Signature as Verify>::Signer,
+        account: AccountId,
+        nonce: Nonce,
+    ) -> Option<(RuntimeCall, <UncheckedExtrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload)> {
+        use codec::Encode;
+
+        let period = BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+        let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+        let extra: SignedExtra = (
+            frame_system::CheckNonZeroSender::<Runtime>::new(),
+            frame_system::CheckSpecVersion::<Runtime>::new(),
+            frame_system::CheckTxVersion::<Runtime>::new(),
+            frame_system::CheckGenesis::<Runtime>::new(),
+            frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+            frame_system::CheckNonce::<Runtime>::from(nonce),
+            frame_system::CheckWeight::<Runtime>::new(),
+            pallet_asset_tx_payment::ChargeAssetTxPayment::<Runtime>::from(0, None),
+            pallet_poe::DeduplicateClaims::<Runtime>::new(),
+        );
+        let raw_payload = SignedPayload::new(call, extra).ok()?;
+        let signature = raw_payload.using_encoded(|payload| A::sign(payload, public))?;
+        let (call, extra, _) = raw_payload.deconstruct();
+        Some((call, (sp_runtime::MultiAddress::Id(account), signature, extra)))
+    }
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -301,6 +498,15 @@ mod runtime {
 
     #[runtime::pallet_index(8)]
     pub type PoeModule = pallet_poe;
+
+    #[runtime::pallet_index(9)]
+    pub type Preimage = pallet_preimage;
+
+    #[runtime::pallet_index(10)]
+    pub type Assets = pallet_assets;
+
+    #[runtime::pallet_index(11)]
+    pub type AssetTxPayment = pallet_asset_tx_payment;
 }
 
 /// The address format for describing accounts.
@@ -318,14 +524,18 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    pallet_asset_tx_payment::ChargeAssetTxPayment<Runtime>,
+    pallet_poe::DeduplicateClaims<Runtime>,
 );
 
 /// All migrations of the runtime, aside from the ones declared in the pallets.
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    pallet_poe::migrations::v1::MigrateToV1<Runtime>,
+    pallet_poe::migrations::v2::MigrateToV2<Runtime>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -350,7 +560,10 @@ mod benches {
         [pallet_balances, Balances]
         [pallet_timestamp, Timestamp]
         [pallet_sudo, Sudo]
+        [pallet_preimage, Preimage]
+        [pallet_assets, Assets]
         [pallet_template, TemplateModule]
+        [pallet_poe, PoeModule]
     );
 }
 
@@ -478,6 +691,46 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_poe_runtime_api::PoeApi<Block, AccountId, BlockNumber, Hash> for Runtime {
+        fn claim_owner(claim_hash: Hash) -> Option<(AccountId, BlockNumber)> {
+            PoeModule::claim_owner(&claim_hash)
+        }
+
+        fn claims_of(owner: AccountId) -> Vec<Hash> {
+            PoeModule::claims_of(&owner)
+        }
+
+        fn total_claims() -> u32 {
+            PoeModule::total_claims()
+        }
+
+        fn ownership_history(claim_hash: Hash) -> Vec<(AccountId, BlockNumber)> {
+            PoeModule::ownership_history(&claim_hash)
+        }
+
+        fn owner_at(claim_hash: Hash, block: BlockNumber) -> Option<AccountId> {
+            PoeModule::owner_at(&claim_hash, block)
+        }
+
+        fn block_stats() -> (u64, u64, u64) {
+            let stats = PoeModule::last_block_stats();
+            (stats.created, stats.revoked, stats.transferred)
+        }
+
+        fn cumulative_stats() -> (u64, u64, u64) {
+            let stats = PoeModule::cumulative_stats();
+            (stats.created, stats.revoked, stats.transferred)
+        }
+
+        fn reputation(who: AccountId) -> i32 {
+            PoeModule::reputation(who)
+        }
+
+        fn badge(who: AccountId) -> Option<BlockNumber> {
+            PoeModule::badge_of(who).map(|badge| badge.minted_at)
+        }
+    }
+
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
         fn query_info(
             uxt: <Block as BlockT>::Extrinsic,